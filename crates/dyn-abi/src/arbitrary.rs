@@ -188,23 +188,7 @@ impl<'a> arbitrary::Arbitrary<'a> for DynSolType {
 
 impl<'a> arbitrary::Arbitrary<'a> for DynSolValue {
     fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
-        match u.arbitrary::<DynSolType>()? {
-            // re-use name and prop_names
-            #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct {
-                name,
-                prop_names,
-                tuple,
-            } => Ok(Self::CustomStruct {
-                name,
-                prop_names,
-                tuple: tuple
-                    .iter()
-                    .map(|ty| Self::arbitrary_from_type(ty, u))
-                    .collect::<Result<_, _>>()?,
-            }),
-            t => Self::arbitrary_from_type(&t, u),
-        }
+        u.arbitrary::<DynSolType>()?.arbitrary_value(u)
     }
 
     fn size_hint(depth: usize) -> (usize, Option<usize>) {
@@ -350,8 +334,35 @@ impl proptest::arbitrary::Arbitrary for DynSolValue {
     }
 }
 
+impl DynSolType {
+    /// Generate an arbitrary [`DynSolValue`] of this type.
+    ///
+    /// The value is always valid for this type: integers fit in their bit
+    /// width, fixed bytes are zero-padded past their length, and fixed arrays
+    /// have the declared number of elements. This means that it can be encoded
+    /// and then decoded in strict mode, which is useful for differential
+    /// fuzzing against other encoders.
+    #[inline]
+    pub fn arbitrary_value(&self, u: &mut Unstructured<'_>) -> arbitrary::Result<DynSolValue> {
+        DynSolValue::arbitrary_from_type(self, u)
+    }
+
+    /// Create a [proptest strategy][Strategy] to generate [`DynSolValue`]s of
+    /// this type.
+    ///
+    /// See [`arbitrary_value`](Self::arbitrary_value) for the guarantees on the
+    /// generated values.
+    #[inline]
+    pub fn value_strategy(&self) -> SBoxedStrategy<DynSolValue> {
+        DynSolValue::type_strategy(self)
+    }
+}
+
 impl DynSolValue {
     /// Generate a [`DynSolValue`] from the given [`DynSolType`].
+    ///
+    /// See [`DynSolType::arbitrary_value`] for the guarantees on the generated
+    /// value.
     pub fn arbitrary_from_type(
         ty: &DynSolType,
         u: &mut Unstructured<'_>,
@@ -359,9 +370,11 @@ impl DynSolValue {
         match ty {
             DynSolType::Bool => u.arbitrary().map(Self::Bool),
             DynSolType::Address => u.arbitrary().map(Self::Address),
-            &DynSolType::Int(sz) => u.arbitrary().map(|x| Self::Int(x, sz)),
-            &DynSolType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(x, sz)),
-            &DynSolType::FixedBytes(sz) => u.arbitrary().map(|x| Self::FixedBytes(x, sz)),
+            &DynSolType::Int(sz) => u.arbitrary().map(|x| Self::Int(adjust_int(x, sz), sz)),
+            &DynSolType::Uint(sz) => u.arbitrary().map(|x| Self::Uint(adjust_uint(x, sz), sz)),
+            &DynSolType::FixedBytes(sz) => u
+                .arbitrary()
+                .map(|x| Self::FixedBytes(adjust_fb(x, sz), sz)),
             DynSolType::Bytes => u.arbitrary().map(Self::Bytes),
             DynSolType::String => u.arbitrary().map(Self::String),
             DynSolType::Array(ty) => {
@@ -385,22 +398,18 @@ impl DynSolValue {
                 .collect::<Result<Vec<_>, _>>()
                 .map(Self::Tuple),
             #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct { tuple, .. } => {
-                let name = u.arbitrary::<AString>()?.0;
-                let tuple = tuple
+            DynSolType::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => Ok(Self::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: tuple
                     .iter()
                     .map(|ty| Self::arbitrary_from_type(ty, u))
-                    .collect::<Result<Vec<_>, _>>()?;
-                let sz = tuple.len();
-                let prop_names = (0..sz)
-                    .map(|_| u.arbitrary::<AString>().map(|s| s.0))
-                    .collect::<Result<Vec<_>, _>>()?;
-                Ok(Self::CustomStruct {
-                    name,
-                    prop_names,
-                    tuple,
-                })
-            }
+                    .collect::<Result<_, _>>()?,
+            }),
         }
     }
 
@@ -410,10 +419,14 @@ impl DynSolValue {
         match ty {
             DynSolType::Bool => any::<bool>().prop_map(Self::Bool).sboxed(),
             DynSolType::Address => any::<Address>().prop_map(Self::Address).sboxed(),
-            &DynSolType::Int(sz) => any::<I256>().prop_map(move |x| Self::Int(x, sz)).sboxed(),
-            &DynSolType::Uint(sz) => any::<U256>().prop_map(move |x| Self::Uint(x, sz)).sboxed(),
+            &DynSolType::Int(sz) => any::<I256>()
+                .prop_map(move |x| Self::Int(adjust_int(x, sz), sz))
+                .sboxed(),
+            &DynSolType::Uint(sz) => any::<U256>()
+                .prop_map(move |x| Self::Uint(adjust_uint(x, sz), sz))
+                .sboxed(),
             &DynSolType::FixedBytes(sz) => any::<B256>()
-                .prop_map(move |x| Self::FixedBytes(x, sz))
+                .prop_map(move |x| Self::FixedBytes(adjust_fb(x, sz), sz))
                 .sboxed(),
             DynSolType::Bytes => any::<Vec<u8>>().prop_map(Self::Bytes).sboxed(),
             DynSolType::String => any::<String>().prop_map(Self::String).sboxed(),
@@ -434,13 +447,19 @@ impl DynSolValue {
                 .prop_map(Self::Tuple)
                 .sboxed(),
             #[cfg(feature = "eip712")]
-            DynSolType::CustomStruct { tuple, .. } => {
-                let types = tuple.iter().map(Self::type_strategy).collect::<Vec<_>>();
-                let sz = types.len();
-                (IDENT_STRATEGY, vec_strategy(IDENT_STRATEGY, sz..=sz), types)
-                    .prop_map(|(name, prop_names, tuple)| Self::CustomStruct {
-                        name,
-                        prop_names,
+            DynSolType::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => {
+                let (name, prop_names) = (name.clone(), prop_names.clone());
+                tuple
+                    .iter()
+                    .map(Self::type_strategy)
+                    .collect::<Vec<_>>()
+                    .prop_map(move |tuple| Self::CustomStruct {
+                        name: name.clone(),
+                        prop_names: prop_names.clone(),
                         tuple,
                     })
                     .sboxed()
@@ -460,9 +479,9 @@ impl DynSolValue {
         prop_oneof![
             any::<bool>().prop_map(Self::Bool),
             any::<Address>().prop_map(Self::Address),
-            int_strategy::<I256>().prop_map(|(x, sz)| Self::Int(x, sz)),
-            int_strategy::<U256>().prop_map(|(x, sz)| Self::Uint(x, sz)),
-            (any::<B256>(), 1..=32usize).prop_map(|(x, sz)| DynSolValue::FixedBytes(x, sz)),
+            int_strategy::<I256>().prop_map(|(x, sz)| Self::Int(adjust_int(x, sz), sz)),
+            int_strategy::<U256>().prop_map(|(x, sz)| Self::Uint(adjust_uint(x, sz), sz)),
+            (any::<B256>(), 1..=32usize).prop_map(|(x, sz)| Self::FixedBytes(adjust_fb(x, sz), sz)),
             any::<Vec<u8>>().prop_map(Self::Bytes),
             any::<String>().prop_map(Self::String),
         ]
@@ -517,6 +536,37 @@ fn int_strategy<T: Arbitrary>() -> impl Strategy<Value = (ValueOfStrategy<T::Str
     (any::<T>(), any::<usize>().prop_map(int_size))
 }
 
+/// Truncates a signed integer to `sz` bits, sign-extending the result.
+#[inline]
+fn adjust_int(int: I256, sz: usize) -> I256 {
+    if sz == 256 {
+        return int
+    }
+    let mask = U256::MAX >> (256 - sz);
+    let raw = int.into_raw();
+    I256::from_raw(if raw.bit(sz - 1) {
+        raw | !mask
+    } else {
+        raw & mask
+    })
+}
+
+/// Truncates an unsigned integer to `sz` bits.
+#[inline]
+fn adjust_uint(mut uint: U256, sz: usize) -> U256 {
+    if sz < 256 {
+        uint &= U256::MAX >> (256 - sz);
+    }
+    uint
+}
+
+/// Zeroes the bytes of a word past the first `sz`.
+#[inline]
+fn adjust_fb(mut word: B256, sz: usize) -> B256 {
+    word[sz..].fill(0);
+    word
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,6 +635,24 @@ mod tests {
             value_test(value.unwrap())?;
         }
 
+        #[test]
+        fn arbitrary_value_of_type(ty: DynSolType, bytes: Vec<u8>) {
+            let mut u = Unstructured::new(&bytes);
+            let value = ty.arbitrary_value(&mut u);
+            prop_assume!(value.is_ok());
+            let value = value.unwrap();
+            prop_assert!(ty.matches(&value), "value doesn't match {ty:?}: {value:?}");
+            value_test(value)?;
+        }
+
+        #[test]
+        fn proptest_value_of_type(
+            (ty, value) in any::<DynSolType>().prop_flat_map(|ty| (Just(ty.clone()), ty.value_strategy()))
+        ) {
+            prop_assert!(ty.matches(&value), "value doesn't match {ty:?}: {value:?}");
+            value_test(value)?;
+        }
+
         #[test]
         fn proptest_type(ty: DynSolType) {
             type_test(ty)?;
@@ -596,6 +664,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn adjust() {
+        let int = |x: i64| I256::try_from(x).unwrap();
+        assert_eq!(adjust_int(int(127), 8), int(127));
+        assert_eq!(adjust_int(int(128), 8), int(-128));
+        assert_eq!(adjust_int(int(-129), 8), int(127));
+        assert_eq!(adjust_int(I256::MIN, 256), I256::MIN);
+        assert_eq!(adjust_int(I256::MIN, 16), int(0));
+
+        assert_eq!(adjust_uint(U256::from(0x1ff), 8), U256::from(0xff));
+        assert_eq!(adjust_uint(U256::MAX, 256), U256::MAX);
+
+        let word = adjust_fb(B256::repeat_byte(0xff), 1);
+        assert_eq!(word[0], 0xff);
+        assert!(word[1..].iter().all(|&b| b == 0));
+        assert_eq!(
+            adjust_fb(B256::repeat_byte(0xff), 32),
+            B256::repeat_byte(0xff)
+        );
+    }
+
     fn type_test(ty: DynSolType) -> Result<(), TestCaseError> {
         let s = ty.sol_type_name();
         prop_assume!(!ty.has_custom_struct());
@@ -645,8 +734,10 @@ mod tests {
             prop_assert_eq!(parsed.as_ref(), Ok(&ty), "types don't match {:?}", s);
         }
 
+        // generated values must be valid for their type, so they can be
+        // decoded in strict mode
         let data = value.encode_params();
-        match ty.decode_params(&data) {
+        match ty.decode_params(&data, true) {
             // skip the check if the type contains a CustomStruct, since
             // decoding will not populate names
             Ok(decoded) if !decoded.has_custom_struct() => prop_assert_eq!(
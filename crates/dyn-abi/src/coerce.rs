@@ -0,0 +1,514 @@
+use crate::{DynAbiError, DynAbiResult, DynSolType, DynSolValue, Word};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_primitives::{Address, Sign, I256, U256};
+
+impl DynSolType {
+    /// Coerce a string to a [`DynSolValue`] via this type.
+    ///
+    /// The accepted formats are:
+    /// - `address`: a `0x`-prefixed, 20-byte hex string;
+    /// - `bool`: `true` or `false`;
+    /// - `intN` and `uintN`: a decimal or `0x`-prefixed hex number, with a
+    ///   leading `-` for negative signed integers. The value must fit in `N`
+    ///   bits;
+    /// - `bytesN`: a `0x`-prefixed hex string of exactly `N` bytes;
+    /// - `bytes`: a `0x`-prefixed hex string of any even length;
+    /// - `string`: a double-quoted string in which `\"` and `\\` are escaped,
+    ///   or the raw text. Inside of arrays and tuples, raw text ends at the
+    ///   next `,`, `]` or `)`;
+    /// - arrays: a comma-separated list of values in brackets, `[a, b]`;
+    /// - tuples and structs: a comma-separated list of values in parentheses,
+    ///   `(a, b)`.
+    ///
+    /// Whitespace around values and delimiters is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DynAbiError::InvalidValueString`] with the byte offset in `s`
+    /// at which parsing failed, and a description of what was expected there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_dyn_abi::{DynAbiError, DynSolType, DynSolValue};
+    /// use alloy_primitives::U256;
+    ///
+    /// let ty: DynSolType = "(uint8,string,bool[])".parse()?;
+    /// let value = ty.coerce_str("(0x2a, \"hello, world\", [true, false])")?;
+    /// assert_eq!(
+    ///     value,
+    ///     DynSolValue::Tuple(vec![
+    ///         DynSolValue::Uint(U256::from(42), 8),
+    ///         DynSolValue::String("hello, world".into()),
+    ///         DynSolValue::Array(vec![DynSolValue::Bool(true), DynSolValue::Bool(false)]),
+    ///     ])
+    /// );
+    ///
+    /// assert_eq!(
+    ///     ty.coerce_str("(256, hi, [])"),
+    ///     Err(DynAbiError::InvalidValueString {
+    ///         position: 1,
+    ///         expected: "uint8".into(),
+    ///     })
+    /// );
+    /// # Ok::<_, DynAbiError>(())
+    /// ```
+    pub fn coerce_str(&self, s: &str) -> DynAbiResult<DynSolValue> {
+        let mut parser = ValueParser {
+            input: s,
+            pos: 0,
+            depth: 0,
+        };
+        let value = parser.value(self)?;
+        parser.skip_whitespace();
+        if parser.pos != s.len() {
+            return Err(parser.error("end of input"))
+        }
+        Ok(value)
+    }
+}
+
+/// Recursive descent parser for value strings. `pos` is a byte offset into
+/// `input`, and `depth` is the number of open arrays and tuples.
+struct ValueParser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> ValueParser<'a> {
+    fn value(&mut self, ty: &DynSolType) -> DynAbiResult<DynSolValue> {
+        self.skip_whitespace();
+        match ty {
+            DynSolType::Address => self.address(),
+            DynSolType::Bool => self.bool(),
+            DynSolType::Int(n) => self.int(*n),
+            DynSolType::Uint(n) => self.uint(*n),
+            DynSolType::FixedBytes(n) => self.fixed_bytes(*n),
+            DynSolType::Bytes => self.bytes(),
+            DynSolType::String => self.string(),
+            DynSolType::Array(inner) => self
+                .list(b'[', b']', |p, _| p.value(inner))
+                .map(DynSolValue::Array),
+            DynSolType::FixedArray(inner, n) => {
+                let start = self.pos;
+                let values = self.list(b'[', b']', |p, _| p.value(inner))?;
+                if values.len() != *n {
+                    return Err(DynAbiError::invalid_value_string(
+                        start,
+                        format!("{n} array elements"),
+                    ))
+                }
+                Ok(DynSolValue::FixedArray(values))
+            }
+            DynSolType::Tuple(types) => self.tuple(types).map(DynSolValue::Tuple),
+            #[cfg(feature = "eip712")]
+            DynSolType::CustomStruct {
+                name,
+                prop_names,
+                tuple,
+            } => Ok(DynSolValue::CustomStruct {
+                name: name.clone(),
+                prop_names: prop_names.clone(),
+                tuple: self.tuple(tuple)?,
+            }),
+        }
+    }
+
+    fn address(&mut self) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        match token.strip_prefix("0x").map(hex::decode) {
+            Some(Ok(bytes)) if bytes.len() == 20 => {
+                Ok(DynSolValue::Address(Address::from_slice(&bytes)))
+            }
+            _ => Err(DynAbiError::invalid_value_string(start, "address")),
+        }
+    }
+
+    fn bool(&mut self) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        match token {
+            "true" => Ok(DynSolValue::Bool(true)),
+            "false" => Ok(DynSolValue::Bool(false)),
+            _ => Err(DynAbiError::invalid_value_string(start, "bool")),
+        }
+    }
+
+    fn int(&mut self, n: usize) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        let (sign, abs) = match token.strip_prefix('-') {
+            Some(abs) => (Sign::Negative, abs),
+            None => (Sign::Positive, token),
+        };
+        // the most negative value has an absolute value of 2^(n-1), which
+        // is one more than the largest positive value
+        let max = U256::from(1) << (n - 1);
+        parse_uint(abs)
+            .filter(|abs| match sign {
+                Sign::Positive => *abs < max,
+                Sign::Negative => *abs <= max,
+            })
+            .and_then(|abs| I256::checked_from_sign_and_abs(sign, abs))
+            .map(|int| DynSolValue::Int(int, n))
+            .ok_or_else(|| DynAbiError::invalid_value_string(start, format!("int{n}")))
+    }
+
+    fn uint(&mut self, n: usize) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        parse_uint(token)
+            .filter(|uint| uint.bit_len() <= n)
+            .map(|uint| DynSolValue::Uint(uint, n))
+            .ok_or_else(|| DynAbiError::invalid_value_string(start, format!("uint{n}")))
+    }
+
+    fn fixed_bytes(&mut self, n: usize) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        match token.strip_prefix("0x").map(hex::decode) {
+            Some(Ok(bytes)) if bytes.len() == n => {
+                let mut word = Word::ZERO;
+                word[..n].copy_from_slice(&bytes);
+                Ok(DynSolValue::FixedBytes(word, n))
+            }
+            _ => Err(DynAbiError::invalid_value_string(
+                start,
+                format!("bytes{n}"),
+            )),
+        }
+    }
+
+    fn bytes(&mut self) -> DynAbiResult<DynSolValue> {
+        let (start, token) = self.token();
+        match token.strip_prefix("0x").map(hex::decode) {
+            Some(Ok(bytes)) => Ok(DynSolValue::Bytes(bytes)),
+            _ => Err(DynAbiError::invalid_value_string(start, "bytes")),
+        }
+    }
+
+    fn string(&mut self) -> DynAbiResult<DynSolValue> {
+        if self.peek() != Some(b'"') {
+            let (_, token) = self.raw_token();
+            return Ok(DynSolValue::String(token.to_string()))
+        }
+
+        let start = self.pos;
+        let mut s = String::new();
+        let mut chars = self.input[start + 1..].char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.pos = start + 1 + i + 1;
+                    return Ok(DynSolValue::String(s))
+                }
+                '\\' => match chars.next() {
+                    Some((_, c @ ('"' | '\\'))) => s.push(c),
+                    Some((j, _)) => {
+                        return Err(DynAbiError::invalid_value_string(
+                            start + 1 + j,
+                            "`\"` or `\\` after `\\`",
+                        ))
+                    }
+                    None => break,
+                },
+                c => s.push(c),
+            }
+        }
+        Err(DynAbiError::invalid_value_string(
+            self.input.len(),
+            "closing `\"`",
+        ))
+    }
+
+    fn tuple(&mut self, types: &[DynSolType]) -> DynAbiResult<Vec<DynSolValue>> {
+        let start = self.pos;
+        let values = self.list(b'(', b')', |p, i| match types.get(i) {
+            Some(ty) => p.value(ty),
+            None => {
+                p.skip_whitespace();
+                Err(p.error("`)`"))
+            }
+        })?;
+        if values.len() != types.len() {
+            return Err(DynAbiError::invalid_value_string(
+                start,
+                format!("{} tuple elements", types.len()),
+            ))
+        }
+        Ok(values)
+    }
+
+    /// Parses a delimited, comma-separated list of values.
+    fn list(
+        &mut self,
+        open: u8,
+        close: u8,
+        mut f: impl FnMut(&mut Self, usize) -> DynAbiResult<DynSolValue>,
+    ) -> DynAbiResult<Vec<DynSolValue>> {
+        self.expect(open)?;
+        self.depth += 1;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+        } else {
+            loop {
+                values.push(f(self, values.len())?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(c) if c == close => {
+                        self.pos += 1;
+                        break
+                    }
+                    _ => return Err(self.error(format!("`,` or `{}`", close as char))),
+                }
+            }
+        }
+        self.depth -= 1;
+        Ok(values)
+    }
+
+    fn expect(&mut self, c: u8) -> DynAbiResult<()> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", c as char)))
+        }
+    }
+
+    /// Returns the start position and contents of the next value token, which
+    /// ends at a delimiter or whitespace.
+    fn token(&mut self) -> (usize, &'a str) {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ',' | '[' | ']' | '(' | ')'))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        (start, &rest[..len])
+    }
+
+    /// Returns the start position and trimmed contents of the next raw string
+    /// token. Outside of lists, this is the rest of the input.
+    fn raw_token(&mut self) -> (usize, &'a str) {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = if self.depth == 0 {
+            rest.len()
+        } else {
+            rest.find([',', ']', ')']).unwrap_or(rest.len())
+        };
+        let token = rest[..len].trim_end();
+        self.pos += token.len();
+        (start, token)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn error(&self, expected: impl Into<Cow<'static, str>>) -> DynAbiError {
+        DynAbiError::invalid_value_string(self.pos, expected)
+    }
+}
+
+/// Parses a decimal or `0x`-prefixed hex number.
+fn parse_uint(s: &str) -> Option<U256> {
+    let (digits, radix) = match s.strip_prefix("0x") {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    // `from_str_radix` accepts underscores and empty strings
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix as u32)) {
+        return None
+    }
+    U256::from_str_radix(digits, radix).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloy_primitives::{address, hex};
+
+    fn coerce(ty: &str, s: &str) -> DynAbiResult<DynSolValue> {
+        ty.parse::<DynSolType>().unwrap().coerce_str(s)
+    }
+
+    fn err(position: usize, expected: &'static str) -> DynAbiResult<DynSolValue> {
+        Err(DynAbiError::invalid_value_string(position, expected))
+    }
+
+    #[test]
+    fn coerce_primitives() {
+        assert_eq!(coerce("bool", " true "), Ok(DynSolValue::Bool(true)));
+        assert_eq!(coerce("bool", "false"), Ok(DynSolValue::Bool(false)));
+        assert_eq!(coerce("bool", "1"), err(0, "bool"));
+
+        assert_eq!(
+            coerce("address", "0x00000000219ab540356cBB839Cbe05303d7705Fa"),
+            Ok(DynSolValue::Address(address!(
+                "00000000219ab540356cBB839Cbe05303d7705Fa"
+            )))
+        );
+        assert_eq!(
+            coerce("address", "00000000219ab540356cBB839Cbe05303d7705Fa"),
+            err(0, "address")
+        );
+        assert_eq!(coerce("address", "0x1234"), err(0, "address"));
+
+        assert_eq!(
+            coerce("bytes", "0x1234"),
+            Ok(DynSolValue::Bytes(vec![0x12, 0x34]))
+        );
+        assert_eq!(coerce("bytes", "0x"), Ok(DynSolValue::Bytes(vec![])));
+        assert_eq!(coerce("bytes", "0x123"), err(0, "bytes"));
+
+        let mut word = Word::ZERO;
+        word[..2].copy_from_slice(&[0x12, 0x34]);
+        assert_eq!(
+            coerce("bytes2", "0x1234"),
+            Ok(DynSolValue::FixedBytes(word, 2))
+        );
+        assert_eq!(coerce("bytes2", "0x12"), err(0, "bytes2"));
+        assert_eq!(coerce("bytes2", "0x123456"), err(0, "bytes2"));
+    }
+
+    #[test]
+    fn coerce_ints() {
+        assert_eq!(
+            coerce("uint8", "255"),
+            Ok(DynSolValue::Uint(U256::from(255), 8))
+        );
+        assert_eq!(
+            coerce("uint8", "0xff"),
+            Ok(DynSolValue::Uint(U256::from(255), 8))
+        );
+        assert_eq!(coerce("uint8", "256"), err(0, "uint8"));
+        assert_eq!(coerce("uint8", "-1"), err(0, "uint8"));
+        assert_eq!(coerce("uint8", "1_0"), err(0, "uint8"));
+        assert_eq!(coerce("uint8", "0x"), err(0, "uint8"));
+        assert_eq!(
+            coerce("uint256", &U256::MAX.to_string()),
+            Ok(DynSolValue::Uint(U256::MAX, 256))
+        );
+
+        assert_eq!(
+            coerce("int8", "127"),
+            Ok(DynSolValue::Int(I256::try_from(127).unwrap(), 8))
+        );
+        assert_eq!(
+            coerce("int8", "-128"),
+            Ok(DynSolValue::Int(I256::try_from(-128).unwrap(), 8))
+        );
+        assert_eq!(
+            coerce("int8", "-0x80"),
+            Ok(DynSolValue::Int(I256::try_from(-128).unwrap(), 8))
+        );
+        assert_eq!(coerce("int8", "128"), err(0, "int8"));
+        assert_eq!(coerce("int8", "-129"), err(0, "int8"));
+        assert_eq!(
+            coerce("int256", &I256::MIN.to_string()),
+            Ok(DynSolValue::Int(I256::MIN, 256))
+        );
+        assert_eq!(
+            coerce("int256", &I256::MAX.to_string()),
+            Ok(DynSolValue::Int(I256::MAX, 256))
+        );
+        assert_eq!(
+            coerce("int256", &U256::from(1).wrapping_shl(255).to_string()),
+            err(0, "int256")
+        );
+    }
+
+    #[test]
+    fn coerce_strings() {
+        let string = |s: &str| Ok(DynSolValue::String(s.into()));
+        assert_eq!(coerce("string", "hello, world "), string("hello, world"));
+        assert_eq!(coerce("string", ""), string(""));
+        assert_eq!(coerce("string", r#" "a \"b\" \\" "#), string(r#"a "b" \"#));
+        assert_eq!(coerce("string", r#""a" b"#), err(4, "end of input"));
+        assert_eq!(coerce("string", r#""a"#), err(2, "closing `\"`"));
+        assert_eq!(
+            coerce("string", r#""\n""#),
+            err(2, "`\"` or `\\` after `\\`")
+        );
+
+        assert_eq!(
+            coerce("string[]", "[a b, \"c,d\" , ]"),
+            Ok(DynSolValue::Array(vec![
+                DynSolValue::String("a b".into()),
+                DynSolValue::String("c,d".into()),
+                DynSolValue::String("".into()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn coerce_composites() {
+        assert_eq!(
+            coerce("uint8[2][]", "[[1, 2], [3,4]]"),
+            Ok(DynSolValue::Array(vec![
+                DynSolValue::FixedArray(vec![
+                    DynSolValue::Uint(U256::from(1), 8),
+                    DynSolValue::Uint(U256::from(2), 8),
+                ]),
+                DynSolValue::FixedArray(vec![
+                    DynSolValue::Uint(U256::from(3), 8),
+                    DynSolValue::Uint(U256::from(4), 8),
+                ]),
+            ]))
+        );
+        assert_eq!(coerce("bool[]", " [ ] "), Ok(DynSolValue::Array(vec![])));
+        assert_eq!(
+            coerce("(bool,(bytes))", "(true, (0x01))"),
+            Ok(DynSolValue::Tuple(vec![
+                DynSolValue::Bool(true),
+                DynSolValue::Tuple(vec![DynSolValue::Bytes(vec![1])])
+            ]))
+        );
+
+        assert_eq!(coerce("bool[]", "true"), err(0, "`[`"));
+        assert_eq!(coerce("bool[]", "[true false]"), err(6, "`,` or `]`"));
+        assert_eq!(coerce("bool[]", "[true,"), err(6, "bool"));
+        assert_eq!(coerce("bool[]", "[true]]"), err(6, "end of input"));
+        assert_eq!(coerce("bool[2]", "[true]"), err(0, "2 array elements"));
+        assert_eq!(coerce("(bool,bool)", "(true)"), err(0, "2 tuple elements"));
+        assert_eq!(coerce("(bool,bool)", "(true, true, true)"), err(13, "`)`"));
+        assert_eq!(
+            coerce("(uint8,(bool,uint8))", "(1, (true, 1000))"),
+            err(11, "uint8")
+        );
+        assert_eq!(
+            DynSolType::FixedArray(Box::new(DynSolType::Bool), 0).coerce_str("[]"),
+            Ok(DynSolValue::FixedArray(vec![]))
+        );
+    }
+
+    #[test]
+    fn coerce_roundtrip() {
+        let ty: DynSolType = "(address,bytes32,int16[])".parse().unwrap();
+        let value = ty
+            .coerce_str(
+                "(0x00000000219ab540356cBB839Cbe05303d7705Fa, \
+                 0x0000000000000000000000000000000000000000000000000000000000000001, \
+                 [-1, 0x7fff])",
+            )
+            .unwrap();
+        let encoded = value.encode_params();
+        assert_eq!(ty.decode_params(&encoded, true).unwrap(), value);
+        assert_eq!(
+            encoded[32..64],
+            hex!("0000000000000000000000000000000000000000000000000000000000000001")
+        );
+    }
+}
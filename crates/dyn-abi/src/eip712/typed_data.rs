@@ -711,4 +711,60 @@ mod tests {
             "25c3d40a39e639a4d0b6e4d2ace5e1281e039c88494d97d8d08f99a6ea75d775",
         );
     }
+
+    /// The example from the EIP, as sent by wallets to `eth_signTypedData_v4`.
+    #[test]
+    fn eip712_example_payload() {
+        let payload = |chain_id: serde_json::Value| {
+            json!({
+                "types": {
+                    "EIP712Domain": [
+                        { "name": "name", "type": "string" },
+                        { "name": "version", "type": "string" },
+                        { "name": "chainId", "type": "uint256" },
+                        { "name": "verifyingContract", "type": "address" }
+                    ],
+                    "Person": [
+                        { "name": "name", "type": "string" },
+                        { "name": "wallet", "type": "address" }
+                    ],
+                    "Mail": [
+                        { "name": "from", "type": "Person" },
+                        { "name": "to", "type": "Person" },
+                        { "name": "contents", "type": "string" }
+                    ]
+                },
+                "primaryType": "Mail",
+                "domain": {
+                    "name": "Ether Mail",
+                    "version": "1",
+                    "chainId": chain_id,
+                    "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+                },
+                "message": {
+                    "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+                    "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+                    "contents": "Hello, Bob!"
+                }
+            })
+        };
+        let expected = "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+
+        // wallets send the chain ID as a number, or as a decimal or hex string
+        for chain_id in [json!(1), json!("1"), json!("0x1")] {
+            let json = payload(chain_id);
+            let typed_data: TypedData = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(
+                hex::encode(typed_data.eip712_signing_hash().unwrap()),
+                expected
+            );
+
+            // the payload may also be stringified
+            let typed_data: TypedData = serde_json::from_value(json.to_string().into()).unwrap();
+            assert_eq!(
+                hex::encode(typed_data.eip712_signing_hash().unwrap()),
+                expected
+            );
+        }
+    }
 }
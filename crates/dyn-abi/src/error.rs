@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloy_sol_type_parser::Error as TypeParserError;
 use core::fmt;
 
@@ -27,10 +28,22 @@ pub enum DynAbiError {
     #[cfg(feature = "eip712")]
     InvalidPropertyDefinition(alloc::string::String),
 
+    /// Invalid value string passed to [`DynSolType::coerce_str`].
+    ///
+    /// [`DynSolType::coerce_str`]: crate::DynSolType::coerce_str
+    InvalidValueString {
+        /// The byte offset in the input at which the error occurred.
+        position: usize,
+        /// A description of what was expected at that position.
+        expected: Cow<'static, str>,
+    },
+
     /// Hex.
     HexError(hex::FromHexError),
     /// Type Str Error
     TypeParserError(TypeParserError),
+    /// Encoding or decoding error.
+    SolTypesError(alloy_sol_types::Error),
 }
 
 impl From<TypeParserError> for DynAbiError {
@@ -40,6 +53,13 @@ impl From<TypeParserError> for DynAbiError {
     }
 }
 
+impl From<alloy_sol_types::Error> for DynAbiError {
+    #[inline]
+    fn from(e: alloy_sol_types::Error) -> Self {
+        Self::SolTypesError(e)
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for DynAbiError {
     #[inline]
@@ -48,6 +68,7 @@ impl std::error::Error for DynAbiError {
         match self {
             Self::HexError(e) => Some(e),
             Self::TypeParserError(e) => Some(e),
+            Self::SolTypesError(e) => Some(e),
             _ => None,
         }
     }
@@ -69,8 +90,16 @@ impl fmt::Display for DynAbiError {
                 write!(f, "Invalid property definition: {def}")
             }
 
+            DynAbiError::InvalidValueString { position, expected } => {
+                write!(
+                    f,
+                    "Invalid value string at position {position}: expected {expected}"
+                )
+            }
+
             DynAbiError::HexError(h) => h.fmt(f),
             DynAbiError::TypeParserError(e) => e.fmt(f),
+            DynAbiError::SolTypesError(e) => e.fmt(f),
         }
     }
 }
@@ -83,6 +112,17 @@ impl From<hex::FromHexError> for DynAbiError {
 
 #[allow(dead_code)]
 impl DynAbiError {
+    #[inline]
+    pub(crate) fn invalid_value_string(
+        position: usize,
+        expected: impl Into<Cow<'static, str>>,
+    ) -> DynAbiError {
+        DynAbiError::InvalidValueString {
+            position,
+            expected: expected.into(),
+        }
+    }
+
     #[cfg(feature = "eip712")]
     #[inline]
     pub(crate) fn type_mismatch(
@@ -0,0 +1,539 @@
+//! Decoding and pretty-printing calls, return data, reverts and logs.
+//!
+//! An [`Explainer`] holds a set of ABI items, either from a [`JsonAbi`] or
+//! parsed from their [human-readable ABI], and matches raw data against them
+//! by selector or topic. The decoded items render in a Solidity-like syntax,
+//! like `transfer(to: 0x…, amount: 1000000)`.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_dyn_abi::{DynSolValue, Explainer, JsonAbiExt};
+//! use alloy_json_abi::Function;
+//! use alloy_primitives::{Address, U256};
+//!
+//! let explainer = Explainer::parse([
+//!     "function transfer(address to, uint256 amount) returns (bool)",
+//!     "event Transfer(address indexed from, address indexed to, uint256 value)",
+//! ])?;
+//!
+//! let transfer = Function::parse("transfer(address,uint256)")?;
+//! let calldata =
+//!     transfer.abi_encode_input(&[Address::ZERO.into(), U256::from(1000000).into()])?;
+//!
+//! let call = explainer.explain_call(&calldata)?;
+//! assert_eq!(
+//!     call.to_string(),
+//!     "transfer(to: 0x0000000000000000000000000000000000000000, amount: 1000000)"
+//! );
+//!
+//! let ret = explainer.explain_return(&calldata, &DynSolValue::Bool(true).encode_params())?;
+//! assert_eq!(
+//!     ret.to_string(),
+//!     "transfer(to: 0x0000000000000000000000000000000000000000, amount: 1000000) -> (true)"
+//! );
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [human-readable ABI]: alloy_json_abi#human-readable-abi
+
+use crate::{DynAbiResult, DynSolValue, EventExt, FunctionExt, JsonAbiExt};
+use alloc::{string::String, vec::Vec};
+use alloy_json_abi::{Error as AbiError, Event, Function, JsonAbi, Param};
+use alloy_primitives::{FixedBytes, B256};
+use alloy_sol_types::{Error, Panic, Revert, SolError};
+use core::fmt::{self, Display};
+
+/// Matches raw calldata, return data, revert data and logs against a set of
+/// ABI items to decode them.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Explainer {
+    functions: Vec<Function>,
+    events: Vec<Event>,
+    errors: Vec<AbiError>,
+}
+
+impl From<JsonAbi> for Explainer {
+    fn from(abi: JsonAbi) -> Self {
+        Self {
+            functions: abi.functions.into_values().flatten().collect(),
+            events: abi.events.into_values().flatten().collect(),
+            errors: abi.errors.into_values().flatten().collect(),
+        }
+    }
+}
+
+impl Explainer {
+    /// Creates a new explainer from the functions, events and errors of a
+    /// JSON ABI.
+    pub fn new(abi: &JsonAbi) -> Self {
+        Self {
+            functions: abi.functions().cloned().collect(),
+            events: abi.events().cloned().collect(),
+            errors: abi.errors().cloned().collect(),
+        }
+    }
+
+    /// Creates a new explainer by parsing human-readable ABI items.
+    ///
+    /// Each item must start with the `function`, `event` or `error` keyword.
+    /// Parsing requires the `std` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the items fails to parse.
+    #[cfg(feature = "std")]
+    pub fn parse<I, S>(items: I) -> alloy_sol_type_parser::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut this = Self::default();
+        for item in items {
+            let item = item.as_ref().trim();
+            let keyword = item.split(|c: char| c.is_whitespace()).next();
+            match keyword {
+                Some("function") => this.functions.push(Function::parse(item)?),
+                Some("event") => this.events.push(Event::parse(item)?),
+                Some("error") => this.errors.push(AbiError::parse(item)?),
+                _ => return Err(alloy_sol_type_parser::Error::invalid_type_string(item)),
+            }
+        }
+        Ok(this)
+    }
+
+    /// Returns the functions known to this explainer.
+    #[inline]
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    /// Returns the events known to this explainer.
+    #[inline]
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the errors known to this explainer.
+    #[inline]
+    pub fn errors(&self) -> &[AbiError] {
+        &self.errors
+    }
+
+    /// Decodes function calldata, prefixed with the function selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownSelector`] if no function matches the
+    /// selector, or an error if decoding the arguments fails.
+    pub fn explain_call(&self, calldata: &[u8]) -> DynAbiResult<Explained> {
+        let function = self.function(calldata)?;
+        let values = function.abi_decode_input(calldata, false)?;
+        Ok(Explained::new(&function.name, &function.inputs, values))
+    }
+
+    /// Decodes a function call along with the data it returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownSelector`] if no function matches the selector
+    /// of the calldata, or an error if decoding the arguments or the return
+    /// data fails.
+    pub fn explain_return(&self, calldata: &[u8], output: &[u8]) -> DynAbiResult<ExplainedReturn> {
+        let function = self.function(calldata)?;
+        let values = function.abi_decode_input(calldata, false)?;
+        let outputs = function.abi_decode_output(output, false)?;
+        Ok(ExplainedReturn {
+            call: Explained::new(&function.name, &function.inputs, values),
+            outputs: params(&function.outputs, outputs),
+        })
+    }
+
+    /// Decodes revert data, prefixed with the error selector.
+    ///
+    /// In addition to the known errors, this recognizes the built-in
+    /// `Error(string)` and `Panic(uint256)` errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownSelector`] if no error matches the selector, or
+    /// an error if decoding the arguments fails.
+    pub fn explain_revert(&self, data: &[u8]) -> DynAbiResult<Explained> {
+        let selector = selector(data)?;
+        if selector == Revert::SELECTOR {
+            let revert = Revert::decode(data, false)?;
+            return Ok(Explained::unnamed("Error", revert.reason.into()))
+        }
+        if selector == Panic::SELECTOR {
+            let panic = Panic::decode(data, false)?;
+            return Ok(Explained::unnamed("Panic", panic.code.into()))
+        }
+
+        let error = self
+            .errors
+            .iter()
+            .find(|e| e.selector() == selector)
+            .ok_or(Error::UnknownSelector {
+                name: "error",
+                selector,
+            })?;
+        let values = error.abi_decode_input(data, false)?;
+        Ok(Explained::new(&error.name, &error.inputs, values))
+    }
+
+    /// Decodes a log from its topics and data.
+    ///
+    /// Events are matched by their signature hash, the first topic. If none
+    /// matches, the first anonymous event which successfully decodes the log
+    /// is used instead.
+    ///
+    /// Indexed parameters of dynamic types are only stored as the hash of
+    /// their value, so they are rendered as a `bytes32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no event matches, or if decoding fails.
+    pub fn explain_log(&self, topics: &[B256], data: &[u8]) -> DynAbiResult<Explained> {
+        if let Some(topic) = topics.first() {
+            let event = self
+                .events
+                .iter()
+                .find(|e| !e.anonymous && e.selector() == *topic);
+            if let Some(event) = event {
+                return explain_event(event, topics, data)
+            }
+        }
+
+        self.events
+            .iter()
+            .filter(|e| e.anonymous)
+            .find_map(|event| explain_event(event, topics, data).ok())
+            .ok_or_else(|| {
+                match topics.first() {
+                    Some(topic) => Error::custom(format!("no event matches topic {topic}")),
+                    None => Error::custom("no anonymous event matches the log"),
+                }
+                .into()
+            })
+    }
+
+    fn function(&self, calldata: &[u8]) -> DynAbiResult<&Function> {
+        let selector = selector(calldata)?;
+        self.functions
+            .iter()
+            .find(|f| f.selector() == selector)
+            .ok_or_else(|| {
+                Error::UnknownSelector {
+                    name: "function",
+                    selector,
+                }
+                .into()
+            })
+    }
+}
+
+/// A decoded function call, error or event.
+///
+/// Its [`Display`](fmt::Display) implementation renders it like
+/// `name(param: value, ...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Explained {
+    /// The name of the item.
+    pub name: String,
+    /// The decoded parameters, in declaration order.
+    pub params: Vec<ExplainedParam>,
+}
+
+impl fmt::Display for Explained {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        fmt_params(&self.params, f)
+    }
+}
+
+impl Explained {
+    fn new(name: &str, params: &[Param], values: Vec<DynSolValue>) -> Self {
+        Self {
+            name: name.into(),
+            params: self::params(params, values),
+        }
+    }
+
+    fn unnamed(name: &str, value: DynSolValue) -> Self {
+        Self {
+            name: name.into(),
+            params: vec![ExplainedParam {
+                name: String::new(),
+                value,
+            }],
+        }
+    }
+}
+
+/// A decoded function call and the data it returned.
+///
+/// Its [`Display`](fmt::Display) implementation renders it like
+/// `name(param: value, ...) -> (output: value, ...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplainedReturn {
+    /// The decoded call.
+    pub call: Explained,
+    /// The decoded outputs, in declaration order.
+    pub outputs: Vec<ExplainedParam>,
+}
+
+impl fmt::Display for ExplainedReturn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> ", self.call)?;
+        fmt_params(&self.outputs, f)
+    }
+}
+
+/// A decoded parameter.
+///
+/// Its [`Display`](fmt::Display) implementation renders it like
+/// `name: value`, or only `value` if the parameter is unnamed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplainedParam {
+    /// The name of the parameter. May be empty.
+    pub name: String,
+    /// The decoded value.
+    pub value: DynSolValue,
+}
+
+impl fmt::Display for ExplainedParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.name.is_empty() {
+            write!(f, "{}: ", self.name)?;
+        }
+        fmt_value(&self.value, f)
+    }
+}
+
+fn explain_event(event: &Event, topics: &[B256], data: &[u8]) -> DynAbiResult<Explained> {
+    let decoded = event.decode_log(topics, data, false)?;
+    let mut indexed = decoded.indexed.into_iter();
+    let mut body = decoded.body.into_iter();
+    let params = event
+        .inputs
+        .iter()
+        .map(|param| ExplainedParam {
+            name: param.name.clone(),
+            value: if param.indexed {
+                indexed.next()
+            } else {
+                body.next()
+            }
+            .unwrap(),
+        })
+        .collect();
+    Ok(Explained {
+        name: event.name.clone(),
+        params,
+    })
+}
+
+fn selector(data: &[u8]) -> DynAbiResult<FixedBytes<4>> {
+    data.get(..4)
+        .map(FixedBytes::from_slice)
+        .ok_or_else(|| Error::Overrun.into())
+}
+
+fn params(params: &[Param], values: Vec<DynSolValue>) -> Vec<ExplainedParam> {
+    params
+        .iter()
+        .zip(values)
+        .map(|(param, value)| ExplainedParam {
+            name: param.name.clone(),
+            value,
+        })
+        .collect()
+}
+
+fn fmt_params(params: &[ExplainedParam], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("(")?;
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        param.fmt(f)?;
+    }
+    f.write_str(")")
+}
+
+fn fmt_seq(values: &[DynSolValue], delims: [&str; 2], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(delims[0])?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        fmt_value(value, f)?;
+    }
+    f.write_str(delims[1])
+}
+
+fn fmt_value(value: &DynSolValue, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match value {
+        DynSolValue::Address(address) => address.fmt(f),
+        DynSolValue::Bool(b) => b.fmt(f),
+        DynSolValue::Int(int, _) => int.fmt(f),
+        DynSolValue::Uint(uint, _) => uint.fmt(f),
+        DynSolValue::FixedBytes(word, size) => f.write_str(&hex::encode_prefixed(&word[..*size])),
+        DynSolValue::Bytes(bytes) => f.write_str(&hex::encode_prefixed(bytes)),
+        DynSolValue::String(s) => write!(f, "{s:?}"),
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            fmt_seq(values, ["[", "]"], f)
+        }
+        DynSolValue::Tuple(values) => fmt_seq(values, ["(", ")"], f),
+        #[cfg(feature = "eip712")]
+        DynSolValue::CustomStruct {
+            name,
+            prop_names,
+            tuple,
+        } => {
+            write!(f, "{name} {{ ")?;
+            for (i, (prop, value)) in prop_names.iter().zip(tuple).enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{prop}: ")?;
+                fmt_value(value, f)?;
+            }
+            f.write_str(" }")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, keccak256, Address, I256, U256};
+    use alloy_sol_types::PanicKind;
+
+    fn explainer() -> Explainer {
+        Explainer::parse([
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function balanceOf(address) view returns (uint256 balance)",
+            "function batch((address,uint96)[] calls, bytes data)",
+            "event Transfer(address indexed from, address indexed to, uint256 value)",
+            "event Named(string indexed name, int8 delta) anonymous",
+            "error InsufficientBalance(uint256 available, uint256 required)",
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_items() {
+        let explainer = explainer();
+        assert_eq!(explainer.functions().len(), 3);
+        assert_eq!(explainer.events().len(), 2);
+        assert_eq!(explainer.errors().len(), 1);
+
+        assert!(Explainer::parse(["transfer(address,uint256)"]).is_err());
+        assert!(Explainer::parse(["function transfer(address,"]).is_err());
+    }
+
+    #[test]
+    fn explain_calls() {
+        let explainer = explainer();
+        let owner = address!("00000000219ab540356cBB839Cbe05303d7705Fa");
+
+        let balance_of = &explainer.functions()[1];
+        let calldata = balance_of.abi_encode_input(&[owner.into()]).unwrap();
+        let call = explainer.explain_call(&calldata).unwrap();
+        assert_eq!(
+            call.to_string(),
+            "balanceOf(0x00000000219ab540356cBB839Cbe05303d7705Fa)"
+        );
+        assert_eq!(
+            explainer.explain_return(&calldata, &[]),
+            Err(Error::Overrun.into())
+        );
+        let output = DynSolValue::from(U256::from(5)).encode_params();
+        let ret = explainer.explain_return(&calldata, &output).unwrap();
+        assert_eq!(ret.outputs[0].name, "balance");
+        assert_eq!(
+            ret.to_string(),
+            "balanceOf(0x00000000219ab540356cBB839Cbe05303d7705Fa) -> (balance: 5)"
+        );
+
+        let batch = &explainer.functions()[2];
+        let calls = DynSolValue::Array(vec![DynSolValue::Tuple(vec![
+            owner.into(),
+            DynSolValue::Uint(U256::from(1), 96),
+        ])]);
+        let calldata = batch
+            .abi_encode_input(&[calls, DynSolValue::Bytes(vec![0xde, 0xad])])
+            .unwrap();
+        assert_eq!(
+            explainer.explain_call(&calldata).unwrap().to_string(),
+            "batch(calls: [(0x00000000219ab540356cBB839Cbe05303d7705Fa, 1)], data: 0xdead)"
+        );
+
+        assert_eq!(explainer.explain_call(&[0xa9]), Err(Error::Overrun.into()));
+        assert_eq!(
+            explainer.explain_call(&[1, 2, 3, 4]),
+            Err(Error::UnknownSelector {
+                name: "function",
+                selector: [1, 2, 3, 4].into(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn explain_reverts() {
+        let explainer = explainer();
+
+        let error = &explainer.errors()[0];
+        let data = error
+            .abi_encode_input(&[U256::from(1).into(), U256::from(2).into()])
+            .unwrap();
+        assert_eq!(
+            explainer.explain_revert(&data).unwrap().to_string(),
+            "InsufficientBalance(available: 1, required: 2)"
+        );
+
+        let data = Revert::from("nope").encode();
+        assert_eq!(
+            explainer.explain_revert(&data).unwrap().to_string(),
+            r#"Error("nope")"#
+        );
+        let data = Panic::from(PanicKind::UnderOverflow).encode();
+        assert_eq!(
+            explainer.explain_revert(&data).unwrap().to_string(),
+            "Panic(17)"
+        );
+    }
+
+    #[test]
+    fn explain_logs() {
+        let explainer = explainer();
+        let (from, to) = (Address::repeat_byte(1), Address::repeat_byte(2));
+
+        let transfer = &explainer.events()[0];
+        let topics = [transfer.selector(), from.into_word(), to.into_word()];
+        let data = DynSolValue::from(U256::from(3)).encode_params();
+        let log = explainer.explain_log(&topics, &data).unwrap();
+        assert_eq!(log.params[1].name, "to");
+        assert_eq!(log.params[1].value, DynSolValue::Address(to));
+        assert_eq!(
+            log.to_string(),
+            format!("Transfer(from: {from}, to: {to}, value: 3)")
+        );
+
+        let name = keccak256("alice");
+        let data = DynSolValue::Int(I256::try_from(-1).unwrap(), 8).encode_params();
+        assert_eq!(
+            explainer.explain_log(&[name], &data).unwrap().to_string(),
+            format!("Named(name: {name}, delta: -1)")
+        );
+
+        assert!(explainer
+            .explain_log(&[B256::ZERO, B256::ZERO], &data)
+            .is_err());
+        assert!(explainer.explain_log(&[], &data).is_err());
+    }
+}
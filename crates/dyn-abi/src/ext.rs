@@ -0,0 +1,405 @@
+//! Encoding and decoding with JSON ABI items.
+//!
+//! These traits resolve the parameters of [`alloy_json_abi`] items into
+//! [`DynSolType`]s to encode and decode their data at runtime. Combined with
+//! the [human-readable ABI] parser, this allows interacting with contracts
+//! whose interface is only known at runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_dyn_abi::{DynSolValue, EventExt, FunctionExt, JsonAbiExt};
+//! use alloy_json_abi::{Event, Function};
+//! use alloy_primitives::{Address, U256};
+//!
+//! let transfer = Function::parse("function transfer(address to, uint256 amount) returns (bool)")?;
+//! let args = [DynSolValue::Address(Address::repeat_byte(1)), U256::from(100).into()];
+//! let calldata = transfer.abi_encode_input(&args)?;
+//! assert_eq!(calldata[..4], transfer.selector());
+//! assert_eq!(transfer.abi_decode_input(&calldata, true)?, args);
+//!
+//! let output = transfer.abi_decode_output(&DynSolValue::Bool(true).encode_params(), true)?;
+//! assert_eq!(output, [DynSolValue::Bool(true)]);
+//!
+//! let event = Event::parse("event Transfer(address indexed from, address indexed to, uint256 value)")?;
+//! let (from, to) = (Address::repeat_byte(2), Address::repeat_byte(3));
+//! let topics = [event.selector(), from.into_word(), to.into_word()];
+//! let data = DynSolValue::Uint(U256::from(100), 256).encode_params();
+//! let decoded = event.decode_log(&topics, &data, true)?;
+//! assert_eq!(decoded.indexed, [DynSolValue::Address(from), DynSolValue::Address(to)]);
+//! assert_eq!(decoded.body, [DynSolValue::Uint(U256::from(100), 256)]);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [human-readable ABI]: alloy_json_abi#human-readable-abi
+
+use crate::{DynAbiResult, DynSolType, DynSolValue, Encoder, Error, ResolveSolType};
+use alloc::{borrow::Cow, vec::Vec};
+use alloy_json_abi::{Error as AbiError, Event, Function, Param};
+use alloy_primitives::{FixedBytes, Selector, B256};
+
+/// Encoding and decoding the inputs of [`Function`]s and [`Error`](AbiError)s.
+pub trait JsonAbiExt {
+    /// Encodes the given values as the inputs of this item, prefixed with its
+    /// selector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number or the types of the values do not match
+    /// the inputs.
+    fn abi_encode_input(&self, values: &[DynSolValue]) -> DynAbiResult<Vec<u8>>;
+
+    /// Decodes the inputs of this item from data prefixed with its selector.
+    ///
+    /// See the [`alloy_sol_types` documentation][modes] for the meaning of the
+    /// `validate` flag.
+    ///
+    /// [modes]: alloy_sol_types#decoding-modes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the selector does not match, or if decoding fails.
+    fn abi_decode_input(&self, data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>>;
+}
+
+/// Encoding and decoding the outputs of [`Function`]s.
+pub trait FunctionExt: JsonAbiExt {
+    /// Encodes the given values as the outputs of this function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number or the types of the values do not match
+    /// the outputs.
+    fn abi_encode_output(&self, values: &[DynSolValue]) -> DynAbiResult<Vec<u8>>;
+
+    /// Decodes the outputs of this function.
+    ///
+    /// See the [`alloy_sol_types` documentation][modes] for the meaning of the
+    /// `validate` flag.
+    ///
+    /// [modes]: alloy_sol_types#decoding-modes
+    fn abi_decode_output(&self, data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>>;
+}
+
+/// Decoding the logs of [`Event`]s.
+pub trait EventExt {
+    /// Decodes a log emitted by this event.
+    ///
+    /// Indexed parameters of dynamic types, like `string` or tuples, are only
+    /// stored as the hash of their value, which is returned as a
+    /// [`DynSolValue::FixedBytes`].
+    ///
+    /// See the [`alloy_sol_types` documentation][modes] for the meaning of the
+    /// `validate` flag.
+    ///
+    /// [modes]: alloy_sol_types#decoding-modes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of topics or the event signature does
+    /// not match, or if decoding fails.
+    fn decode_log(
+        &self,
+        topics: &[B256],
+        data: &[u8],
+        validate: bool,
+    ) -> DynAbiResult<DecodedEvent>;
+}
+
+/// A decoded event log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    /// The indexed parameters, decoded from the topics, in order.
+    pub indexed: Vec<DynSolValue>,
+    /// The non-indexed parameters, decoded from the data, in order.
+    pub body: Vec<DynSolValue>,
+}
+
+impl JsonAbiExt for Function {
+    #[inline]
+    fn abi_encode_input(&self, values: &[DynSolValue]) -> DynAbiResult<Vec<u8>> {
+        encode_with_selector(self.selector(), &self.inputs, values)
+    }
+
+    #[inline]
+    fn abi_decode_input(&self, data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>> {
+        decode_with_selector("function", self.selector(), &self.inputs, data, validate)
+    }
+}
+
+impl FunctionExt for Function {
+    #[inline]
+    fn abi_encode_output(&self, values: &[DynSolValue]) -> DynAbiResult<Vec<u8>> {
+        encode_params(&self.outputs, values)
+    }
+
+    #[inline]
+    fn abi_decode_output(&self, data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>> {
+        decode_params(&self.outputs, data, validate)
+    }
+}
+
+impl JsonAbiExt for AbiError {
+    #[inline]
+    fn abi_encode_input(&self, values: &[DynSolValue]) -> DynAbiResult<Vec<u8>> {
+        encode_with_selector(self.selector(), &self.inputs, values)
+    }
+
+    #[inline]
+    fn abi_decode_input(&self, data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>> {
+        decode_with_selector("error", self.selector(), &self.inputs, data, validate)
+    }
+}
+
+impl EventExt for Event {
+    fn decode_log(
+        &self,
+        topics: &[B256],
+        data: &[u8],
+        validate: bool,
+    ) -> DynAbiResult<DecodedEvent> {
+        let mut topics = topics.iter();
+        let num_indexed = self.inputs.iter().filter(|p| p.indexed).count();
+        let expected = num_indexed + !self.anonymous as usize;
+        if topics.len() != expected {
+            return Err(Error::TopicLengthMismatch {
+                expected,
+                actual: topics.len(),
+            }
+            .into())
+        }
+        if !self.anonymous {
+            let expected = self.selector();
+            let got = *topics.next().unwrap();
+            if got != expected {
+                return Err(Error::EventSignatureMismatch { expected, got }.into())
+            }
+        }
+
+        let mut indexed = Vec::with_capacity(num_indexed);
+        let mut body = Vec::new();
+        for param in &self.inputs {
+            if param.indexed {
+                let topic = topics.next().unwrap();
+                let ty = param.resolve()?;
+                indexed.push(if is_hashed(&ty) {
+                    DynSolValue::FixedBytes(*topic, 32)
+                } else {
+                    ty.decode_single(topic.as_slice(), validate)?
+                });
+            } else {
+                body.push(param.resolve()?);
+            }
+        }
+        let body = decode_types(body, data, validate)?;
+        Ok(DecodedEvent { indexed, body })
+    }
+}
+
+/// Returns whether a value of this type is hashed when it is indexed.
+#[inline]
+const fn is_hashed(ty: &DynSolType) -> bool {
+    !matches!(
+        ty,
+        DynSolType::Address
+            | DynSolType::Bool
+            | DynSolType::Int(_)
+            | DynSolType::Uint(_)
+            | DynSolType::FixedBytes(_)
+    )
+}
+
+fn encode_with_selector(
+    selector: Selector,
+    params: &[Param],
+    values: &[DynSolValue],
+) -> DynAbiResult<Vec<u8>> {
+    let encoded = encode_params(params, values)?;
+    let mut data = Vec::with_capacity(4 + encoded.len());
+    data.extend_from_slice(&selector);
+    data.extend_from_slice(&encoded);
+    Ok(data)
+}
+
+fn encode_params(params: &[Param], values: &[DynSolValue]) -> DynAbiResult<Vec<u8>> {
+    if params.len() != values.len() {
+        return Err(Error::Other(Cow::Owned(format!(
+            "expected {} values, got {}",
+            params.len(),
+            values.len()
+        )))
+        .into())
+    }
+    for (param, value) in params.iter().zip(values) {
+        let ty = param.resolve()?;
+        if !ty.matches(value) {
+            return Err(Error::Other(Cow::Owned(format!(
+                "value for parameter {:?} does not match type {ty}",
+                param.name
+            )))
+            .into())
+        }
+    }
+    let mut encoder = Encoder::with_capacity(values.iter().map(DynSolValue::total_words).sum());
+    DynSolValue::encode_sequence(values, &mut encoder);
+    Ok(encoder.into_bytes())
+}
+
+fn decode_with_selector(
+    name: &'static str,
+    selector: Selector,
+    params: &[Param],
+    data: &[u8],
+    validate: bool,
+) -> DynAbiResult<Vec<DynSolValue>> {
+    if data.len() < 4 {
+        return Err(Error::Overrun.into())
+    }
+    let (got, data) = data.split_at(4);
+    if got != selector {
+        return Err(Error::UnknownSelector {
+            name,
+            selector: FixedBytes::from_slice(got),
+        }
+        .into())
+    }
+    decode_params(params, data, validate)
+}
+
+fn decode_params(params: &[Param], data: &[u8], validate: bool) -> DynAbiResult<Vec<DynSolValue>> {
+    let types = params
+        .iter()
+        .map(Param::resolve)
+        .collect::<DynAbiResult<Vec<_>>>()?;
+    decode_types(types, data, validate)
+}
+
+fn decode_types(
+    types: Vec<DynSolType>,
+    data: &[u8],
+    validate: bool,
+) -> DynAbiResult<Vec<DynSolValue>> {
+    match DynSolType::Tuple(types).decode_sequence(data, validate)? {
+        DynSolValue::Tuple(values) => Ok(values),
+        _ => unreachable!("decoded a tuple type"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynAbiError;
+    use alloc::string::String;
+    use alloy_primitives::{keccak256, Address, U256};
+
+    #[test]
+    fn function_inputs() {
+        let f = Function::parse("f((address a, string[] b)[] orders, uint8 x)").unwrap();
+        let order = DynSolValue::Tuple(vec![
+            DynSolValue::Address(Address::repeat_byte(1)),
+            DynSolValue::Array(vec![DynSolValue::String(String::from("hi"))]),
+        ]);
+        let args = [
+            DynSolValue::Array(vec![order.clone(), order]),
+            DynSolValue::Uint(U256::from(3), 8),
+        ];
+        let data = f.abi_encode_input(&args).unwrap();
+        assert_eq!(data[..4], keccak256("f((address,string[])[],uint8)")[..4]);
+        assert_eq!(data[4..], DynSolValue::Tuple(args.to_vec()).encode_params());
+        assert_eq!(f.abi_decode_input(&data, true).unwrap(), args);
+
+        assert_eq!(
+            f.abi_encode_input(&args[..1]),
+            Err(Error::Other("expected 2 values, got 1".into()).into())
+        );
+        assert_eq!(
+            f.abi_encode_input(&[args[1].clone(), args[0].clone()]),
+            Err(Error::Other(
+                "value for parameter \"orders\" does not match type (address,string[])[]".into()
+            )
+            .into())
+        );
+
+        let mut wrong = data.clone();
+        wrong[0] ^= 1;
+        assert!(matches!(
+            f.abi_decode_input(&wrong, true),
+            Err(DynAbiError::SolTypesError(Error::UnknownSelector {
+                name: "function",
+                ..
+            }))
+        ));
+        assert_eq!(
+            f.abi_decode_input(&data[..3], true),
+            Err(Error::Overrun.into())
+        );
+    }
+
+    #[test]
+    fn function_outputs() {
+        let f = Function::parse("f() returns (bool, bytes)").unwrap();
+        let values = [DynSolValue::Bool(true), DynSolValue::Bytes(vec![1, 2, 3])];
+        let data = f.abi_encode_output(&values).unwrap();
+        assert_eq!(f.abi_decode_output(&data, true).unwrap(), values);
+
+        let f = Function::parse("f()").unwrap();
+        assert!(f.abi_encode_output(&[]).unwrap().is_empty());
+        assert!(f.abi_decode_output(&[], true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        let e = AbiError::parse("error InsufficientBalance(uint256 available)").unwrap();
+        let args = [DynSolValue::Uint(U256::from(1), 256)];
+        let data = e.abi_encode_input(&args).unwrap();
+        assert_eq!(data[..4], e.selector());
+        assert_eq!(e.abi_decode_input(&data, true).unwrap(), args);
+    }
+
+    #[test]
+    fn events() {
+        let e =
+            Event::parse("event E(string indexed s, int16 indexed i, bytes b, bool c)").unwrap();
+        let s = keccak256("hello");
+        let i = DynSolValue::Int(alloy_primitives::I256::try_from(-2).unwrap(), 16);
+        let topics = [e.selector(), s, B256::from_slice(&i.encode_single())];
+        let body = [DynSolValue::Bytes(vec![4]), DynSolValue::Bool(false)];
+        let data = DynSolValue::Tuple(body.to_vec()).encode_params();
+        assert_eq!(
+            e.decode_log(&topics, &data, true).unwrap(),
+            DecodedEvent {
+                indexed: vec![DynSolValue::FixedBytes(s, 32), i],
+                body: body.to_vec(),
+            }
+        );
+
+        assert_eq!(
+            e.decode_log(&topics[..2], &data, true),
+            Err(Error::TopicLengthMismatch {
+                expected: 3,
+                actual: 2
+            }
+            .into())
+        );
+        let mut wrong = topics;
+        wrong[0] = B256::ZERO;
+        assert_eq!(
+            e.decode_log(&wrong, &data, true),
+            Err(Error::EventSignatureMismatch {
+                expected: e.selector(),
+                got: B256::ZERO
+            }
+            .into())
+        );
+
+        let e = Event::parse("event E(address indexed a) anonymous").unwrap();
+        let a = Address::repeat_byte(1);
+        assert_eq!(
+            e.decode_log(&[a.into_word()], &[], true).unwrap(),
+            DecodedEvent {
+                indexed: vec![DynSolValue::Address(a)],
+                body: vec![],
+            }
+        );
+    }
+}
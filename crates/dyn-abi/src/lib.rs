@@ -43,12 +43,20 @@ pub use ty::DynSolType;
 mod value;
 pub use value::DynSolValue;
 
+mod coerce;
+
 mod token;
 pub use token::DynToken;
 
 pub mod resolve;
 pub use resolve::ResolveSolType;
 
+mod ext;
+pub use ext::{DecodedEvent, EventExt, FunctionExt, JsonAbiExt};
+
+pub mod explain;
+pub use explain::{Explained, ExplainedParam, ExplainedReturn, Explainer};
+
 pub use alloy_sol_type_parser as parser;
 
 #[cfg(feature = "eip712")]
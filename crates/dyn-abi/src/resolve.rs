@@ -400,4 +400,20 @@ mod tests {
             Err(TypeStrError::invalid_type_string("MyStruct"))
         );
     }
+
+    #[test]
+    fn error_messages() {
+        let err = |s: &str| parse(s).unwrap_err().to_string();
+        assert_eq!(err("uint256[2"), "Invalid type string: uint256[2");
+        assert_eq!(err("uint256[0]"), "Invalid size for type: uint256[0]");
+        assert_eq!(
+            err("(bool,,address)"),
+            "Invalid type string: (bool,,address)"
+        );
+        assert_eq!(
+            err("(address,(bytes,uint7)[])"),
+            "Invalid size for type: uint7"
+        );
+        assert_eq!(err("MyStruct[]"), "Invalid type string: MyStruct");
+    }
 }
@@ -2,7 +2,8 @@ use crate::{
     resolve::ResolveSolType, DynAbiError, DynAbiResult, DynSolValue, DynToken, Result, SolType,
     Word,
 };
-use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec::Vec};
+use alloy_primitives::U256;
 use alloy_sol_type_parser::TypeSpecifier;
 use alloy_sol_types::sol_data;
 use core::{fmt, num::NonZeroUsize, str::FromStr};
@@ -68,7 +69,7 @@ struct StructProp {
 /// let my_data: DynSolValue = U256::from(183u64).into();
 ///
 /// let encoded = my_data.encode_single();
-/// let decoded = my_type.decode_single(&encoded)?;
+/// let decoded = my_type.decode_single(&encoded, true)?;
 ///
 /// assert_eq!(decoded, my_data);
 ///
@@ -76,7 +77,7 @@ struct StructProp {
 /// let my_data = DynSolValue::Array(vec![my_data.clone()]);
 ///
 /// let encoded = my_data.encode_single();
-/// let decoded = my_type.decode_single(&encoded)?;
+/// let decoded = my_type.decode_single(&encoded, true)?;
 ///
 /// assert_eq!(decoded, my_data);
 /// # Ok::<_, alloy_dyn_abi::Error>(())
@@ -153,6 +154,15 @@ impl DynSolType {
 
     /// Parses a Solidity type name string into a [`DynSolType`].
     ///
+    /// Only types which can be ABI-encoded are supported: elementary types,
+    /// arrays and tuples, written either as `(...)` or as `tuple(...)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DynAbiError::TypeParserError`] containing the invalid part
+    /// of the string: the innermost element type that is not an elementary
+    /// type, or the whole type if it is malformed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -161,6 +171,12 @@ impl DynSolType {
     /// let ty = DynSolType::parse(type_name)?;
     /// assert_eq!(ty, DynSolType::Uint(256));
     /// assert_eq!(ty.sol_type_name(), type_name);
+    ///
+    /// let ty = DynSolType::parse("(address,(bytes,bool)[])")?;
+    /// assert_eq!(ty.sol_type_name(), "(address,(bytes,bool)[])");
+    ///
+    /// let err = DynSolType::parse("(address,uint7)").unwrap_err();
+    /// assert_eq!(err.to_string(), "Invalid size for type: uint7");
     /// # Ok::<_, alloy_dyn_abi::DynAbiError>(())
     /// ```
     #[inline]
@@ -482,20 +498,20 @@ impl DynSolType {
     ///
     /// ```ignore
     /// // This function takes a single simple param. The user should use
-    /// // DynSolType::Uint(256).decode_params(data) to decode the param.
+    /// // DynSolType::Uint(256).decode_params(data, true) to decode the param.
     /// function myFunc(uint256 a) public;
     ///
     /// // This function takes 2 params. The user should use
     /// // DynSolType::Tuple(
     /// //    vec![DynSolType::Uint(256), DynSolType::Bool])
-    /// // .decode_params(data)
+    /// // .decode_params(data, true)
     /// function myFunc(uint256 b, bool c) public;
     /// ```
     #[inline]
-    pub fn decode_params(&self, data: &[u8]) -> Result<DynSolValue> {
+    pub fn decode_params(&self, data: &[u8], validate: bool) -> Result<DynSolValue> {
         match self {
-            Self::Tuple(_) => self.decode_sequence(data),
-            _ => self.decode_single(data),
+            Self::Tuple(_) => self.decode_sequence(data, validate),
+            _ => self.decode_single(data, validate),
         }
     }
 
@@ -504,21 +520,81 @@ impl DynSolType {
     ///
     /// This method is used for decoding single values. It assumes the `data`
     /// argument is an encoded single-element sequence wrapping the `self` type.
-    pub fn decode_single(&self, data: &[u8]) -> Result<DynSolValue> {
-        let mut decoder = crate::Decoder::new(data, false);
+    ///
+    /// See the [`alloy_sol_types` documentation][modes] for the meaning of the
+    /// `validate` flag.
+    ///
+    /// [modes]: alloy_sol_types#decoding-modes
+    pub fn decode_single(&self, data: &[u8], validate: bool) -> Result<DynSolValue> {
+        let mut decoder = crate::Decoder::new(data, validate);
         let mut token = self.empty_dyn_token();
         token.decode_single_populate(&mut decoder)?;
-        self.detokenize(token)
+        let value = self.detokenize(token)?;
+        if validate {
+            type_check(&value)?;
+            if value.encode_single() != data {
+                return Err(crate::Error::ReserMismatch)
+            }
+        }
+        Ok(value)
     }
 
     /// Decode a [`DynSolValue`] from a byte slice. Fails if the value does not
     /// match this type.
-    pub fn decode_sequence(&self, data: &[u8]) -> Result<DynSolValue> {
-        let mut decoder = crate::Decoder::new(data, false);
+    ///
+    /// See the [`alloy_sol_types` documentation][modes] for the meaning of the
+    /// `validate` flag.
+    ///
+    /// [modes]: alloy_sol_types#decoding-modes
+    pub fn decode_sequence(&self, data: &[u8], validate: bool) -> Result<DynSolValue> {
+        let mut decoder = crate::Decoder::new(data, validate);
         let mut token = self.empty_dyn_token();
         token.decode_sequence_populate(&mut decoder)?;
-        self.detokenize(token)
+        let value = self.detokenize(token)?;
+        if validate {
+            type_check(&value)?;
+            let encoded = value.encode().unwrap_or_else(|| value.encode_single());
+            if encoded != data {
+                return Err(crate::Error::ReserMismatch)
+            }
+        }
+        Ok(value)
+    }
+}
+
+/// Checks that the integers and fixed bytes in a decoded value are within the
+/// bounds of their types, as these are detokenized from their full words.
+/// Every other invalid word is caught by re-encoding the value.
+fn type_check(value: &DynSolValue) -> Result<()> {
+    match value {
+        DynSolValue::Int(int, size) => {
+            let high = int.into_raw() >> (*size - 1);
+            if high != U256::ZERO && high != U256::MAX >> (*size - 1) {
+                return Err(crate::Error::type_check_fail(
+                    &int.to_be_bytes::<32>(),
+                    format!("int{size}"),
+                ))
+            }
+        }
+        DynSolValue::Uint(uint, size) if uint.bit_len() > *size => {
+            return Err(crate::Error::type_check_fail(
+                &uint.to_be_bytes::<32>(),
+                format!("uint{size}"),
+            ))
+        }
+        DynSolValue::FixedBytes(word, size) if word[*size..].iter().any(|&b| b != 0) => {
+            return Err(crate::Error::type_check_fail(
+                word.as_slice(),
+                format!("bytes{size}"),
+            ))
+        }
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => {
+            values.iter().try_for_each(type_check)?
+        }
+        as_tuple!(DynSolValue values) => values.iter().try_for_each(type_check)?,
+        _ => {}
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -554,6 +630,50 @@ mod tests {
         assert_eq!(enc.finish(), vec![word1, word2]);
     }
 
+    #[test]
+    fn decoding_modes() {
+        let ty: DynSolType = "(int8,bytes3,string)".parse().unwrap();
+        let data = hex!(
+            "
+            ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80
+            0102030000000000000000000000000000000000000000000000000000000000
+            0000000000000000000000000000000000000000000000000000000000000060
+            0000000000000000000000000000000000000000000000000000000000000002
+            6869000000000000000000000000000000000000000000000000000000000000
+            "
+        );
+        let value = ty.decode_params(&data, true).unwrap();
+        assert_eq!(value.encode_params(), data);
+
+        // trailing bytes
+        let mut dirty = data.to_vec();
+        dirty.extend_from_slice(&[0; 32]);
+        assert_eq!(ty.decode_params(&dirty, false).unwrap(), value);
+        assert_eq!(
+            ty.decode_params(&dirty, true),
+            Err(crate::Error::ReserMismatch)
+        );
+
+        // out-of-range values
+        for (i, byte) in [(0, 0x7f), (32 + 3, 1)] {
+            let mut dirty = data;
+            dirty[i] = byte;
+            assert!(ty.decode_params(&dirty, false).is_ok());
+            assert!(matches!(
+                ty.decode_params(&dirty, true),
+                Err(crate::Error::TypeCheckFail { .. })
+            ));
+        }
+
+        // non-zero padding and non-canonical offsets
+        for (i, byte) in [(4 * 32 + 2, 1), (2 * 32 + 31, 0x40)] {
+            let mut dirty = data;
+            dirty[i] = byte;
+            assert!(ty.decode_params(&dirty, false).is_ok());
+            assert!(ty.decode_params(&dirty, true).is_err());
+        }
+    }
+
     // also tests the type name parser
     macro_rules! encoder_tests {
         ($($name:ident($ty:literal, $encoded:literal)),* $(,)?) => {$(
@@ -568,7 +688,7 @@ mod tests {
         let t: DynSolType = s.parse().expect("parsing failed");
         assert_eq!(t.sol_type_name(), s, "type names are not the same");
 
-        let dec = t.decode_params(encoded).expect("decoding failed");
+        let dec = t.decode_params(encoded, true).expect("decoding failed");
         if let Some(value_name) = dec.sol_type_name() {
             assert_eq!(value_name, s, "value names are not the same");
         }
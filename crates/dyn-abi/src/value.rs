@@ -30,7 +30,7 @@ macro_rules! as_fixed_seq {
 /// let my_data: DynSolValue = 183u64.into();
 ///
 /// let encoded = my_data.encode_single();
-/// let decoded = my_type.decode_single(&encoded)?;
+/// let decoded = my_type.decode_single(&encoded, true)?;
 ///
 /// assert_eq!(decoded, my_data);
 /// # Ok::<(), alloy_dyn_abi::Error>(())
@@ -634,9 +634,19 @@ impl DynSolValue {
             Self::Uint(num, size) => {
                 buf.extend_from_slice(&num.to_be_bytes::<32>()[(32 - *size)..])
             }
-            as_fixed_seq!(inner) | Self::Array(inner) => {
-                inner.iter().for_each(|v| v.encode_packed_to(buf))
+            Self::Array(inner) | Self::FixedArray(inner) => {
+                // static array elements are padded to 32 bytes
+                for v in inner {
+                    if v.is_dynamic() {
+                        v.encode_packed_to(buf);
+                    } else {
+                        buf.extend_from_slice(&v.encode_single());
+                    }
+                }
             }
+            Self::Tuple(inner) => inner.iter().for_each(|v| v.encode_packed_to(buf)),
+            #[cfg(feature = "eip712")]
+            Self::CustomStruct { tuple, .. } => tuple.iter().for_each(|v| v.encode_packed_to(buf)),
         }
     }
 
@@ -720,3 +730,43 @@ impl DynSolValue {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn encode_packed() {
+        let mut word = Word::ZERO;
+        word[0] = 0x42;
+        let value = DynSolValue::Tuple(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 2),
+            DynSolValue::FixedBytes(word, 1),
+            DynSolValue::Uint(U256::from(3), 2),
+            DynSolValue::String("Hello, world!".into()),
+        ]);
+        assert_eq!(
+            value.encode_packed(),
+            hex!("ffff42000348656c6c6f2c20776f726c6421")
+        );
+
+        // array elements are padded
+        let array = DynSolValue::Array(vec![
+            DynSolValue::Int(I256::MINUS_ONE, 1),
+            DynSolValue::Int(I256::ONE, 1),
+        ]);
+        assert_eq!(
+            array.encode_packed(),
+            hex!(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            )
+        );
+        let array = DynSolValue::FixedArray(vec![DynSolValue::Address(Address::repeat_byte(1))]);
+        assert_eq!(
+            array.encode_packed(),
+            hex!("0000000000000000000000000101010101010101010101010101010101010101")
+        );
+    }
+}
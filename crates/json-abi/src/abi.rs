@@ -1,10 +1,10 @@
-use crate::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
+use crate::{utils::set_if_none, AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
 use alloc::{
     collections::{btree_map, btree_map::Values},
     string::String,
     vec::Vec,
 };
-use alloy_primitives::Bytes;
+use alloy_primitives::{Bytes, Selector, B256};
 use btree_map::BTreeMap;
 use core::{fmt, iter, iter::Flatten};
 use serde::{
@@ -84,13 +84,12 @@ impl JsonAbi {
 
     /// Parse the ABI json from a `str`. This is a convenience wrapper around
     /// [`serde_json::from_str`].
-    #[cfg(feature = "serde_json")]
     pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
 
     /// Loads contract from json
-    #[cfg(all(feature = "std", feature = "serde_json"))]
+    #[cfg(feature = "std")]
     pub fn load<T: std::io::Read>(mut reader: T) -> Result<Self, serde_json::Error> {
         // https://docs.rs/serde_json/latest/serde_json/fn.from_reader.html
         // serde_json docs recommend buffering the whole reader to a string
@@ -132,6 +131,105 @@ impl JsonAbi {
     pub fn errors(&self) -> Flatten<Values<'_, String, Vec<Error>>> {
         self.errors.values().flatten()
     }
+
+    /// Indexes the functions, events and errors of the contract by their
+    /// selectors, for looking up the item that corresponds to calldata, a log
+    /// topic or revert data.
+    ///
+    /// Anonymous events are not indexed, as their logs do not contain their
+    /// selector. If several items of the same kind have the same selector,
+    /// only the first one in the order of [`functions`](Self::functions),
+    /// [`events`](Self::events) or [`errors`](Self::errors) is indexed. This
+    /// happens when an item is declared more than once, like an error used by
+    /// several contracts, or in the unlikely case of a selector collision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{Function, JsonAbi};
+    ///
+    /// let mut abi = JsonAbi::default();
+    /// let transfer = Function::parse("transfer(address,uint256)")?;
+    /// abi.functions.insert(transfer.name.clone(), vec![transfer]);
+    ///
+    /// let selectors = abi.selectors();
+    /// let function = selectors.function([0xa9, 0x05, 0x9c, 0xbb]).unwrap();
+    /// assert_eq!(function.name, "transfer");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    pub fn selectors(&self) -> Selectors<'_> {
+        Selectors {
+            functions: first_by_key(self.functions().map(|f| (f.selector(), f))),
+            events: first_by_key(
+                self.events()
+                    .filter(|e| !e.anonymous)
+                    .map(|e| (e.selector(), e)),
+            ),
+            errors: first_by_key(self.errors().map(|e| (e.selector(), e))),
+        }
+    }
+}
+
+/// Collects an iterator into a map, keeping the first value for each key.
+fn first_by_key<K: Ord, V>(iter: impl Iterator<Item = (K, V)>) -> BTreeMap<K, V> {
+    let mut map = BTreeMap::new();
+    for (k, v) in iter {
+        map.entry(k).or_insert(v);
+    }
+    map
+}
+
+/// An index of the items of a [`JsonAbi`] by their selectors.
+///
+/// Functions and errors are indexed by their 4-byte selectors, and
+/// non-anonymous events by their 32-byte selectors, which is the first topic of
+/// their logs.
+///
+/// This `struct` is created by [`JsonAbi::selectors`]. See its documentation
+/// for more.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selectors<'a> {
+    functions: BTreeMap<Selector, &'a Function>,
+    events: BTreeMap<B256, &'a Event>,
+    errors: BTreeMap<Selector, &'a Error>,
+}
+
+impl<'a> Selectors<'a> {
+    /// Gets the function with the given selector.
+    #[inline]
+    pub fn function(&self, selector: Selector) -> Option<&'a Function> {
+        self.functions.get(&selector).copied()
+    }
+
+    /// Gets the event with the given selector.
+    #[inline]
+    pub fn event(&self, selector: B256) -> Option<&'a Event> {
+        self.events.get(&selector).copied()
+    }
+
+    /// Gets the error with the given selector.
+    #[inline]
+    pub fn error(&self, selector: Selector) -> Option<&'a Error> {
+        self.errors.get(&selector).copied()
+    }
+
+    /// Iterates over the functions, ordered by selector.
+    #[inline]
+    pub fn functions(&self) -> impl Iterator<Item = (Selector, &'a Function)> + '_ {
+        self.functions.iter().map(|(k, v)| (*k, *v))
+    }
+
+    /// Iterates over the events, ordered by selector.
+    #[inline]
+    pub fn events(&self) -> impl Iterator<Item = (B256, &'a Event)> + '_ {
+        self.events.iter().map(|(k, v)| (*k, *v))
+    }
+
+    /// Iterates over the errors, ordered by selector.
+    #[inline]
+    pub fn errors(&self) -> impl Iterator<Item = (Selector, &'a Error)> + '_ {
+        self.errors.iter().map(|(k, v)| (*k, *v))
+    }
 }
 
 macro_rules! next_item {
@@ -296,16 +394,6 @@ impl Serialize for JsonAbi {
     }
 }
 
-macro_rules! set_if_none {
-    ($opt:expr, $val:expr) => { set_if_none!(stringify!($opt) => $opt, $val) };
-    ($name:expr => $opt:expr, $val:expr) => {{
-        if $opt.is_some() {
-            return Err(serde::de::Error::duplicate_field($name))
-        }
-        $opt = Some($val);
-    }};
-}
-
 /// Equivalent of `map.entry(v.name.clone()).or_default().push(v.into_owned())`
 /// but without cloning the key for when the entry is occupied.
 macro_rules! map_default_and_push {
@@ -322,7 +410,7 @@ macro_rules! map_default_and_push {
     };
 }
 
-struct JsonAbiVisitor;
+pub(crate) struct JsonAbiVisitor;
 
 impl<'de> Visitor<'de> for JsonAbiVisitor {
     type Value = JsonAbi;
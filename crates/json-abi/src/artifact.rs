@@ -0,0 +1,610 @@
+use crate::{abi::JsonAbiVisitor, utils::set_if_none, ContractObject, JsonAbi};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
+use alloy_primitives::{hex, Address, Bytes, FixedBytes};
+use core::{fmt, ops::Range};
+use serde::{
+    de::{IgnoredAny, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// `file => library name => offsets`
+pub type LinkReferences = BTreeMap<String, BTreeMap<String, Vec<Offsets>>>;
+
+/// A contract compilation artifact: the ABI, the creation and deployed
+/// bytecode, and other compiler output.
+///
+/// Can be deserialized from:
+/// - [Foundry] artifacts (`out/<File>.sol/<Contract>.json`);
+/// - [Hardhat] artifacts (`artifacts/<File>.sol/<Contract>.json`);
+/// - contract objects of the [solc standard JSON output][solc];
+/// - the same formats as [`ContractObject`], including a plain ABI array.
+///
+/// Unlike [`ContractObject`], bytecode containing unlinked library
+/// placeholders is supported, and can be [linked](Bytecode::link) once the
+/// library addresses are known.
+///
+/// [Foundry]: https://book.getfoundry.sh/
+/// [Hardhat]: https://hardhat.org/hardhat-runner/docs/advanced/artifacts
+/// [solc]: https://docs.soliditylang.org/en/latest/using-the-compiler.html#output-description
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractArtifact {
+    /// The contract ABI.
+    pub abi: JsonAbi,
+    /// The creation bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode: Option<Bytecode>,
+    /// The deployed (runtime) bytecode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployed_bytecode: Option<Bytecode>,
+    /// The function selectors, indexed by function signature.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub method_identifiers: BTreeMap<String, FixedBytes<4>>,
+    /// The raw JSON of the compiler metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+}
+
+impl From<ContractArtifact> for ContractObject {
+    /// Converts the artifact into a contract object, dropping any bytecode
+    /// that is not fully linked.
+    #[inline]
+    fn from(artifact: ContractArtifact) -> Self {
+        Self {
+            abi: artifact.abi,
+            bytecode: artifact.bytecode.and_then(Bytecode::into_bytes),
+            deployed_bytecode: artifact.deployed_bytecode.and_then(Bytecode::into_bytes),
+        }
+    }
+}
+
+impl ContractArtifact {
+    /// Parses an artifact from a JSON string. This is a convenience wrapper
+    /// around [`serde_json::from_str`].
+    pub fn from_json_str(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Links the library `file:library` at `address` in both the creation and
+    /// the deployed bytecode.
+    ///
+    /// Returns `true` if any placeholder was replaced, or an error if a link
+    /// reference is out of bounds, in which case nothing is modified.
+    pub fn link(&mut self, file: &str, library: &str, address: Address) -> Result<bool, LinkError> {
+        for bytecode in [&self.bytecode, &self.deployed_bytecode]
+            .into_iter()
+            .flatten()
+        {
+            if let Some(offsets) = bytecode
+                .link_references
+                .get(file)
+                .and_then(|libraries| libraries.get(library))
+            {
+                bytecode.object.link_ranges(offsets)?;
+            }
+        }
+        let mut linked = false;
+        for bytecode in [&mut self.bytecode, &mut self.deployed_bytecode]
+            .into_iter()
+            .flatten()
+        {
+            linked |= bytecode.link(file, library, address)?;
+        }
+        Ok(linked)
+    }
+}
+
+/// Bytecode, together with the positions of the values that are only known
+/// when it is linked or deployed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    /// The bytecode, which may contain placeholders for library addresses.
+    pub object: BytecodeObject,
+    /// The positions of the library addresses that still have to be linked.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_references: LinkReferences,
+    /// The positions of the values of immutable variables, indexed by the AST
+    /// ID of the variable. Only present in deployed bytecode.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub immutable_references: BTreeMap<String, Vec<Offsets>>,
+}
+
+impl From<Bytes> for Bytecode {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        Self {
+            object: BytecodeObject::Bytecode(bytes),
+            ..Default::default()
+        }
+    }
+}
+
+impl Bytecode {
+    /// Returns the bytecode if it is fully linked.
+    #[inline]
+    pub const fn bytes(&self) -> Option<&Bytes> {
+        self.object.bytes()
+    }
+
+    /// Converts this into the bytecode if it is fully linked.
+    #[inline]
+    pub fn into_bytes(self) -> Option<Bytes> {
+        self.object.into_bytes()
+    }
+
+    /// Replaces the placeholders of the library `file:library` with `address`,
+    /// and removes them from the [link references](Self::link_references).
+    ///
+    /// Returns `true` if any placeholder was replaced, or an error if a link
+    /// reference is out of bounds, in which case nothing is modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::{Bytecode, BytecodeObject, Offsets};
+    /// use alloy_primitives::{address, bytes};
+    ///
+    /// let mut bytecode = Bytecode {
+    ///     object: BytecodeObject::Unlinked(
+    ///         "0x73__$2e8ee3b5cbac8a6d1d2a3a3e9cc7b8b8ed$__63".into(),
+    ///     ),
+    ///     ..Default::default()
+    /// };
+    /// bytecode
+    ///     .link_references
+    ///     .entry("src/Lib.sol".into())
+    ///     .or_default()
+    ///     .insert("Lib".into(), vec![Offsets { start: 1, length: 20 }]);
+    ///
+    /// let lib = address!("5fbdb2315678afecb367f032d93f642f64180aa3");
+    /// assert!(bytecode.link("src/Lib.sol", "Lib", lib).unwrap());
+    /// assert!(bytecode.link_references.is_empty());
+    /// assert_eq!(
+    ///     bytecode.bytes(),
+    ///     Some(&bytes!("735fbdb2315678afecb367f032d93f642f64180aa363"))
+    /// );
+    /// ```
+    pub fn link(&mut self, file: &str, library: &str, address: Address) -> Result<bool, LinkError> {
+        let Some(offsets) = self
+            .link_references
+            .get(file)
+            .and_then(|libraries| libraries.get(library))
+        else {
+            return Ok(false)
+        };
+        // check all the offsets before modifying anything
+        let ranges = self.object.link_ranges(offsets)?;
+
+        let libraries = self.link_references.get_mut(file).unwrap();
+        libraries.remove(library);
+        if libraries.is_empty() {
+            self.link_references.remove(file);
+        }
+
+        match &mut self.object {
+            BytecodeObject::Bytecode(bytes) => {
+                let mut vec = Vec::from(&bytes[..]);
+                for range in ranges {
+                    vec[range].copy_from_slice(address.as_slice());
+                }
+                *bytes = vec.into();
+            }
+            BytecodeObject::Unlinked(s) => {
+                let address = hex::encode(address);
+                for range in ranges {
+                    s.replace_range(range, &address);
+                }
+                if let Ok(bytes) = hex::decode(&s[..]) {
+                    self.object = BytecodeObject::Bytecode(bytes.into());
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// The object of a [`Bytecode`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BytecodeObject {
+    /// Fully linked bytecode.
+    Bytecode(Bytes),
+    /// Hex-encoded bytecode that contains placeholders for library addresses,
+    /// such as `__$<34 hex characters>$__`.
+    Unlinked(String),
+}
+
+impl Default for BytecodeObject {
+    #[inline]
+    fn default() -> Self {
+        Self::Bytecode(Bytes::new())
+    }
+}
+
+impl BytecodeObject {
+    /// Returns the bytecode if it is fully linked.
+    #[inline]
+    pub const fn bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Bytecode(bytes) => Some(bytes),
+            Self::Unlinked(_) => None,
+        }
+    }
+
+    /// Converts this into the bytecode if it is fully linked.
+    #[inline]
+    pub fn into_bytes(self) -> Option<Bytes> {
+        match self {
+            Self::Bytecode(bytes) => Some(bytes),
+            Self::Unlinked(_) => None,
+        }
+    }
+
+    /// Returns `true` if the bytecode contains unlinked library placeholders.
+    #[inline]
+    pub const fn is_unlinked(&self) -> bool {
+        matches!(self, Self::Unlinked(_))
+    }
+
+    /// Returns the ranges of the library addresses at `offsets`, in bytes for
+    /// linked bytecode, or in hex characters for unlinked bytecode.
+    fn link_ranges(&self, offsets: &[Offsets]) -> Result<Vec<Range<usize>>, LinkError> {
+        // library addresses always take up 20 bytes
+        let offsets = offsets.iter().filter(|offsets| offsets.length == 20);
+        offsets
+            .map(|&offsets| {
+                let range = match self {
+                    Self::Bytecode(bytes) => offsets
+                        .start
+                        .checked_add(20)
+                        .map(|end| offsets.start..end)
+                        .filter(|range| range.end <= bytes.len()),
+                    Self::Unlinked(s) => {
+                        let prefix = if s.starts_with("0x") { 2 } else { 0 };
+                        offsets
+                            .start
+                            .checked_mul(2)
+                            .and_then(|start| start.checked_add(prefix))
+                            .and_then(|start| Some(start..start.checked_add(40)?))
+                            .filter(|range| s.get(range.clone()).is_some())
+                    }
+                };
+                range.ok_or(LinkError { offsets })
+            })
+            .collect()
+    }
+}
+
+impl Serialize for BytecodeObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bytecode(bytes) => bytes.serialize(serializer),
+            Self::Unlinked(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BytecodeObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = Cow::<'de, str>::deserialize(deserializer)?;
+        if let Ok(bytes) = hex::decode(&*s) {
+            return Ok(Self::Bytecode(bytes.into()))
+        }
+        if s.contains("__") {
+            Ok(Self::Unlinked(s.into_owned()))
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&s),
+                &"a hex string, optionally containing library placeholders",
+            ))
+        }
+    }
+}
+
+/// The error returned by [`Bytecode::link`] when a link reference is out of
+/// the bounds of the bytecode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinkError {
+    /// The offsets of the invalid link reference.
+    pub offsets: Offsets,
+}
+
+impl fmt::Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "link reference of {} bytes at offset {} is out of bounds",
+            self.offsets.length, self.offsets.start
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LinkError {}
+
+/// A range of bytes in a [`Bytecode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Offsets {
+    /// The byte offset of the start of the range.
+    pub start: usize,
+    /// The length of the range in bytes.
+    pub length: usize,
+}
+
+impl<'de> Deserialize<'de> for Bytecode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Object(BytecodeObject),
+            #[serde(rename_all = "camelCase")]
+            Full {
+                object: BytecodeObject,
+                #[serde(default)]
+                link_references: LinkReferences,
+                #[serde(default)]
+                immutable_references: BTreeMap<String, Vec<Offsets>>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Object(object) => Self {
+                object,
+                ..Default::default()
+            },
+            Repr::Full {
+                object,
+                link_references,
+                immutable_references,
+            } => Self {
+                object,
+                link_references,
+                immutable_references,
+            },
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ContractArtifact {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContractArtifactVisitor)
+    }
+}
+
+/// The compiler metadata, which is a JSON string in solc output and in
+/// Foundry's `rawMetadata`, but an object in Foundry's `metadata`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Metadata {
+    Raw(String),
+    Parsed(IgnoredAny),
+}
+
+struct ContractArtifactVisitor;
+
+impl<'de> Visitor<'de> for ContractArtifactVisitor {
+    type Value = ContractArtifact;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a sequence or map with `abi` key")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        /// Represents the `evm` object of the solc standard JSON output.
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct EvmObj {
+            bytecode: Option<Bytecode>,
+            deployed_bytecode: Option<Bytecode>,
+            #[serde(default)]
+            method_identifiers: BTreeMap<String, FixedBytes<4>>,
+        }
+
+        let mut abi = None;
+        let mut bytecode = None;
+        let mut deployed_bytecode = None;
+        let mut method_identifiers = None;
+        let mut metadata = None;
+        let mut raw_metadata = None;
+        // Hardhat stores these next to the bytecode strings
+        let mut link_references = None;
+        let mut deployed_link_references = None;
+
+        while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+            match &*key {
+                "abi" => set_if_none!("abi" => abi, map.next_value()?),
+                "evm" => {
+                    let evm = map.next_value::<EvmObj>()?;
+                    if let Some(evm_bytecode) = evm.bytecode {
+                        set_if_none!("bytecode" => bytecode, evm_bytecode);
+                    }
+                    if let Some(evm_bytecode) = evm.deployed_bytecode {
+                        set_if_none!("deployedBytecode" => deployed_bytecode, evm_bytecode);
+                    }
+                    if !evm.method_identifiers.is_empty() {
+                        set_if_none!(
+                            "methodIdentifiers" => method_identifiers,
+                            evm.method_identifiers
+                        );
+                    }
+                }
+                "byteCode" | "bytecode" | "bin" => {
+                    set_if_none!("bytecode" => bytecode, map.next_value()?);
+                }
+                "deployedBytecode" | "deployedbytecode" | "runtimeBin" | "runtimebin" => {
+                    set_if_none!("deployedBytecode" => deployed_bytecode, map.next_value()?);
+                }
+                "linkReferences" => {
+                    set_if_none!("linkReferences" => link_references, map.next_value()?);
+                }
+                "deployedLinkReferences" => {
+                    set_if_none!(
+                        "deployedLinkReferences" => deployed_link_references,
+                        map.next_value()?
+                    );
+                }
+                "methodIdentifiers" => {
+                    set_if_none!("methodIdentifiers" => method_identifiers, map.next_value()?);
+                }
+                "metadata" => {
+                    if let Metadata::Raw(s) = map.next_value()? {
+                        set_if_none!("metadata" => metadata, s);
+                    }
+                }
+                "rawMetadata" => {
+                    set_if_none!("rawMetadata" => raw_metadata, map.next_value()?);
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let abi = abi.ok_or_else(|| serde::de::Error::missing_field("abi"))?;
+        let mut bytecode: Option<Bytecode> = bytecode;
+        let mut deployed_bytecode: Option<Bytecode> = deployed_bytecode;
+        if let (Some(bytecode), Some(refs)) = (&mut bytecode, link_references) {
+            if bytecode.link_references.is_empty() {
+                bytecode.link_references = refs;
+            }
+        }
+        if let (Some(bytecode), Some(refs)) = (&mut deployed_bytecode, deployed_link_references) {
+            if bytecode.link_references.is_empty() {
+                bytecode.link_references = refs;
+            }
+        }
+        Ok(ContractArtifact {
+            abi,
+            bytecode,
+            deployed_bytecode,
+            method_identifiers: method_identifiers.unwrap_or_default(),
+            metadata: metadata.or(raw_metadata),
+        })
+    }
+
+    #[inline]
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        JsonAbiVisitor.visit_seq(seq).map(|abi| ContractArtifact {
+            abi,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, bytes};
+
+    #[test]
+    fn link() {
+        let placeholder = "__$2e8ee3b5cbac8a6d1d2a3a3e9cc7b8b8ed$__";
+        let mut bytecode = Bytecode {
+            object: BytecodeObject::Unlinked(format!("60{placeholder}00{placeholder}")),
+            ..Default::default()
+        };
+        let offsets = vec![
+            Offsets {
+                start: 1,
+                length: 20,
+            },
+            Offsets {
+                start: 22,
+                length: 20,
+            },
+        ];
+        bytecode
+            .link_references
+            .entry("A.sol".into())
+            .or_default()
+            .insert("A".into(), offsets.clone());
+        let original = bytecode.clone();
+
+        let a = address!("00000000000000000000000000000000000000aa");
+        assert!(!bytecode.link("A.sol", "B", a).unwrap());
+        assert!(!bytecode.link("B.sol", "A", a).unwrap());
+        assert_eq!(bytecode, original);
+
+        assert!(bytecode.link("A.sol", "A", a).unwrap());
+        assert!(bytecode.link_references.is_empty());
+        let expected = [&[0x60][..], &a[..], &[0x00], &a[..]].concat();
+        let expected = Bytes::from(expected);
+        assert_eq!(bytecode.bytes(), Some(&expected));
+        assert!(!bytecode.link("A.sol", "A", a).unwrap());
+
+        // relinking already linked bytecode
+        let mut bytecode = Bytecode::from(expected);
+        bytecode
+            .link_references
+            .entry("A.sol".into())
+            .or_default()
+            .insert("A".into(), offsets);
+        let b = address!("00000000000000000000000000000000000000bb");
+        assert!(bytecode.link("A.sol", "A", b).unwrap());
+        let expected = [&[0x60][..], &b[..], &[0x00], &b[..]].concat();
+        assert_eq!(bytecode.bytes(), Some(&expected.into()));
+    }
+
+    #[test]
+    fn link_out_of_bounds() {
+        let placeholder = "__$2e8ee3b5cbac8a6d1d2a3a3e9cc7b8b8ed$__";
+        let unlinked = Bytecode {
+            object: BytecodeObject::Unlinked(format!("0x60{placeholder}")),
+            ..Default::default()
+        };
+        let linked = Bytecode::from(Bytes::from(vec![0; 21]));
+        let a = address!("00000000000000000000000000000000000000aa");
+        for start in [2, 21, usize::MAX / 2, usize::MAX - 19, usize::MAX] {
+            for mut bytecode in [unlinked.clone(), linked.clone()] {
+                let offsets = Offsets { start, length: 20 };
+                bytecode
+                    .link_references
+                    .entry("A.sol".into())
+                    .or_default()
+                    .insert(
+                        "A".into(),
+                        vec![
+                            Offsets {
+                                start: 1,
+                                length: 20,
+                            },
+                            offsets,
+                        ],
+                    );
+                let original = bytecode.clone();
+                assert_eq!(bytecode.link("A.sol", "A", a), Err(LinkError { offsets }));
+                assert_eq!(bytecode, original);
+
+                let mut artifact = ContractArtifact {
+                    bytecode: Some(original.clone()),
+                    deployed_bytecode: Some(Bytecode::from(Bytes::new())),
+                    ..Default::default()
+                };
+                artifact.deployed_bytecode.as_mut().unwrap().link_references =
+                    original.link_references.clone();
+                let original = artifact.clone();
+                assert!(artifact.link("A.sol", "A", a).is_err());
+                assert_eq!(artifact, original);
+            }
+        }
+    }
+
+    #[test]
+    fn bytecode_object() {
+        let object: BytecodeObject = serde_json::from_str(r#""0x6001""#).unwrap();
+        assert_eq!(object, BytecodeObject::Bytecode(bytes!("6001")));
+        let object: BytecodeObject = serde_json::from_str(r#""6001""#).unwrap();
+        assert_eq!(object, BytecodeObject::Bytecode(bytes!("6001")));
+        let object: BytecodeObject = serde_json::from_str(r#""""#).unwrap();
+        assert_eq!(object, BytecodeObject::Bytecode(Bytes::new()));
+
+        let unlinked = r#""73__$2e8ee3b5cbac8a6d1d2a3a3e9cc7b8b8ed$__""#;
+        let object: BytecodeObject = serde_json::from_str(unlinked).unwrap();
+        assert!(object.is_unlinked());
+        assert_eq!(serde_json::to_string(&object).unwrap(), unlinked);
+
+        assert!(serde_json::from_str::<BytecodeObject>(r#""0xzz""#).is_err());
+    }
+}
@@ -0,0 +1,405 @@
+//! Human-readable ABI parsing.
+//!
+//! Declarations are parsed with [`syn_solidity`], and the resulting items are
+//! converted into their JSON ABI representation. See the [crate-level
+//! documentation](crate#human-readable-abi).
+
+use crate::{Error, Event, EventParam, Function, Param, StateMutability};
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use alloy_sol_type_parser::{Error as ParserError, Result};
+use proc_macro2::{Delimiter, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+use syn_solidity::{
+    FunctionAttribute, FunctionKind, ItemError, ItemEvent, ItemFunction, Mutability, ParameterList,
+    Type, VariableDeclaration,
+};
+
+/// The prefix of the placeholder types that replace tuple types before
+/// parsing. See [`Declaration::new`].
+const TUPLE: &str = "__tuple";
+
+/// The keywords that start a declaration.
+const KEYWORDS: &[&str] = &[
+    "function",
+    "event",
+    "error",
+    "constructor",
+    "fallback",
+    "receive",
+    "modifier",
+];
+
+pub(crate) fn parse_function(s: &str) -> Result<Function> {
+    let decl = Declaration::new(s, "function")?;
+    let f: ItemFunction = decl.parse()?;
+    let name = match &f.name {
+        Some(name) if matches!(f.kind, FunctionKind::Function(_)) => name.to_string(),
+        _ => return Err(ParserError::invalid_type_string(s)),
+    };
+
+    for attr in f.attributes.iter() {
+        match attr {
+            FunctionAttribute::Visibility(_)
+            | FunctionAttribute::Mutability(_)
+            | FunctionAttribute::Virtual(_)
+            | FunctionAttribute::Override(_) => {}
+            // not a Solidity keyword, but the JSON ABI name of the default
+            FunctionAttribute::Modifier(m)
+                if m.name.to_string() == "nonpayable"
+                    && m.paren_token.is_none()
+                    && f.attributes.mutability().is_none() => {}
+            attr => return Err(decl.error(format_args!("unexpected attribute `{attr}`"))),
+        }
+    }
+    let state_mutability = match f.attributes.mutability() {
+        Some(Mutability::Pure(_)) => StateMutability::Pure,
+        Some(Mutability::View(_) | Mutability::Constant(_)) => StateMutability::View,
+        Some(Mutability::Payable(_)) => StateMutability::Payable,
+        None => StateMutability::NonPayable,
+    };
+
+    let outputs = match &f.returns {
+        Some(returns) => decl.params(&returns.returns)?,
+        None => Vec::new(),
+    };
+    Ok(Function {
+        name,
+        inputs: decl.params(&f.arguments)?,
+        outputs,
+        state_mutability,
+        extra: BTreeMap::new(),
+    })
+}
+
+pub(crate) fn parse_event(s: &str) -> Result<Event> {
+    let decl = Declaration::new(s, "event")?;
+    let e: ItemEvent = decl.parse()?;
+    let inputs = e
+        .parameters
+        .iter()
+        .map(|p| {
+            let (ty, components) = decl.ty(&p.ty)?;
+            Ok(EventParam {
+                name: p.name.as_ref().map(ToString::to_string).unwrap_or_default(),
+                ty,
+                indexed: p.is_indexed(),
+                components,
+                internal_type: None,
+                extra: BTreeMap::new(),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Event {
+        name: e.name.to_string(),
+        inputs,
+        anonymous: e.is_anonymous(),
+        extra: BTreeMap::new(),
+    })
+}
+
+pub(crate) fn parse_error(s: &str) -> Result<Error> {
+    let decl = Declaration::new(s, "error")?;
+    let e: ItemError = decl.parse()?;
+    Ok(Error {
+        name: e.name.to_string(),
+        inputs: decl.params(&e.parameters)?,
+        extra: BTreeMap::new(),
+    })
+}
+
+/// A declaration, lexed and prepared to be parsed as a Solidity item.
+struct Declaration<'a> {
+    /// The original string, for error messages.
+    src: &'a str,
+    tokens: TokenStream,
+    /// The contents of the tuple types replaced by placeholders.
+    tuples: Vec<TokenStream>,
+}
+
+impl<'a> Declaration<'a> {
+    /// Lexes `src`, adding the `keyword` and the trailing semicolon that
+    /// human-readable declarations may omit.
+    ///
+    /// Solidity tuple types cannot name their components, so tuple types in
+    /// parameter lists, like `(address to, uint256 amount)[]` or
+    /// `tuple(address, uint256)`, are replaced by placeholder custom types
+    /// before parsing. Their contents are parsed as parameter lists on their
+    /// own when converting the types, see [`Self::ty`].
+    fn new(src: &'a str, keyword: &str) -> Result<Self> {
+        let tokens: TokenStream = src
+            .parse()
+            .map_err(|_| ParserError::invalid_type_string(src))?;
+        let mut tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        // don't turn `constructor()` into a function named `constructor`
+        if !matches!(tokens.first(), Some(TokenTree::Ident(i)) if KEYWORDS.iter().any(|kw| i == kw))
+        {
+            tokens.insert(0, Ident::new(keyword, Span::call_site()).into());
+        }
+        if !matches!(tokens.last(), Some(TokenTree::Punct(p)) if p.as_char() == ';') {
+            tokens.push(Punct::new(';', Spacing::Alone).into());
+        }
+
+        let mut tuples = Vec::new();
+        let tokens = tokens
+            .into_iter()
+            .map(|token| match token {
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                    let stream = replace_tuples(group.stream(), &mut tuples);
+                    let mut new = proc_macro2::Group::new(Delimiter::Parenthesis, stream);
+                    new.set_span(group.span());
+                    new.into()
+                }
+                token => token,
+            })
+            .collect();
+        Ok(Self {
+            src,
+            tokens,
+            tuples,
+        })
+    }
+
+    fn parse<T: syn::parse::Parse>(&self) -> Result<T> {
+        syn::parse2(self.tokens.clone()).map_err(|e| self.error(e))
+    }
+
+    fn error(&self, e: impl core::fmt::Display) -> ParserError {
+        ParserError::invalid_type_string(format!("{}: {e}", self.src))
+    }
+
+    fn params(&self, params: &ParameterList) -> Result<Vec<Param>> {
+        params.iter().map(|p| self.param(p)).collect()
+    }
+
+    fn param(&self, p: &VariableDeclaration) -> Result<Param> {
+        let name = p.name.as_ref().map(ToString::to_string).unwrap_or_default();
+        if name == "indexed" {
+            return Err(self.error("only event parameters can be indexed"))
+        }
+        let (ty, components) = self.ty(&p.ty)?;
+        Ok(Param {
+            name,
+            ty,
+            components,
+            internal_type: None,
+            extra: BTreeMap::new(),
+        })
+    }
+
+    /// Converts a type into its ABI type string and, for tuples, components.
+    fn ty(&self, ty: &Type) -> Result<(String, Vec<Param>)> {
+        match ty {
+            Type::Array(array) => {
+                let (ty, components) = self.ty(&array.ty)?;
+                let ty = match array.size() {
+                    Some(size) => format!("{ty}[{size}]"),
+                    None => format!("{ty}[]"),
+                };
+                Ok((ty, components))
+            }
+            Type::Custom(path) => {
+                let tuple = path
+                    .to_string()
+                    .strip_prefix(TUPLE)
+                    .and_then(|i| i.parse::<usize>().ok())
+                    .and_then(|i| self.tuples.get(i));
+                let Some(tuple) = tuple else {
+                    return Err(self.error(format_args!("unknown type `{path}`")))
+                };
+                let mut tuples = Vec::new();
+                let stream = replace_tuples(tuple.clone(), &mut tuples);
+                let params: ParameterList = syn::parse2(stream).map_err(|e| self.error(e))?;
+                if params.is_empty() {
+                    return Err(self.error("empty tuples are not allowed"))
+                }
+                let inner = Declaration {
+                    src: self.src,
+                    tokens: TokenStream::new(),
+                    tuples,
+                };
+                Ok(("tuple".into(), inner.params(&params)?))
+            }
+            Type::Uint(_, None) => Ok(("uint256".into(), Vec::new())),
+            Type::Int(_, None) => Ok(("int256".into(), Vec::new())),
+            Type::Address(..)
+            | Type::Bool(_)
+            | Type::String(_)
+            | Type::Bytes(_)
+            | Type::FixedBytes(..)
+            | Type::Int(..)
+            | Type::Uint(..) => Ok((ty.to_string(), Vec::new())),
+            Type::Tuple(_) | Type::Function(_) | Type::Mapping(_) => Err(self.error(format_args!(
+                "`{ty}` cannot be used in a human-readable ABI"
+            ))),
+        }
+    }
+}
+
+/// Replaces the tuple types at the start of each parameter of a parameter
+/// list with placeholder types, and pushes their contents to `tuples`.
+fn replace_tuples(stream: TokenStream, tuples: &mut Vec<TokenStream>) -> TokenStream {
+    let mut out = Vec::new();
+    let mut tokens = stream.into_iter().peekable();
+    let mut at_start = true;
+    while let Some(token) = tokens.next() {
+        let tuple = match &token {
+            TokenTree::Group(group) if at_start && group.delimiter() == Delimiter::Parenthesis => {
+                Some(group.clone())
+            }
+            TokenTree::Ident(ident) if at_start && ident == "tuple" => match tokens.peek() {
+                Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+                    let group = group.clone();
+                    tokens.next();
+                    Some(group)
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        at_start = matches!(&token, TokenTree::Punct(p) if p.as_char() == ',');
+        match tuple {
+            Some(group) => {
+                let name = format!("{TUPLE}{}", tuples.len());
+                tuples.push(group.stream());
+                out.push(Ident::new(&name, group.span()).into());
+            }
+            None => out.push(token),
+        }
+    }
+    out.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param(ty: &str, name: &str) -> Param {
+        Param {
+            name: name.into(),
+            ty: ty.into(),
+            components: vec![],
+            internal_type: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn functions() {
+        let f =
+            parse_function("function transfer(address to, uint amount) external returns (bool)")
+                .unwrap();
+        assert_eq!(
+            f,
+            Function {
+                name: "transfer".into(),
+                inputs: vec![param("address", "to"), param("uint256", "amount")],
+                outputs: vec![param("bool", "")],
+                state_mutability: StateMutability::NonPayable,
+                extra: BTreeMap::new(),
+            }
+        );
+        assert_eq!(f.selector(), [0xa9, 0x05, 0x9c, 0xbb]);
+
+        let f = parse_function("deposit() payable").unwrap();
+        assert_eq!(f.state_mutability, StateMutability::Payable);
+        assert!(f.inputs.is_empty() && f.outputs.is_empty());
+
+        let f = parse_function(
+            "function f(string calldata s, address payable a, int[2][] memory) public pure returns(bytes memory b);",
+        )
+        .unwrap();
+        assert_eq!(f.signature(), "f(string,address,int256[2][])");
+        assert_eq!(f.state_mutability, StateMutability::Pure);
+        assert_eq!(f.outputs, [param("bytes", "b")]);
+
+        let f = parse_function("function f() nonpayable").unwrap();
+        assert_eq!(f.state_mutability, StateMutability::NonPayable);
+        let f = parse_function("function f() external constant returns (uint)").unwrap();
+        assert_eq!(f.state_mutability, StateMutability::View);
+    }
+
+    #[test]
+    fn tuples() {
+        let f = parse_function(
+            "function f((address to, (uint8,bool)[2] inner)[] orders, tuple(bytes32) id)",
+        )
+        .unwrap();
+        assert_eq!(f.signature(), "f((address,(uint8,bool)[2])[],(bytes32))");
+        let orders = &f.inputs[0];
+        assert_eq!(orders.name, "orders");
+        assert_eq!(orders.ty, "tuple[]");
+        assert_eq!(orders.components[0], param("address", "to"));
+        assert_eq!(orders.components[1].ty, "tuple[2]");
+        assert_eq!(orders.components[1].components.len(), 2);
+        assert_eq!(f.inputs[1].ty, "tuple");
+        assert_eq!(f.inputs[1].name, "id");
+
+        let e = parse_event("event E((uint256 a, string b) indexed t)").unwrap();
+        assert_eq!(e.signature(), "E((uint256,string))");
+        assert!(e.inputs[0].indexed);
+        assert_eq!(e.inputs[0].components[1], param("string", "b"));
+    }
+
+    #[test]
+    fn events() {
+        let e =
+            parse_event("event Transfer(address indexed from, address indexed to, uint256 value)")
+                .unwrap();
+        assert_eq!(e.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(
+            e.inputs.iter().map(|p| p.indexed).collect::<Vec<_>>(),
+            [true, true, false]
+        );
+        assert!(!e.anonymous);
+
+        let e = parse_event("Log(string) anonymous").unwrap();
+        assert!(e.anonymous);
+        assert_eq!(e.inputs[0].name, "");
+    }
+
+    #[test]
+    fn errors() {
+        let e = parse_error("error Unauthorized()").unwrap();
+        assert_eq!(e.signature(), "Unauthorized()");
+        assert_eq!(e.selector(), [0x82, 0xb4, 0x29, 0x00]);
+    }
+
+    #[test]
+    fn invalid() {
+        let err = |s: &str| parse_function(s).unwrap_err().to_string();
+        let invalid = [
+            "function f(uint7)",
+            "function f(MyStruct s)",
+            "function f(uint256 a b)",
+            "function f(uint256,,)",
+            "function f(() a)",
+            "function f(uint256 indexed a)",
+            "function f(uint256 indexed)",
+            "function f() returns (bool) view",
+            "function f() onlyOwner",
+            "function f() view nonpayable",
+            "function f() view pure",
+            "function f(mapping(uint => uint) m)",
+            "function 1f()",
+            "function f(uint256",
+            "constructor()",
+            "event E()",
+        ];
+        for s in invalid {
+            assert!(
+                err(s).starts_with(&format!("Invalid type string: {s}")),
+                "{s}"
+            );
+        }
+        assert!(err("function f(uint7)").ends_with("uintX must be a multiple of 8 up to 256"));
+        assert!(err("function f(MyStruct s)").ends_with("unknown type `MyStruct`"));
+
+        assert!(parse_event("event E(uint256) indexed").is_err());
+        assert!(parse_event("event E(string memory s)").is_err());
+        assert!(parse_error("error E(uint256 indexed)").is_err());
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{param::Param, utils::*, EventParam, StateMutability};
-use alloc::{borrow::Cow, string::String, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, string::String, vec::Vec};
 use alloy_primitives::{keccak256, Selector, B256};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
 // Serde order:
 // Public items -> public enum -> private enum -> private items
@@ -27,7 +28,12 @@ macro_rules! abi_items {
             $vis struct $name {$(
                 $(#[$fattr])*
                 $fvis $field: $type,
-            )*}
+            )*
+                /// Any fields of the JSON object that are not part of the
+                /// specification, such as ones added by other compilers. These
+                /// are preserved when the item is serialized.
+                pub extra: BTreeMap<String, Value>,
+            }
 
             impl From<$name> for AbiItem<'_> {
                 #[inline]
@@ -87,7 +93,10 @@ macro_rules! abi_items {
                 #[repr(C)]
                 pub(super) struct $name {$(
                     $field: $type,
-                )*}
+                )*
+                    #[serde(flatten)]
+                    extra: BTreeMap<String, Value>,
+                }
             )*
 
             #[derive(Serialize, Deserialize)]
@@ -110,14 +119,12 @@ abi_items! {
     }
 
     /// A JSON ABI fallback function.
-    #[derive(Copy)]
     pub struct Fallback {
         /// The state mutability of the fallback function.
         pub state_mutability: StateMutability,
     }
 
     /// A JSON ABI receive function.
-    #[derive(Copy)]
     pub struct Receive {
         /// The state mutability of the receive function.
         pub state_mutability: StateMutability,
@@ -357,6 +364,28 @@ impl AbiItem<'_> {
 }
 
 impl Error {
+    /// Parses a human-readable Solidity error declaration, e.g.
+    /// `error InsufficientBalance(uint256 available, uint256 required)`.
+    ///
+    /// The `error` keyword is optional. See the [human-readable ABI
+    /// documentation](crate#human-readable-abi) for the supported syntax.
+    /// Parsing requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::Error;
+    ///
+    /// let error = Error::parse("error InsufficientBalance(uint256 available, uint256 required)")?;
+    /// assert_eq!(error.signature(), "InsufficientBalance(uint256,uint256)");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn parse(s: &str) -> crate::parser::Result<Self> {
+        crate::human_readable::parse_error(s)
+    }
+
     /// Computes this error's signature.
     ///
     /// This is the preimage input used to [compute the
@@ -374,6 +403,28 @@ impl Error {
 }
 
 impl Function {
+    /// Parses a human-readable Solidity function declaration, e.g.
+    /// `function transfer(address to, uint256 amount) external returns (bool)`.
+    ///
+    /// The `function` keyword is optional. See the [human-readable ABI
+    /// documentation](crate#human-readable-abi) for the supported syntax.
+    /// Parsing requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::Function;
+    ///
+    /// let function = Function::parse("function transfer(address to, uint256 amount) external returns (bool)")?;
+    /// assert_eq!(function.signature(), "transfer(address,uint256)");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn parse(s: &str) -> crate::parser::Result<Self> {
+        crate::human_readable::parse_function(s)
+    }
+
     /// Returns this function's signature.
     ///
     /// This is the preimage input used to [compute the
@@ -383,7 +434,7 @@ impl Function {
         signature(&self.name, &self.inputs)
     }
 
-    /// Computes this error's selector: `keccak256(self.signature())[..4]`
+    /// Computes this function's selector: `keccak256(self.signature())[..4]`
     #[inline]
     pub fn selector(&self) -> Selector {
         selector(&self.signature())
@@ -391,6 +442,29 @@ impl Function {
 }
 
 impl Event {
+    /// Parses a human-readable Solidity event declaration, e.g.
+    /// `event Transfer(address indexed from, address indexed to, uint256
+    /// value)`.
+    ///
+    /// The `event` keyword is optional. See the [human-readable ABI
+    /// documentation](crate#human-readable-abi) for the supported syntax.
+    /// Parsing requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::Event;
+    ///
+    /// let event = Event::parse("event Transfer(address indexed from, address indexed to, uint256 value)")?;
+    /// assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+    /// # Ok::<_, alloy_json_abi::parser::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn parse(s: &str) -> crate::parser::Result<Self> {
+        crate::human_readable::parse_event(s)
+    }
+
     /// Returns this event's signature.
     ///
     /// This is the preimage input used to [compute the
@@ -32,7 +32,12 @@ extern crate alloc;
 use serde::{Deserialize, Serialize};
 
 mod abi;
-pub use abi::{ContractObject, IntoItems, Items, JsonAbi};
+pub use abi::{ContractObject, IntoItems, Items, JsonAbi, Selectors};
+
+mod artifact;
+pub use artifact::{
+    Bytecode, BytecodeObject, ContractArtifact, LinkError, LinkReferences, Offsets,
+};
 
 mod item;
 pub use item::{AbiItem, Constructor, Error, Event, Fallback, Function, Receive};
@@ -43,6 +48,11 @@ pub use param::{EventParam, Param};
 mod internal_type;
 pub use internal_type::InternalType;
 
+#[cfg(feature = "std")]
+mod human_readable;
+
+mod to_sol;
+
 pub(crate) mod utils;
 
 pub use alloy_sol_type_parser as parser;
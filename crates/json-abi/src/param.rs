@@ -5,12 +5,14 @@ use crate::{
 };
 use alloc::{
     borrow::{Cow, ToOwned},
+    collections::BTreeMap,
     string::String,
     vec::Vec,
 };
 use alloy_sol_type_parser::TypeSpecifier;
 use core::fmt;
 use serde::{de::Unexpected, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
 /// JSON specification of a parameter.
 ///
@@ -36,6 +38,9 @@ pub struct Param {
     /// the author of the solidity contract specified. E.g. for a contract, this
     /// will be `contract MyContract` while the `type` field will be `address`.
     pub internal_type: Option<InternalType>,
+    /// Any fields of the JSON object that are not part of the specification.
+    /// These are preserved when the parameter is serialized.
+    pub extra: BTreeMap<String, Value>,
 }
 
 impl fmt::Display for Param {
@@ -58,6 +63,7 @@ impl<'de> Deserialize<'de> for Param {
                     ty: inner.ty.to_owned(),
                     internal_type: inner.internal_type.map(Into::into),
                     components: inner.components.into_owned(),
+                    extra: inner.extra.into_owned(),
                 })
             } else {
                 Err(serde::de::Error::custom(
@@ -180,6 +186,10 @@ impl Param {
             s.push_str(&self.ty)
         } else {
             crate::utils::signature_raw("", &self.components, s);
+            // array suffixes, e.g. `tuple[2][]`
+            if let Some(suffix) = self.ty.strip_prefix("tuple") {
+                s.push_str(suffix);
+            }
         }
     }
 
@@ -191,7 +201,9 @@ impl Param {
         if self.components.is_empty() {
             Cow::Borrowed(&self.ty)
         } else {
-            Cow::Owned(crate::utils::signature("", &self.components))
+            let mut s = String::with_capacity(self.components.len() * 32);
+            self.selector_type_raw(&mut s);
+            Cow::Owned(s)
         }
     }
 
@@ -207,6 +219,7 @@ impl Param {
             indexed: None,
             internal_type: self.borrowed_internal_type(),
             components: Cow::Borrowed(&self.components),
+            extra: Cow::Borrowed(&self.extra),
         }
     }
 }
@@ -237,6 +250,9 @@ pub struct EventParam {
     /// the author of the solidity contract specified. E.g. for a contract, this
     /// will be `contract MyContract` while the `type` field will be `address`.
     pub internal_type: Option<InternalType>,
+    /// Any fields of the JSON object that are not part of the specification.
+    /// These are preserved when the parameter is serialized.
+    pub extra: BTreeMap<String, Value>,
 }
 
 impl fmt::Display for EventParam {
@@ -260,6 +276,7 @@ impl<'de> Deserialize<'de> for EventParam {
                     indexed,
                     internal_type: gp.internal_type.map(Into::into),
                     components: gp.components.into_owned(),
+                    extra: gp.extra.into_owned(),
                 })
             } else {
                 Err(serde::de::Error::custom(
@@ -381,7 +398,11 @@ impl EventParam {
         if self.components.is_empty() {
             s.push_str(&self.ty)
         } else {
-            crate::utils::signature_raw("", &self.components, s)
+            crate::utils::signature_raw("", &self.components, s);
+            // array suffixes, e.g. `tuple[2][]`
+            if let Some(suffix) = self.ty.strip_prefix("tuple") {
+                s.push_str(suffix);
+            }
         }
     }
 
@@ -393,7 +414,9 @@ impl EventParam {
         if self.components.is_empty() {
             Cow::Borrowed(&self.ty)
         } else {
-            Cow::Owned(crate::utils::signature("", &self.components))
+            let mut s = String::with_capacity(self.components.len() * 32);
+            self.selector_type_raw(&mut s);
+            Cow::Owned(s)
         }
     }
 
@@ -409,6 +432,7 @@ impl EventParam {
             indexed: Some(self.indexed),
             internal_type: self.borrowed_internal_type(),
             components: Cow::Borrowed(&self.components),
+            extra: Cow::Borrowed(&self.extra),
         }
     }
 }
@@ -430,6 +454,8 @@ struct BorrowedParam<'a, T: Clone> {
     internal_type: Option<BorrowedInternalType<'a>>,
     #[serde(default, skip_serializing_if = "<[_]>::is_empty")]
     components: Cow<'a, [T]>,
+    #[serde(flatten)]
+    extra: Cow<'a, BTreeMap<String, Value>>,
 }
 
 #[cfg(test)]
@@ -445,4 +471,30 @@ mod tests {
         }"#;
         let _param = serde_json::from_str::<Param>(param).unwrap();
     }
+
+    #[test]
+    fn tuple_array_selector_type() {
+        let param = r#"{
+            "name": "pairs",
+            "type": "tuple[2][]",
+            "components": [
+                { "name": "a", "type": "uint256" },
+                { "name": "b", "type": "bool" }
+            ]
+        }"#;
+        let param = serde_json::from_str::<Param>(param).unwrap();
+        assert_eq!(param.selector_type(), "(uint256,bool)[2][]");
+
+        let param = r#"{
+            "name": "items",
+            "type": "tuple[]",
+            "indexed": false,
+            "components": [
+                { "name": "to", "type": "address" },
+                { "name": "data", "type": "bytes32[]" }
+            ]
+        }"#;
+        let param = serde_json::from_str::<EventParam>(param).unwrap();
+        assert_eq!(param.selector_type(), "(address,bytes32[])[]");
+    }
 }
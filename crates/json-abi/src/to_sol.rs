@@ -0,0 +1,463 @@
+use crate::{Error, Event, EventParam, Function, InternalType, JsonAbi, Param, StateMutability};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::fmt::Write;
+
+impl JsonAbi {
+    /// Renders the ABI as the source code of a Solidity `interface` named
+    /// `name`.
+    ///
+    /// Struct definitions are reconstructed from the components of tuple
+    /// parameters, using the names given by their `internalType`. Tuples
+    /// without one are given generated names (`Tuple0`, `Tuple1`, ...).
+    ///
+    /// All types are declared in the interface, so a type whose name is
+    /// already taken by a different definition, like `B.S` after `A.S`, is
+    /// renamed to `B_S`, or to `S_1`, `S_2`, ... if that is taken too.
+    ///
+    /// Some information is not present in the ABI, so the result is only
+    /// ABI-compatible with the original contract:
+    /// - user-defined value types are declared with the underlying type of
+    ///   their first use;
+    /// - enums are declared as `uint8` user-defined value types, since their
+    ///   variants are unknown;
+    /// - contract types are replaced by `address`;
+    /// - the constructor is omitted, as interfaces cannot declare one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_json_abi::JsonAbi;
+    ///
+    /// let abi: JsonAbi = serde_json::from_str(
+    ///     r#"[{
+    ///         "type": "function",
+    ///         "name": "balanceOf",
+    ///         "inputs": [{ "name": "owner", "type": "address" }],
+    ///         "outputs": [{ "name": "", "type": "uint256" }],
+    ///         "stateMutability": "view"
+    ///     }]"#,
+    /// )?;
+    /// assert_eq!(
+    ///     abi.to_sol("IToken"),
+    ///     "\
+    /// interface IToken {
+    ///     function balanceOf(address owner) external view returns (uint256);
+    /// }
+    /// "
+    /// );
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    pub fn to_sol(&self, name: &str) -> String {
+        SolPrinter::default().print(self, name)
+    }
+}
+
+/// The borrowed fields shared by [`Param`] and [`EventParam`].
+#[derive(Clone, Copy)]
+struct ParamRef<'a> {
+    name: &'a str,
+    ty: &'a str,
+    internal_type: Option<&'a InternalType>,
+    components: &'a [Param],
+    indexed: bool,
+}
+
+impl<'a> From<&'a Param> for ParamRef<'a> {
+    fn from(param: &'a Param) -> Self {
+        Self {
+            name: &param.name,
+            ty: &param.ty,
+            internal_type: param.internal_type.as_ref(),
+            components: &param.components,
+            indexed: false,
+        }
+    }
+}
+
+impl<'a> From<&'a EventParam> for ParamRef<'a> {
+    fn from(param: &'a EventParam) -> Self {
+        Self {
+            name: &param.name,
+            ty: &param.ty,
+            internal_type: param.internal_type.as_ref(),
+            components: &param.components,
+            indexed: param.indexed,
+        }
+    }
+}
+
+impl ParamRef<'_> {
+    /// Whether the type needs a data location in function parameters.
+    fn is_reference(&self) -> bool {
+        !self.components.is_empty()
+            || self.ty.ends_with(']')
+            || self.ty == "bytes"
+            || self.ty == "string"
+    }
+}
+
+#[derive(Default)]
+struct SolPrinter<'a> {
+    /// The names of all the declared types.
+    names: BTreeSet<String>,
+    /// `(qualified name, underlying type) => name`
+    udvts: BTreeMap<(String, &'a str), String>,
+    /// `qualified name => name`
+    enums: BTreeMap<String, String>,
+    /// `(qualified name, fields, name)`, in order of first use. Tuples without
+    /// a struct internal type have no qualified name.
+    structs: Vec<(Option<String>, &'a [Param], String)>,
+}
+
+impl<'a> SolPrinter<'a> {
+    fn print(mut self, abi: &'a JsonAbi, name: &str) -> String {
+        let mut items = Vec::new();
+        items.extend(abi.events().map(|event| self.event(event)));
+        items.extend(abi.errors().map(|error| self.error(error)));
+        if let Some(fallback) = &abi.fallback {
+            items.push(format!(
+                "fallback() external{};",
+                mutability(fallback.state_mutability)
+            ));
+        }
+        if let Some(receive) = &abi.receive {
+            items.push(format!(
+                "receive() external{};",
+                mutability(receive.state_mutability)
+            ));
+        }
+        items.extend(abi.functions().map(|function| self.function(function)));
+
+        // printing struct fields may add more structs
+        let mut structs = Vec::new();
+        let mut i = 0;
+        while let Some((_, fields, name)) = self.structs.get(i) {
+            let (fields, name) = (*fields, name.clone());
+            structs.push(self.r#struct(&name, fields));
+            i += 1;
+        }
+
+        let mut udvts: Vec<_> = self
+            .udvts
+            .iter()
+            .map(|((_, ty), name)| (name, ty))
+            .collect();
+        udvts.sort();
+        let mut enums: Vec<_> = self.enums.values().collect();
+        enums.sort();
+
+        let mut out = format!("interface {name} {{\n");
+        let types = udvts
+            .into_iter()
+            .map(|(name, ty)| format!("type {name} is {ty};"))
+            .chain(
+                enums
+                    .into_iter()
+                    .map(|name| format!("type {name} is uint8;")),
+            );
+        for item in types.chain(structs).chain(items) {
+            for line in item.lines() {
+                let _ = writeln!(out, "    {line}");
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Reserves a unique type name, preferably `name`, then
+    /// `{contract}_{name}`.
+    fn declare(&mut self, name: &str, contract: Option<&str>) -> String {
+        let name = core::iter::once(name.into())
+            .chain(contract.map(|contract| format!("{contract}_{name}")))
+            .chain((1..).map(|i| format!("{name}_{i}")))
+            .find(|name| !self.names.contains(name))
+            .unwrap();
+        self.names.insert(name.clone());
+        name
+    }
+
+    fn event(&mut self, event: &'a Event) -> String {
+        let params = self.params(event.inputs.iter().map(Into::into), false);
+        let anonymous = if event.anonymous { " anonymous" } else { "" };
+        format!("event {}({params}){anonymous};", event.name)
+    }
+
+    fn error(&mut self, error: &'a Error) -> String {
+        let params = self.params(error.inputs.iter().map(Into::into), false);
+        format!("error {}({params});", error.name)
+    }
+
+    fn function(&mut self, function: &'a Function) -> String {
+        let inputs = self.params(function.inputs.iter().map(Into::into), true);
+        let mut s = format!(
+            "function {}({inputs}) external{}",
+            function.name,
+            mutability(function.state_mutability)
+        );
+        if !function.outputs.is_empty() {
+            let outputs = self.params(function.outputs.iter().map(Into::into), true);
+            let _ = write!(s, " returns ({outputs})");
+        }
+        s.push(';');
+        s
+    }
+
+    fn r#struct(&mut self, name: &str, fields: &'a [Param]) -> String {
+        let mut s = format!("struct {name} {{\n");
+        for (i, field) in fields.iter().enumerate() {
+            let ty = self.ty(field.into());
+            if field.name.is_empty() {
+                let _ = writeln!(s, "    {ty} field{i};");
+            } else {
+                let _ = writeln!(s, "    {ty} {};", field.name);
+            }
+        }
+        s.push('}');
+        s
+    }
+
+    /// Returns a comma-separated parameter list. Function parameters of
+    /// reference types are declared in `memory`.
+    fn params<I>(&mut self, params: I, is_function: bool) -> String
+    where
+        I: Iterator<Item = ParamRef<'a>>,
+    {
+        let mut s = String::new();
+        for (i, param) in params.enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            s.push_str(&self.ty(param));
+            if is_function && param.is_reference() {
+                s.push_str(" memory");
+            }
+            if param.indexed {
+                s.push_str(" indexed");
+            }
+            if !param.name.is_empty() {
+                s.push(' ');
+                s.push_str(param.name);
+            }
+        }
+        s
+    }
+
+    /// Returns the name of the struct with the given `(contract, name)` and
+    /// fields, declaring it on first use. Tuples without a struct internal
+    /// type are named `Tuple{i}`.
+    fn struct_name(&mut self, ty: Option<(Option<&str>, &str)>, fields: &'a [Param]) -> String {
+        let key = ty.map(|(contract, ty)| qualified(contract, ty));
+        if let Some((_, _, name)) = self
+            .structs
+            .iter()
+            .find(|(k, f, _)| *k == key && *f == fields)
+        {
+            return name.clone()
+        }
+        let name = match ty {
+            Some((contract, ty)) => self.declare(ty, contract),
+            None => {
+                let i = self.structs.iter().filter(|(k, ..)| k.is_none()).count();
+                self.declare(&format!("Tuple{i}"), None)
+            }
+        };
+        self.structs.push((key, fields, name.clone()));
+        name
+    }
+
+    /// Returns the Solidity type of a parameter, declaring any user-defined
+    /// types it uses.
+    fn ty(&mut self, param: ParamRef<'a>) -> String {
+        // array suffixes, e.g. `[2][]`
+        let suffix = param.ty.find('[').map_or("", |i| &param.ty[i..]);
+        match param.internal_type {
+            Some(InternalType::Struct { contract, ty }) => {
+                let name = self.struct_name(
+                    Some((contract.as_deref(), strip_suffix(ty))),
+                    param.components,
+                );
+                format!("{name}{suffix}")
+            }
+            Some(InternalType::Enum { contract, ty }) => {
+                let ty = strip_suffix(ty);
+                let key = qualified(contract.as_deref(), ty);
+                let name = match self.enums.get(&key) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.declare(ty, contract.as_deref());
+                        self.enums.insert(key, name.clone());
+                        name
+                    }
+                };
+                format!("{name}{suffix}")
+            }
+            Some(InternalType::Other { contract, ty })
+                if param.components.is_empty()
+                    && (contract.is_some() || strip_suffix(ty) != strip_suffix(param.ty)) =>
+            {
+                let ty = strip_suffix(ty);
+                let key = (qualified(contract.as_deref(), ty), strip_suffix(param.ty));
+                let name = match self.udvts.get(&key) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self.declare(ty, contract.as_deref());
+                        self.udvts.insert(key, name.clone());
+                        name
+                    }
+                };
+                format!("{name}{suffix}")
+            }
+            Some(InternalType::AddressPayable(_)) => format!("address payable{suffix}"),
+            _ if !param.components.is_empty() => {
+                let name = self.struct_name(None, param.components);
+                format!("{name}{suffix}")
+            }
+            _ => param.ty.into(),
+        }
+    }
+}
+
+fn qualified(contract: Option<&str>, ty: &str) -> String {
+    match contract {
+        Some(contract) => format!("{contract}.{ty}"),
+        None => ty.into(),
+    }
+}
+
+fn strip_suffix(ty: &str) -> &str {
+    ty.split('[').next().unwrap()
+}
+
+fn mutability(state_mutability: StateMutability) -> String {
+    state_mutability
+        .as_str()
+        .map(|s| format!(" {s}"))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_sol() {
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[
+                {"type":"constructor","inputs":[],"stateMutability":"nonpayable"},
+                {"type":"receive","stateMutability":"payable"},
+                {"type":"fallback","stateMutability":"nonpayable"},
+                {
+                    "type":"function","name":"fill","stateMutability":"payable",
+                    "inputs":[
+                        {"name":"orders","type":"tuple[]","internalType":"struct Market.Order[]","components":[
+                            {"name":"maker","type":"address","internalType":"address payable"},
+                            {"name":"kind","type":"uint8","internalType":"enum Market.Kind"},
+                            {"name":"price","type":"uint256","internalType":"Price"},
+                            {"name":"token","type":"address","internalType":"contract IERC20"}
+                        ]},
+                        {"name":"data","type":"bytes","internalType":"bytes"}
+                    ],
+                    "outputs":[
+                        {"name":"","type":"tuple","components":[
+                            {"name":"a","type":"uint256"},
+                            {"name":"","type":"string[2]"}
+                        ]},
+                        {"name":"ok","type":"bool","internalType":"bool"}
+                    ]
+                },
+                {
+                    "type":"event","name":"Filled","anonymous":true,
+                    "inputs":[{"name":"maker","type":"address","indexed":true}]
+                },
+                {
+                    "type":"error","name":"Expired",
+                    "inputs":[{"name":"at","type":"uint64","internalType":"Time.Timestamp"}]
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            abi.to_sol("IMarket"),
+            "\
+interface IMarket {
+    type Price is uint256;
+    type Timestamp is uint64;
+    type Kind is uint8;
+    struct Order {
+        address payable maker;
+        Kind kind;
+        Price price;
+        address token;
+    }
+    struct Tuple0 {
+        uint256 a;
+        string[2] field1;
+    }
+    event Filled(address indexed maker) anonymous;
+    error Expired(Timestamp at);
+    fallback() external;
+    receive() external payable;
+    function fill(Order[] memory orders, bytes memory data) external payable returns (Tuple0 memory, bool ok);
+}
+"
+        );
+    }
+
+    #[test]
+    fn name_conflicts() {
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[
+                {
+                    "type":"function","name":"f","stateMutability":"nonpayable",
+                    "inputs":[
+                        {"name":"a","type":"tuple","internalType":"struct A.S","components":[
+                            {"name":"x","type":"uint256","internalType":"A.Price"}
+                        ]},
+                        {"name":"b","type":"tuple","internalType":"struct B.S","components":[
+                            {"name":"y","type":"uint128","internalType":"B.Price"}
+                        ]},
+                        {"name":"c","type":"tuple[]","internalType":"struct A.S[]","components":[
+                            {"name":"x","type":"uint256","internalType":"A.Price"}
+                        ]},
+                        {"name":"d","type":"tuple","internalType":"struct Tuple0","components":[
+                            {"name":"z","type":"bool","internalType":"bool"}
+                        ]},
+                        {"name":"e","type":"tuple","components":[
+                            {"name":"w","type":"address"}
+                        ]}
+                    ],
+                    "outputs":[]
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            abi.to_sol("I"),
+            "\
+interface I {
+    type B_Price is uint128;
+    type Price is uint256;
+    struct S {
+        Price x;
+    }
+    struct B_S {
+        B_Price y;
+    }
+    struct Tuple0 {
+        bool z;
+    }
+    struct Tuple0_1 {
+        address w;
+    }
+    function f(S memory a, B_S memory b, S[] memory c, Tuple0 memory d, Tuple0_1 memory e) external;
+}
+"
+        );
+    }
+}
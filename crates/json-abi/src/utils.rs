@@ -16,6 +16,18 @@ macro_rules! signature {
     }};
 }
 
+macro_rules! set_if_none {
+    ($opt:expr, $val:expr) => { $crate::utils::set_if_none!(stringify!($opt) => $opt, $val) };
+    ($name:expr => $opt:expr, $val:expr) => {{
+        if $opt.is_some() {
+            return Err(serde::de::Error::duplicate_field($name))
+        }
+        $opt = Some($val);
+    }};
+}
+
+pub(crate) use set_if_none;
+
 macro_rules! validate_identifier {
     ($name:expr) => {
         if !$name.is_empty() && !alloy_sol_type_parser::is_valid_identifier($name) {
@@ -88,6 +100,7 @@ mod tests {
             ty: kind.to_string(),
             internal_type: None,
             components: vec![],
+            extra: Default::default(),
         }
     }
 
@@ -98,6 +111,7 @@ mod tests {
             ty: "ty".to_string(),
             internal_type: None,
             components,
+            extra: Default::default(),
         }
     }
 
@@ -1,6 +1,196 @@
-use alloy_json_abi::{AbiItem, Error, EventParam, JsonAbi, Param};
+use alloy_json_abi::{
+    AbiItem, ContractArtifact, ContractObject, Error, EventParam, JsonAbi, Param,
+};
+use alloy_primitives::{keccak256, Address, Bytes};
 use std::{collections::HashMap, fs::File, io::BufReader};
 
+#[test]
+fn tuple_selectors() {
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "fill",
+            "inputs": [
+                {"name": "orders", "type": "tuple[2][]", "components": [
+                    {"name": "maker", "type": "address"},
+                    {"name": "amounts", "type": "tuple", "components": [
+                        {"name": "", "type": "uint256[]"},
+                        {"name": "", "type": "bytes32"}
+                    ]}
+                ]},
+                {"name": "data", "type": "bytes"}
+            ],
+            "outputs": [],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "event",
+            "name": "Filled",
+            "inputs": [
+                {"name": "order", "type": "tuple", "indexed": true, "components": [
+                    {"name": "maker", "type": "address"}
+                ]}
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "event",
+            "name": "Logged",
+            "inputs": [],
+            "anonymous": true
+        },
+        {
+            "type": "error",
+            "name": "Transfer",
+            "inputs": [{"name": "", "type": "tuple[]", "components": [{"name": "", "type": "bool"}]}]
+        },
+        {
+            "type": "error",
+            "name": "Transfer",
+            "inputs": [{"name": "value", "type": "tuple[]", "components": [{"name": "", "type": "bool"}]}]
+        }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+
+    let function = &abi.function("fill").unwrap()[0];
+    let signature = "fill((address,(uint256[],bytes32))[2][],bytes)";
+    assert_eq!(function.signature(), signature);
+    assert_eq!(function.selector(), keccak256(signature)[..4]);
+
+    let event = &abi.event("Filled").unwrap()[0];
+    assert_eq!(event.signature(), "Filled((address))");
+    assert_eq!(event.selector(), keccak256("Filled((address))"));
+
+    let error = &abi.error("Transfer").unwrap()[0];
+    assert_eq!(error.signature(), "Transfer((bool)[])");
+    assert_eq!(error.selector(), keccak256("Transfer((bool)[])")[..4]);
+
+    let selectors = abi.selectors();
+    assert_eq!(selectors.function(function.selector()), Some(function));
+    assert_eq!(selectors.event(event.selector()), Some(event));
+    assert_eq!(selectors.error(error.selector()), Some(error));
+    assert_eq!(selectors.function(error.selector()), None);
+    assert_eq!(selectors.error(function.selector()), None);
+    assert_eq!(selectors.functions().count(), 1);
+    assert_eq!(selectors.events().count(), 1);
+    assert_eq!(selectors.errors().count(), 1);
+
+    // anonymous events are not indexed
+    let anonymous = &abi.event("Logged").unwrap()[0];
+    assert_eq!(selectors.event(anonymous.selector()), None);
+
+    // the first of the errors with the same selector is indexed
+    let errors = abi.error("Transfer").unwrap();
+    assert_eq!(errors[1].selector(), error.selector());
+    assert!(std::ptr::eq(
+        selectors.error(error.selector()).unwrap(),
+        &errors[0]
+    ));
+}
+
+#[test]
+fn artifacts() {
+    let abi =
+        r#"[{"type":"function","name":"f","inputs":[],"outputs":[],"stateMutability":"pure"}]"#;
+    let placeholder = "__$2e8ee3b5cbac8a6d1d2a3a3e9cc7b8b8ed$__";
+    let link_references = r#"{"src/Lib.sol":{"Lib":[{"start":1,"length":20}]}}"#;
+
+    let foundry = format!(
+        r#"{{
+            "abi": {abi},
+            "bytecode": {{
+                "object": "0x73{placeholder}00",
+                "sourceMap": "1:2:3:-:0",
+                "linkReferences": {link_references}
+            }},
+            "deployedBytecode": {{
+                "object": "0x6001",
+                "sourceMap": "",
+                "linkReferences": {{}},
+                "immutableReferences": {{"7": [{{"start": 0, "length": 32}}]}}
+            }},
+            "methodIdentifiers": {{"f()": "26121ff0"}},
+            "rawMetadata": "{{\"version\":1}}",
+            "metadata": {{"version": 1}},
+            "id": 3
+        }}"#
+    );
+    let hardhat = format!(
+        r#"{{
+            "_format": "hh-sol-artifact-1",
+            "contractName": "C",
+            "sourceName": "src/C.sol",
+            "abi": {abi},
+            "bytecode": "0x73{placeholder}00",
+            "deployedBytecode": "0x6001",
+            "linkReferences": {link_references},
+            "deployedLinkReferences": {{}}
+        }}"#
+    );
+    let solc = format!(
+        r#"{{
+            "abi": {abi},
+            "metadata": "{{\"version\":1}}",
+            "evm": {{
+                "bytecode": {{
+                    "object": "73{placeholder}00",
+                    "linkReferences": {link_references}
+                }},
+                "deployedBytecode": {{
+                    "object": "6001",
+                    "immutableReferences": {{"7": [{{"start": 0, "length": 32}}]}}
+                }},
+                "methodIdentifiers": {{"f()": "26121ff0"}}
+            }}
+        }}"#
+    );
+
+    let lib = Address::repeat_byte(0x11);
+    let linked = [&[0x73][..], &lib[..], &[0x00]].concat();
+    for (json, full) in [(foundry, true), (hardhat, false), (solc, true)] {
+        let mut artifact: ContractArtifact = serde_json::from_str(&json).unwrap();
+        assert_eq!(artifact.abi.functions().count(), 1);
+
+        let bytecode = artifact.bytecode.as_ref().unwrap();
+        assert!(bytecode.object.is_unlinked());
+        assert_eq!(bytecode.link_references["src/Lib.sol"]["Lib"].len(), 1);
+
+        let deployed = artifact.deployed_bytecode.as_ref().unwrap();
+        assert_eq!(deployed.bytes(), Some(&Bytes::from_static(&[0x60, 0x01])));
+        if full {
+            assert_eq!(deployed.immutable_references["7"][0].length, 32);
+            assert_eq!(artifact.method_identifiers["f()"], [0x26, 0x12, 0x1f, 0xf0]);
+            assert_eq!(artifact.metadata.as_deref(), Some(r#"{"version":1}"#));
+        } else {
+            assert!(artifact.method_identifiers.is_empty());
+            assert_eq!(artifact.metadata, None);
+        }
+
+        // unlinked bytecode is dropped when converting to a `ContractObject`
+        let object = ContractObject::from(artifact.clone());
+        assert_eq!(object.bytecode, None);
+
+        assert!(artifact.link("src/Lib.sol", "Lib", lib).unwrap());
+        assert_eq!(
+            artifact.bytecode.as_ref().unwrap().bytes(),
+            Some(&linked.clone().into())
+        );
+
+        let object = ContractObject::from(artifact.clone());
+        assert_eq!(object.bytecode, Some(linked.clone().into()));
+
+        let json = serde_json::to_string(&artifact).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ContractArtifact>(&json).unwrap(),
+            artifact
+        );
+    }
+
+    let artifact: ContractArtifact = serde_json::from_str(abi).unwrap();
+    assert_eq!(artifact.abi.functions().count(), 1);
+    assert_eq!(artifact.bytecode, None);
+}
+
 #[test]
 fn complex_error() {
     let json = r#"{
@@ -26,12 +216,69 @@ fn complex_error() {
                 name: "reason".into(),
                 ty: "string".into(),
                 components: vec![],
+                extra: Default::default(),
             }],
-            name: "SomeName".into()
+            name: "SomeName".into(),
+            extra: Default::default(),
         }
     );
 }
 
+#[test]
+fn unknown_fields() {
+    let json = r#"[
+        {
+            "type": "function",
+            "name": "f",
+            "inputs": [],
+            "outputs": [],
+            "stateMutability": "view",
+            "constant": true,
+            "gas": 1234,
+            "devdoc": { "details": "does nothing" }
+        },
+        {
+            "type": "event",
+            "name": "E",
+            "inputs": [
+                {
+                    "name": "s",
+                    "type": "tuple",
+                    "indexed": false,
+                    "components": [
+                        { "name": "a", "type": "uint256", "x-docs": "the amount" }
+                    ],
+                    "x-id": 1
+                }
+            ],
+            "anonymous": false,
+            "id": null
+        },
+        { "type": "receive", "stateMutability": "payable", "payable": true }
+    ]"#;
+    let abi: JsonAbi = serde_json::from_str(json).unwrap();
+
+    let f = &abi.function("f").unwrap()[0];
+    assert_eq!(f.extra.len(), 3);
+    assert_eq!(f.extra["constant"], true);
+    assert_eq!(f.extra["gas"], 1234);
+    assert_eq!(f.extra["devdoc"]["details"], "does nothing");
+    assert_eq!(f.selector(), keccak256("f()")[..4]);
+    let e = &abi.event("E").unwrap()[0];
+    assert_eq!(e.extra["id"], serde_json::Value::Null);
+    assert_eq!(e.inputs[0].extra["x-id"], 1);
+    assert_eq!(e.inputs[0].components[0].extra["x-docs"], "the amount");
+    assert_eq!(e.selector(), keccak256("E((uint256))"));
+    assert_eq!(abi.receive.as_ref().unwrap().extra["payable"], true);
+
+    // unknown fields survive a round trip, without duplicating the tag
+    let original: serde_json::Value = serde_json::from_str(json).unwrap();
+    let items: Vec<AbiItem<'_>> = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_value(&items).unwrap(), original);
+    let json = serde_json::to_string(&abi).unwrap();
+    assert_eq!(serde_json::from_str::<JsonAbi>(&json).unwrap(), abi);
+}
+
 macro_rules! abi_parse_tests {
     ($($name:ident($path:literal, $len:literal))*) => {$(
         #[test]
@@ -88,6 +335,14 @@ fn parse_test(s: &str, len: usize, path: &str) {
     let abi_items: Vec<AbiItem<'_>> = serde_json::from_str(s).unwrap();
     assert_eq!(abi_items.len(), len);
 
+    // the items serialize back to the original JSON, up to formatting
+    let original: serde_json::Value = serde_json::from_str(s).unwrap();
+    assert_eq!(
+        serde_json::to_value(&abi_items).unwrap(),
+        original,
+        "{path}"
+    );
+
     let json: String = serde_json::to_string(&abi_items).unwrap();
     let abi1: JsonAbi = serde_json::from_str(&json).unwrap();
 
@@ -141,6 +396,7 @@ fn method_tests(abi: &JsonAbi) {
     test_functions(abi);
     test_events(abi);
     test_errors(abi);
+    test_selectors(abi);
 }
 
 fn test_functions(abi: &JsonAbi) {
@@ -193,6 +449,22 @@ fn test_events(abi: &JsonAbi) {
         });
 }
 
+fn test_selectors(abi: &JsonAbi) {
+    let selectors = abi.selectors();
+    for f in abi.functions() {
+        let found = selectors.function(f.selector()).unwrap();
+        assert_eq!(found.signature(), f.signature());
+    }
+    for e in abi.events().filter(|e| !e.anonymous) {
+        let found = selectors.event(e.selector()).unwrap();
+        assert_eq!(found.signature(), e.signature());
+    }
+    for e in abi.errors() {
+        let found = selectors.error(e.selector()).unwrap();
+        assert_eq!(found.signature(), e.signature());
+    }
+}
+
 fn test_event_param(param: &EventParam) {
     if param.components.is_empty() {
         assert!(!param.ty.contains("tuple"));
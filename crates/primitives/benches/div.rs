@@ -0,0 +1,85 @@
+use alloy_primitives::{
+    utils::{div_rem_u64, divmod_10, to_decimal_string},
+    U256,
+};
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+use std::time::Duration;
+
+static VALUES: &[U256] = &[
+    U256::from_limbs([0x1234_5678_9abc_def0, 0, 0, 0]),
+    U256::from_limbs([u64::MAX, u64::MAX, 0, 0]),
+    U256::from_limbs([0x0123_4567_89ab_cdef, 0xfedc_ba98_7654_3210, 1, 0x8000]),
+    U256::MAX,
+];
+
+fn div(c: &mut Criterion) {
+    let mut g = group(c, "div");
+    let d = 1_000_000_007u64;
+
+    g.bench_function("div_rem_u64", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(div_rem_u64(black_box(x), black_box(d)));
+            }
+        })
+    });
+    g.bench_function("uint_div_rem", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(black_box(x).div_rem(U256::from(black_box(d))));
+            }
+        })
+    });
+
+    g.bench_function("divmod_10", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(divmod_10(black_box(x)));
+            }
+        })
+    });
+    g.bench_function("uint_div_rem_10", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(black_box(x).div_rem(U256::from(10)));
+            }
+        })
+    });
+
+    g.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let mut g = group(c, "format");
+
+    g.bench_function("to_decimal_string", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(to_decimal_string(black_box(x)));
+            }
+        })
+    });
+    g.bench_function("to_string", |b| {
+        b.iter(|| {
+            for &x in VALUES {
+                black_box(black_box(x).to_string());
+            }
+        })
+    });
+
+    g.finish();
+}
+
+fn group<'a>(c: &'a mut Criterion, group_name: &str) -> BenchmarkGroup<'a, WallTime> {
+    let mut g = c.benchmark_group(group_name);
+    g.noise_threshold(0.03)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .sample_size(200);
+    g
+}
+
+criterion_group!(benches, div, format);
+criterion_main!(benches);
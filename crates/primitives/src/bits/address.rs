@@ -1,4 +1,8 @@
-use crate::{aliases::U160, utils::keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{
+    aliases::U160,
+    utils::{self, keccak256},
+    wrap_fixed_bytes, FixedBytes, ParseFixedBytesError, ParseOptions,
+};
 use alloc::{
     borrow::Borrow,
     string::{String, ToString},
@@ -11,6 +15,9 @@ pub enum AddressError {
     /// Error while decoding hex.
     Hex(hex::FromHexError),
 
+    /// The `0x` prefix is required but missing.
+    MissingPrefix,
+
     /// Invalid ERC-55 checksum.
     InvalidChecksum,
 }
@@ -21,6 +28,15 @@ impl From<hex::FromHexError> for AddressError {
     }
 }
 
+impl From<ParseFixedBytesError> for AddressError {
+    fn from(value: ParseFixedBytesError) -> Self {
+        match value {
+            ParseFixedBytesError::MissingPrefix => Self::MissingPrefix,
+            ParseFixedBytesError::Hex(err) => Self::Hex(err),
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for AddressError {}
 
@@ -28,6 +44,7 @@ impl fmt::Display for AddressError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Hex(err) => err.fmt(f),
+            Self::MissingPrefix => f.write_str("missing `0x` prefix"),
             Self::InvalidChecksum => f.write_str("Bad address checksum"),
         }
     }
@@ -94,15 +111,17 @@ impl From<Address> for U160 {
 impl fmt::Display for Address {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut buf = [0; 42];
-        let checksum = self.to_checksum_raw(&mut buf, None);
+        let checksum = &self.to_checksum_raw(&mut buf, None)[2..];
         if f.alternate() {
             // If the alternate flag is set, use middle-out compression
             // "0x" + first 4 bytes + "…" + last 4 bytes
-            f.write_str(&checksum[..6])?;
-            f.write_str("…")?;
-            f.write_str(&checksum[38..])
+            utils::pad_integral(f, "0x", 4 + 1 + 4, |f| {
+                f.write_str(&checksum[..4])?;
+                f.write_str("…")?;
+                f.write_str(&checksum[36..])
+            })
         } else {
-            f.write_str(checksum)
+            utils::pad_integral(f, "0x", checksum.len(), |f| f.write_str(checksum))
         }
     }
 }
@@ -176,7 +195,7 @@ impl Address {
         fn inner(s: &str, chain_id: Option<u64>) -> Result<Address, AddressError> {
             // checksummed addresses always start with the "0x" prefix
             if !s.starts_with("0x") {
-                return Err(AddressError::Hex(hex::FromHexError::InvalidStringLength))
+                return Err(AddressError::MissingPrefix)
             }
 
             let address: Address = s.parse()?;
@@ -192,6 +211,38 @@ impl Address {
         inner(s.as_ref(), chain_id)
     }
 
+    /// Parses an Ethereum address from a hex string with the given options.
+    ///
+    /// See [`FixedBytes::parse_with`] for more details. If a checksum is
+    /// required, the string must contain all 40 hex digits, in the case given
+    /// by [`to_checksum`](Self::to_checksum).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not valid hex with the given options,
+    /// or if it does not match the required checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use alloy_primitives::{address, Address, ParseOptions};
+    /// let opts = ParseOptions::new().require_prefix(true).require_checksum(true);
+    /// let address = Address::parse_with("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045", opts);
+    /// assert_eq!(address.unwrap(), address!("d8da6bf26964af9d7eed9e03e53415d37aa96045"));
+    /// assert!(Address::parse_with("0xd8da6bf26964af9d7eed9e03e53415d37aa96045", opts).is_err());
+    /// ```
+    pub fn parse_with(s: &str, opts: ParseOptions) -> Result<Self, AddressError> {
+        let address = Self(FixedBytes::parse_with(s, opts)?);
+        if opts.require_checksum {
+            let buf = &mut [0; 42];
+            let expected = address.to_checksum_raw(buf, opts.chain_id);
+            if s.strip_prefix("0x").unwrap_or(s) != &expected[2..] {
+                return Err(AddressError::InvalidChecksum)
+            }
+        }
+        Ok(address)
+    }
+
     /// Encodes an Ethereum address to its [EIP-55] checksum.
     ///
     /// You can optionally specify an [EIP-155 chain ID] to encode the address
@@ -312,30 +363,41 @@ impl Address {
     /// let expected = address!("e33c6e89e69d085897f98e92b06ebd541d1daa99");
     /// assert_eq!(sender.create(1), expected);
     /// ```
-    #[cfg(feature = "rlp")]
     #[inline]
     #[must_use]
     pub fn create(&self, nonce: u64) -> Self {
-        use alloy_rlp::{Encodable, EMPTY_LIST_CODE, EMPTY_STRING_CODE};
+        // RLP string and list headers
+        const EMPTY_STRING_CODE: u8 = 0x80;
+        const EMPTY_LIST_CODE: u8 = 0xc0;
 
         // max u64 encoded length is `1 + u64::BYTES`
         const MAX_LEN: usize = 1 + (1 + 20) + 9;
 
-        let len = 22 + nonce.length();
-        debug_assert!(len <= MAX_LEN);
-
         let mut out = [0u8; MAX_LEN];
 
-        // list header
-        // minus 1 to account for the list header itself
-        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
-
         // address header + address
         out[1] = EMPTY_STRING_CODE + 20;
         out[2..22].copy_from_slice(self.as_slice());
 
-        // nonce
-        nonce.encode(&mut &mut out[22..]);
+        // nonce: single bytes below 0x80 are their own encoding, and zero is
+        // the empty string
+        let len = match nonce {
+            1..=0x7f => {
+                out[22] = nonce as u8;
+                23
+            }
+            _ => {
+                let bytes = nonce.to_be_bytes();
+                let start = (nonce.leading_zeros() / 8) as usize;
+                out[22] = EMPTY_STRING_CODE + (8 - start) as u8;
+                out[23..31 - start].copy_from_slice(&bytes[start..]);
+                31 - start
+            }
+        };
+
+        // list header
+        // minus 1 to account for the list header itself
+        out[0] = EMPTY_LIST_CODE + len as u8 - 1;
 
         let hash = keccak256(&out[..len]);
         Self::from_word(hash)
@@ -440,6 +502,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_with() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let expected: Address = checksummed.parse().unwrap();
+        let lower = checksummed.to_lowercase();
+
+        let opts = ParseOptions::new().require_checksum(true);
+        assert_eq!(Address::parse_with(checksummed, opts).unwrap(), expected);
+        assert_eq!(
+            Address::parse_with(&checksummed[2..], opts).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            Address::parse_with(&lower, opts),
+            Err(AddressError::InvalidChecksum)
+        ));
+        assert!(matches!(
+            Address::parse_with(checksummed, opts.chain_id(Some(1))),
+            Err(AddressError::InvalidChecksum)
+        ));
+
+        let opts = opts.require_prefix(true);
+        assert!(matches!(
+            Address::parse_with(&checksummed[2..], opts),
+            Err(AddressError::MissingPrefix)
+        ));
+        assert!(matches!(
+            Address::parse_checksummed(&checksummed[2..], None),
+            Err(AddressError::MissingPrefix)
+        ));
+
+        let opts = ParseOptions::new().allow_short(true).allow_odd_length(true);
+        assert_eq!(
+            Address::parse_with("0xabc", opts).unwrap(),
+            Address::from(U160::from(0xabc))
+        );
+    }
+
     // https://eips.ethereum.org/EIPS/eip-55
     #[test]
     fn checksum() {
@@ -526,7 +626,6 @@ mod tests {
 
     // https://ethereum.stackexchange.com/questions/760/how-is-the-address-of-an-ethereum-contract-computed
     #[test]
-    #[cfg(feature = "rlp")]
     fn create() {
         let from = "0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"
             .parse::<Address>()
@@ -631,4 +730,34 @@ mod tests {
             assert_eq!(expected, from.create2_from_code(salt, init_code));
         }
     }
+
+    #[test]
+    fn formatting() {
+        let addr = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+            .parse::<Address>()
+            .unwrap();
+        assert_eq!(format!("{addr:#}"), "0xd8dA…6045");
+        assert_eq!(format!("[{addr:#14}]"), "[   0xd8dA…6045]");
+        assert_eq!(format!("[{addr:<#13}]"), "[0xd8dA…6045  ]");
+        assert_eq!(
+            format!("[{addr:>44}]"),
+            "[  0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045]"
+        );
+        assert_eq!(
+            format!("{addr:044}"),
+            "0x00d8dA6BF26964aF9D7eEd9e03E53415D37aA96045"
+        );
+        assert_eq!(
+            format!("{addr:x}"),
+            "d8da6bf26964af9d7eed9e03e53415d37aa96045"
+        );
+        assert_eq!(
+            format!("{addr:#X}"),
+            "0xD8DA6BF26964AF9D7EED9E03E53415D37AA96045"
+        );
+        assert_eq!(
+            format!("{:#b}", Address::with_last_byte(5)),
+            format!("0b{}101", "0".repeat(157))
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Adapted from <https://github.com/paritytech/parity-common/blob/2fb72eea96b6de4a085144ce239feb49da0cd39e/ethbloom/src/lib.rs>
 
-use crate::{keccak256, wrap_fixed_bytes, FixedBytes};
+use crate::{keccak256, wrap_fixed_bytes, Address, FixedBytes, Log, B256};
 use core::borrow::Borrow;
 
 /// Number of bits to set per input in Ethereum bloom filter.
@@ -115,6 +115,50 @@ impl Bloom {
         *self |= *other;
     }
 
+    /// Accrues the address and topics of a log into the bloom filter, as done
+    /// for the bloom of a transaction receipt.
+    pub fn accrue_raw_log(&mut self, address: Address, topics: &[B256]) {
+        self.m3_2048(address.as_slice());
+        for topic in topics {
+            self.m3_2048(topic.as_slice());
+        }
+    }
+
+    /// Accrues the address and topics of a log into the bloom filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{Address, Bloom, Log, LogData, B256};
+    ///
+    /// let log = Log::new(
+    ///     Address::repeat_byte(0x11),
+    ///     LogData::new(vec![B256::repeat_byte(0x22)], Default::default()),
+    /// );
+    /// let mut bloom = Bloom::default();
+    /// bloom.accrue_log(&log);
+    /// assert!(bloom.contains_log(&log));
+    /// assert!(bloom.contains_raw_log(log.address, &[]));
+    /// assert!(!bloom.contains_raw_log(Address::ZERO, &[]));
+    /// ```
+    pub fn accrue_log(&mut self, log: &Log) {
+        self.accrue_raw_log(log.address, log.topics())
+    }
+
+    /// Returns whether the bloom filter contains the address and all of the
+    /// topics of a log (allowing for false positives).
+    pub fn contains_raw_log(&self, address: Address, topics: &[B256]) -> bool {
+        let mut bloom = Self::default();
+        bloom.accrue_raw_log(address, topics);
+        self.contains(bloom)
+    }
+
+    /// Returns whether the bloom filter contains a log (allowing for false
+    /// positives).
+    pub fn contains_log(&self, log: &Log) -> bool {
+        self.contains_raw_log(log.address, log.topics())
+    }
+
     /// See Section 4.3.1 "Transaction Receipt" of the Ethereum Yellow Paper.
     pub fn m3_2048(&mut self, x: &[u8]) {
         let hash = keccak256(x);
@@ -176,5 +220,12 @@ mod tests {
         assert!(my_bloom.contains_input(BloomInput::Raw(&topic)));
 
         assert_eq!(my_bloom, bloom);
+
+        let mut log_bloom = Bloom::default();
+        log_bloom.accrue_raw_log(Address::new(address), &[B256::new(topic)]);
+        assert_eq!(log_bloom, bloom);
+        assert!(log_bloom.contains_raw_log(Address::new(address), &[]));
+        assert!(log_bloom.contains_raw_log(Address::new(address), &[B256::new(topic)]));
+        assert!(!log_bloom.contains_raw_log(Address::ZERO, &[B256::new(topic)]));
     }
 }
@@ -0,0 +1,50 @@
+use super::FixedBytes;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        <[u8; N]>::deserialize_reader(reader).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, Bytes, FixedBytes, B256};
+
+    #[test]
+    fn roundtrip() {
+        let address = Address::repeat_byte(0x11);
+        let encoded = borsh::to_vec(&address).unwrap();
+        // fixed-size arrays are not length-prefixed
+        assert_eq!(encoded, [0x11; 20]);
+        assert_eq!(borsh::from_slice::<Address>(&encoded).unwrap(), address);
+
+        let word = B256::repeat_byte(0x22);
+        let encoded = borsh::to_vec(&word).unwrap();
+        assert_eq!(borsh::from_slice::<B256>(&encoded).unwrap(), word);
+
+        let bloom = Bloom::repeat_byte(0x33);
+        let encoded = borsh::to_vec(&bloom).unwrap();
+        assert_eq!(borsh::from_slice::<Bloom>(&encoded).unwrap(), bloom);
+
+        let empty = FixedBytes::<0>::ZERO;
+        assert!(borsh::to_vec(&empty).unwrap().is_empty());
+
+        let bytes = Bytes::from_static(&[1, 2, 3]);
+        let encoded = borsh::to_vec(&bytes).unwrap();
+        // dynamic bytes are prefixed with a `u32` length
+        assert_eq!(encoded, [3, 0, 0, 0, 1, 2, 3]);
+        assert_eq!(borsh::from_slice::<Bytes>(&encoded).unwrap(), bytes);
+
+        assert!(borsh::from_slice::<Address>(&[0; 19]).is_err());
+        assert!(borsh::from_slice::<Address>(&[0; 21]).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use super::FixedBytes;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: `FixedBytes<N>` is `repr(transparent)` for `[u8; N]`, which has no
+// padding and for which all bit patterns, including all zeroes, are valid.
+unsafe impl<const N: usize> Zeroable for FixedBytes<N> {}
+unsafe impl<const N: usize> Pod for FixedBytes<N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, B256};
+
+    #[test]
+    fn cast_slice() {
+        let bytes = [[1u8; 20], [2u8; 20]].concat();
+        let addresses: &[Address] = bytemuck::cast_slice(&bytes);
+        assert_eq!(
+            addresses,
+            [Address::repeat_byte(1), Address::repeat_byte(2)]
+        );
+
+        let words = [B256::repeat_byte(3); 2];
+        let bytes: &[u8] = bytemuck::cast_slice(&words);
+        assert_eq!(bytes, [3; 64]);
+
+        assert_eq!(<Bloom as bytemuck::Zeroable>::zeroed(), Bloom::ZERO);
+    }
+}
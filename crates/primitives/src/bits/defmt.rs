@@ -0,0 +1,32 @@
+use super::FixedBytes;
+
+impl<const N: usize> defmt::Format for FixedBytes<N> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        // Logged as `0x` followed by two lowercase hex digits per byte, like
+        // `{:x}`. The bytes are written one at a time into the same frame:
+        // `{=[u8]:x}` would render them as an unpadded list, e.g. `[3, e]`.
+        defmt::write!(f, "0x");
+        for byte in &self.0 {
+            defmt::write!(f, "{=u8:02x}", *byte);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, Bytes, FixedBytes, Sign, B256, I256};
+
+    fn assert_format<T: defmt::Format>() {}
+
+    #[test]
+    fn format_impls() {
+        assert_format::<FixedBytes<0>>();
+        assert_format::<FixedBytes<4>>();
+        assert_format::<Address>();
+        assert_format::<Bloom>();
+        assert_format::<B256>();
+        assert_format::<Bytes>();
+        assert_format::<Sign>();
+        assert_format::<I256>();
+    }
+}
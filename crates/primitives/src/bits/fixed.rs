@@ -1,4 +1,4 @@
-use crate::aliases;
+use crate::{aliases, utils};
 use core::{fmt, ops, str};
 use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 
@@ -11,6 +11,18 @@ use derive_more::{Deref, DerefMut, From, Index, IndexMut, IntoIterator};
 /// Users looking to prevent type-confusion between byte arrays of different
 /// lengths should use the [`wrap_fixed_bytes!`](crate::wrap_fixed_bytes) macro
 /// to create a new fixed-length byte array type.
+///
+/// `FixedBytes<N>` is guaranteed to have the same layout as `[u8; N]`. With the
+/// `bytemuck` feature enabled, it implements [`Pod`] and [`Zeroable`], so it
+/// can be safely cast from unaligned byte slices, such as memory-mapped files.
+///
+/// Like the primitive integers, the [`Display`](fmt::Display),
+/// [`LowerHex`](fmt::LowerHex), [`UpperHex`](fmt::UpperHex) and
+/// [`Binary`](fmt::Binary) implementations honor the width, fill, alignment
+/// and `0` flag, and ignore the precision.
+///
+/// [`Pod`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html
+/// [`Zeroable`]: https://docs.rs/bytemuck/latest/bytemuck/trait.Zeroable.html
 #[derive(
     Clone,
     Copy,
@@ -203,15 +215,14 @@ impl<const N: usize> fmt::Display for FixedBytes<N> {
 
         // If the alternate flag is set, we use middle-out compression.
         const SEP_LEN: usize = '…'.len_utf8();
-        let mut buf = [0; 2 + 4 + SEP_LEN + 4];
-        buf[0] = b'0';
-        buf[1] = b'x';
-        hex::encode_to_slice(&self.0[0..2], &mut buf[2..6]).unwrap();
-        '…'.encode_utf8(&mut buf[6..]);
-        hex::encode_to_slice(&self.0[N - 2..N], &mut buf[6 + SEP_LEN..]).unwrap();
+        let mut buf = [0; 4 + SEP_LEN + 4];
+        hex::encode_to_slice(&self.0[0..2], &mut buf[..4]).unwrap();
+        '…'.encode_utf8(&mut buf[4..]);
+        hex::encode_to_slice(&self.0[N - 2..N], &mut buf[4 + SEP_LEN..]).unwrap();
 
         // SAFETY: always valid UTF-8
-        f.write_str(unsafe { str::from_utf8_unchecked(&buf) })
+        let s = unsafe { str::from_utf8_unchecked(&buf) };
+        utils::pad_integral(f, "0x", 4 + 1 + 4, |f| f.write_str(s))
     }
 }
 
@@ -229,6 +240,15 @@ impl<const N: usize> fmt::UpperHex for FixedBytes<N> {
     }
 }
 
+impl<const N: usize> fmt::Binary for FixedBytes<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "0b" } else { "" };
+        utils::pad_integral(f, prefix, N * 8, |f| {
+            self.0.iter().try_for_each(|byte| write!(f, "{byte:08b}"))
+        })
+    }
+}
+
 impl<const N: usize> ops::BitAnd for FixedBytes<N> {
     type Output = Self;
 
@@ -452,6 +472,160 @@ impl<const N: usize> FixedBytes<N> {
         ret
     }
 
+    /// Returns an adapter that [`Display`](fmt::Display)s these bytes as a
+    /// `0x`-prefixed hex string without allocating.
+    #[inline]
+    pub const fn display_hex(&self) -> crate::DisplayHex<'_> {
+        crate::DisplayHex::new(&self.0)
+    }
+
+    /// Writes these bytes as a `0x`-prefixed hex string to the given writer
+    /// without allocating.
+    #[inline]
+    pub fn write_hex<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        crate::utils::write_hex(w, &self.0)
+    }
+
+    /// Returns the number of leading zero bits.
+    #[inline]
+    pub const fn leading_zeros(&self) -> usize {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return i * 8 + self.0[i].leading_zeros() as usize
+            }
+            i += 1;
+        }
+        N * 8
+    }
+
+    /// Returns the number of trailing zero bits.
+    #[inline]
+    pub const fn trailing_zeros(&self) -> usize {
+        let mut i = 0;
+        while i < N {
+            let byte = self.0[N - 1 - i];
+            if byte != 0 {
+                return i * 8 + byte.trailing_zeros() as usize
+            }
+            i += 1;
+        }
+        N * 8
+    }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub const fn count_ones(&self) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < N {
+            count += self.0[i].count_ones() as usize;
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns the bit at the given index.
+    ///
+    /// Bits are indexed from the least significant bit of the big-endian
+    /// value, so bit `0` is the lowest bit of the last byte. This matches
+    /// Solidity's `(x >> index) & 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub const fn bit(&self, index: usize) -> bool {
+        assert!(index < N * 8, "bit index out of bounds");
+        (self.0[N - 1 - index / 8] >> (index % 8)) & 1 != 0
+    }
+
+    /// Sets the bit at the given index to `value`.
+    ///
+    /// See [`bit`](Self::bit) for how bits are indexed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < N * 8, "bit index out of bounds");
+        let byte = &mut self.0[N - 1 - index / 8];
+        let mask = 1 << (index % 8);
+        if value {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// Extracts the bits in `range`, right-aligned and zero-extended to `M`
+    /// bytes.
+    ///
+    /// See [`bit`](Self::bit) for how bits are indexed. This is equivalent to
+    /// Solidity's `(x >> range.start) & ((1 << range.len()) - 1)`, and can be
+    /// used to unpack values from a storage slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or does not fit in `M` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{b256, Address};
+    ///
+    /// // `(uint32 timestamp, int24 tick, address owner)` packed into one slot
+    /// let slot = b256!("000000000064e1b2c0000bb81111111111111111111111111111111111111111");
+    /// assert_eq!(Address::from(slot.bits(0..160)), Address::repeat_byte(0x11));
+    /// assert_eq!(u32::from_be_bytes(slot.bits(160..184).0), 3000);
+    /// assert_eq!(u32::from_be_bytes(slot.bits(184..216).0), 0x64e1b2c0);
+    /// ```
+    #[track_caller]
+    pub fn bits<const M: usize>(&self, range: ops::Range<usize>) -> FixedBytes<M> {
+        let ops::Range { start, end } = range;
+        assert!(start <= end && end <= N * 8, "bit range out of bounds");
+        let len = end - start;
+        assert!(len <= M * 8, "bit range does not fit in the output");
+
+        // byte `i` counting from the least significant end, or zero
+        let byte = |i: usize| if i < N { self.0[N - 1 - i] } else { 0 };
+        let (offset, shift) = (start / 8, start % 8);
+        let mut out = [0u8; M];
+        for i in 0..(len + 7) / 8 {
+            let mut value = byte(offset + i) >> shift;
+            if shift != 0 {
+                value |= byte(offset + i + 1) << (8 - shift);
+            }
+            let remaining = len - i * 8;
+            if remaining < 8 {
+                value &= (1 << remaining) - 1;
+            }
+            out[M - 1 - i] = value;
+        }
+        FixedBytes(out)
+    }
+
+    /// Sets the bits in `range` to the lowest bits of `value`.
+    ///
+    /// This is the inverse of [`bits`](Self::bits). Bits of `value` above
+    /// `range.len()` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or is larger than `M` bytes.
+    #[track_caller]
+    pub fn set_bits<const M: usize>(&mut self, range: ops::Range<usize>, value: FixedBytes<M>) {
+        let ops::Range { start, end } = range;
+        assert!(start <= end && end <= N * 8, "bit range out of bounds");
+        assert!(end - start <= M * 8, "bit range does not fit in the input");
+        for i in 0..end - start {
+            self.set_bit(start + i, value.bit(i));
+        }
+    }
+
     fn fmt_hex<const UPPER: bool>(&self, f: &mut fmt::Formatter<'_>, prefix: bool) -> fmt::Result {
         let mut buf = hex::Buffer::<N, true>::new();
         let s = if UPPER {
@@ -459,7 +633,9 @@ impl<const N: usize> FixedBytes<N> {
         } else {
             buf.format(self)
         };
-        f.write_str(&s[(!prefix as usize) * 2..])
+        let (prefix_str, digits) = s.split_at(2);
+        let prefix = if prefix { prefix_str } else { "" };
+        utils::pad_integral(f, prefix, digits.len(), |f| f.write_str(digits))
     }
 }
 
@@ -519,4 +695,106 @@ mod tests {
             "{:#X}", "0123456789abcdef" => "0x0123456789ABCDEF";
         }
     }
+
+    #[test]
+    fn binary() {
+        test_fmt! {
+            "{:b}", "05a0" => "0000010110100000";
+            "{:#b}", "05a0" => "0b0000010110100000";
+            "{:#020b}", "05a0" => "0b000000010110100000";
+        }
+    }
+
+    #[test]
+    fn padding() {
+        test_fmt! {
+            "[{:8}]", "0123" => "[  0x0123]";
+            "[{:<8}]", "0123" => "[0x0123  ]";
+            "[{:*^9}]", "0123" => "[*0x0123**]";
+            "[{:08}]", "0123" => "[0x000123]";
+            "[{:6x}]", "abcd" => "[  abcd]";
+            "[{:#08X}]", "abcd" => "[0x00ABCD]";
+            "[{:-<14}]", "0123456789" => "[0x0123456789--]";
+            "[{:>13}]", "0123456789" => "[ 0x0123456789]";
+            "[{:#>14}]", "0123456789" => "[##0x0123456789]";
+            "[{:#13}]", "0123456789" => "[  0x0123…6789]";
+            "[{:^#13}]", "0123456789" => "[ 0x0123…6789 ]";
+            // the precision is ignored
+            "[{:8.2}]", "0123" => "[  0x0123]";
+        }
+    }
+
+    #[test]
+    fn bit_counts() {
+        let b = fixed_bytes!("00f0000000000100");
+        assert_eq!(b.leading_zeros(), 8);
+        assert_eq!(b.trailing_zeros(), 8);
+        assert_eq!(b.count_ones(), 5);
+        assert_eq!(FixedBytes::<4>::ZERO.leading_zeros(), 32);
+        assert_eq!(FixedBytes::<4>::ZERO.trailing_zeros(), 32);
+        assert_eq!(FixedBytes::<0>::ZERO.leading_zeros(), 0);
+    }
+
+    #[test]
+    fn bit_access() {
+        let mut b = FixedBytes::<2>::ZERO;
+        b.set_bit(0, true);
+        b.set_bit(9, true);
+        assert_eq!(b, fixed_bytes!("0201"));
+        assert!(b.bit(0) && b.bit(9) && !b.bit(1) && !b.bit(15));
+        b.set_bit(0, false);
+        assert_eq!(b, fixed_bytes!("0200"));
+    }
+
+    #[test]
+    #[should_panic = "bit index out of bounds"]
+    fn bit_out_of_bounds() {
+        FixedBytes::<2>::ZERO.bit(16);
+    }
+
+    #[test]
+    fn bit_ranges() {
+        let b = fixed_bytes!("123456789abcdef0");
+        assert_eq!(b.bits::<8>(0..64), b);
+        assert_eq!(b.bits::<1>(0..8), fixed_bytes!("f0"));
+        assert_eq!(b.bits::<1>(4..8), fixed_bytes!("0f"));
+        assert_eq!(b.bits::<2>(4..16), fixed_bytes!("0def"));
+        assert_eq!(b.bits::<2>(60..64), fixed_bytes!("0001"));
+        assert_eq!(b.bits::<3>(3..27), fixed_bytes!("579bde"));
+        assert_eq!(b.bits::<0>(5..5), FixedBytes::ZERO);
+
+        for start in 0..64 {
+            for end in start..64.min(start + 32) {
+                let bits = b.bits::<4>(start..end);
+                let expected = (u64::from_be_bytes(b.0) >> start) & ((1 << (end - start)) - 1);
+                assert_eq!(
+                    u32::from_be_bytes(bits.0) as u64,
+                    expected,
+                    "{start}..{end}"
+                );
+
+                let mut c = FixedBytes::<8>::ZERO;
+                c.set_bits(start..end, bits);
+                assert_eq!(u64::from_be_bytes(c.0), expected << start, "{start}..{end}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic = "bit range does not fit in the output"]
+    fn bit_range_too_large() {
+        fixed_bytes!("1234").bits::<1>(0..9);
+    }
+
+    #[test]
+    fn display_hex() {
+        use alloc::string::{String, ToString};
+
+        let b = fixed_bytes!("0123456789abcdef");
+        assert_eq!(b.display_hex().to_string(), b.to_string());
+
+        let mut s = String::new();
+        b.write_hex(&mut s).unwrap();
+        assert_eq!(s, "0x0123456789abcdef");
+    }
 }
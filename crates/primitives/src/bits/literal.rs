@@ -0,0 +1,111 @@
+//! Compile-time decoding of hex string literals, used by the [`address!`],
+//! [`b256!`], [`bytes!`], etc. macros.
+//!
+//! This is [`hex_literal::hex!`], except that the first string may be prefixed
+//! with `0x`. Errors are reported as panics, which are compile errors when
+//! the functions are evaluated in a `const` item.
+
+/// Returns the number of bytes encoded in `strings`.
+pub const fn hex_len(strings: &[&[u8]]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < strings.len() {
+        let mut pos = if i == 0 { prefix_len(strings[0]) } else { 0 };
+        while let Some((_, next)) = next_byte(strings[i], pos) {
+            len += 1;
+            pos = next;
+        }
+        i += 1;
+    }
+    len
+}
+
+/// Decodes `strings` into an array of the length computed by [`hex_len`].
+pub const fn hex_decode<const N: usize>(strings: &[&[u8]]) -> [u8; N] {
+    let mut buf = [0u8; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < strings.len() {
+        let mut pos = if i == 0 { prefix_len(strings[0]) } else { 0 };
+        while let Some((byte, next)) = next_byte(strings[i], pos) {
+            buf[len] = byte;
+            len += 1;
+            pos = next;
+        }
+        i += 1;
+    }
+    assert!(len == N, "hex literal length mismatch");
+    buf
+}
+
+const fn prefix_len(s: &[u8]) -> usize {
+    if s.len() >= 2 && s[0] == b'0' && s[1] == b'x' {
+        2
+    } else {
+        0
+    }
+}
+
+const fn next_byte(s: &[u8], pos: usize) -> Option<(u8, usize)> {
+    let (hi, pos) = match next_nibble(s, pos) {
+        Some(x) => x,
+        None => return None,
+    };
+    match next_nibble(s, pos) {
+        Some((lo, pos)) => Some(((hi << 4) | lo, pos)),
+        None => panic!("odd number of hex characters"),
+    }
+}
+
+const fn next_nibble(s: &[u8], mut pos: usize) -> Option<(u8, usize)> {
+    while pos < s.len() {
+        let c = s[pos];
+        pos += 1;
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            b'A'..=b'F' => c - b'A' + 10,
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            _ => panic!("invalid hex character"),
+        };
+        return Some((nibble, pos))
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn decode<const N: usize>(strings: &[&[u8]]) -> [u8; N] {
+        assert!(hex_len(strings) == N);
+        hex_decode(strings)
+    }
+
+    #[test]
+    fn decode_literals() {
+        assert_eq!(decode::<0>(&[]), [0u8; 0]);
+        assert_eq!(decode::<0>(&[b"0x", b""]), [0u8; 0]);
+        assert_eq!(decode(&[b"0x0aFf"]), [0x0a, 0xff]);
+        assert_eq!(decode(&[b"0aFf"]), [0x0a, 0xff]);
+        assert_eq!(decode(&[b"0x01 02", b"\n03\t04"]), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic = "invalid hex character"]
+    fn prefix_only_first() {
+        hex_len(&[b"01", b"0x02"]);
+    }
+
+    #[test]
+    #[should_panic = "invalid hex character"]
+    fn invalid_char() {
+        hex_len(&[b"0g"]);
+    }
+
+    #[test]
+    #[should_panic = "odd number of hex characters"]
+    fn odd_length() {
+        hex_len(&[b"0x012"]);
+    }
+}
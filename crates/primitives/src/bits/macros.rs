@@ -4,6 +4,11 @@
 /// This functionally creates a new named FixedBytes that cannot be
 /// type-confused for another named FixedBytes.
 ///
+/// The newtype gets the same conversions, formatting, parsing and traits as
+/// [`Address`](crate::Address) and [`Bloom`](crate::Bloom), including the
+/// implementations gated behind this crate's `arbitrary`, `borsh`, `bytemuck`,
+/// `defmt`, `getrandom`, `rlp` and `serde` features.
+///
 /// # Example
 ///
 /// ```
@@ -13,6 +18,12 @@
 /// // are distinct types
 /// wrap_fixed_bytes!(pub struct KeccakOutput<32>;);
 /// wrap_fixed_bytes!(pub struct MerkleTreeItem<32>;);
+///
+/// let item: MerkleTreeItem = "0x0101010101010101010101010101010101010101010101010101010101010101"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(item, MerkleTreeItem::repeat_byte(1));
+/// assert_eq!(item.as_slice(), [1u8; 32]);
 /// ```
 #[macro_export]
 macro_rules! wrap_fixed_bytes {
@@ -60,6 +71,7 @@ macro_rules! wrap_fixed_bytes {
             $crate::private::derive_more::IntoIterator,
             $crate::private::derive_more::LowerHex,
             $crate::private::derive_more::UpperHex,
+            $crate::private::derive_more::Binary,
             $(
                 $extra_derives,
             )*
@@ -168,6 +180,9 @@ macro_rules! wrap_fixed_bytes {
 
         $crate::impl_fixed_bytes_traits!($name, $n);
         $crate::impl_getrandom!($name);
+        $crate::impl_borsh!($name);
+        $crate::impl_bytemuck!($name);
+        $crate::impl_defmt!($name);
         $crate::impl_rlp!($name, $n);
         $crate::impl_serde!($name);
         $crate::impl_arbitrary!($name, $n);
@@ -245,6 +260,23 @@ macro_rules! wrap_fixed_bytes {
             pub const fn bit_xor(self, rhs: Self) -> Self {
                 Self(self.0.bit_xor(rhs.0))
             }
+
+            /// Returns an adapter that displays these bytes as a `0x`-prefixed
+            /// hex string without allocating.
+            #[inline]
+            pub const fn display_hex(&self) -> $crate::DisplayHex<'_> {
+                self.0.display_hex()
+            }
+
+            /// Writes these bytes as a `0x`-prefixed hex string to the given
+            /// writer without allocating.
+            #[inline]
+            pub fn write_hex<W: $crate::private::core::fmt::Write + ?Sized>(
+                &self,
+                w: &mut W,
+            ) -> $crate::private::core::fmt::Result {
+                self.0.write_hex(w)
+            }
         }
     };
 }
@@ -386,6 +418,78 @@ macro_rules! impl_getrandom {
     ($t:ty) => {};
 }
 
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "borsh")]
+macro_rules! impl_borsh {
+    ($t:ty) => {
+        impl $crate::private::borsh::BorshSerialize for $t {
+            #[inline]
+            fn serialize<W: $crate::private::borsh::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> $crate::private::borsh::io::Result<()> {
+                $crate::private::borsh::BorshSerialize::serialize(&self.0, writer)
+            }
+        }
+
+        impl $crate::private::borsh::BorshDeserialize for $t {
+            #[inline]
+            fn deserialize_reader<R: $crate::private::borsh::io::Read>(
+                reader: &mut R,
+            ) -> $crate::private::borsh::io::Result<Self> {
+                $crate::private::borsh::BorshDeserialize::deserialize_reader(reader).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "borsh"))]
+macro_rules! impl_borsh {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "bytemuck")]
+macro_rules! impl_bytemuck {
+    ($t:ty) => {
+        // SAFETY: `$t` is `repr(transparent)` for `FixedBytes`, which is `Pod`.
+        unsafe impl $crate::private::bytemuck::Zeroable for $t {}
+        unsafe impl $crate::private::bytemuck::Pod for $t {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "bytemuck"))]
+macro_rules! impl_bytemuck {
+    ($t:ty) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "defmt")]
+macro_rules! impl_defmt {
+    ($t:ty) => {
+        impl $crate::private::defmt::Format for $t {
+            #[inline]
+            fn format(&self, f: $crate::private::defmt::Formatter<'_>) {
+                $crate::private::defmt::Format::format(&self.0, f)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "defmt"))]
+macro_rules! impl_defmt {
+    ($t:ty) => {};
+}
+
 #[doc(hidden)]
 #[macro_export]
 #[cfg(feature = "rlp")]
@@ -513,20 +617,47 @@ macro_rules! fixed_bytes_macros {
             "into a new [`", stringify!($ty), "`][crate::", stringify!($ty), "].\n",
         )]
         ///
-        /// Note that the strings cannot be prefixed with `0x`.
+        /// The first string may be prefixed with `0x`. The literal is decoded
+        /// at compile time, so invalid characters or a wrong number of bytes
+        /// are compile errors.
         ///
         /// See [`hex_literal::hex!`] for more information.
         $(#[$attr])*
         #[macro_export]
         macro_rules! $name {
-            ($d ($d s:literal)*) => {
-                $crate::$ty::new($crate::hex!($d ($d s)*))
-            };
+            ($d ($d s:literal)*) => {{
+                const STRINGS: &[&[u8]] = &[$d ($d s.as_bytes(),)*];
+                const LEN: usize = $crate::private::hex_len(STRINGS);
+                const BYTES: [u8; LEN] = $crate::private::hex_decode(STRINGS);
+                $crate::$ty::new(BYTES)
+            }};
         }
     )*};
 }
 
 fixed_bytes_macros! { $
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{address, Address};
+    ///
+    /// const WETH: Address = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+    /// assert_eq!(WETH, address!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+    /// ```
+    ///
+    /// A literal of the wrong length does not compile:
+    ///
+    /// ```compile_fail
+    /// # use alloy_primitives::address;
+    /// let _ = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756C");
+    /// ```
+    ///
+    /// Neither does one with invalid characters:
+    ///
+    /// ```compile_fail
+    /// # use alloy_primitives::address;
+    /// let _ = address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cgg");
+    /// ```
     macro address(Address);
 
     macro b64(B64);
@@ -545,19 +676,23 @@ fixed_bytes_macros! { $
 /// Converts a sequence of string literals containing hex-encoded data into a
 /// new [`Bytes`][crate::Bytes].
 ///
-/// Note that the strings cannot be prefixed with `0x`.
+/// The first string may be prefixed with `0x`. The literal is decoded at
+/// compile time, so invalid characters are compile errors.
 ///
 /// See [`hex_literal::hex!`] for more information.
 #[macro_export]
 macro_rules! bytes {
-    ($($s:literal)*) => {
-        $crate::Bytes::from_static(&$crate::hex!($($s)*))
-    };
+    ($($s:literal)*) => {{
+        const STRINGS: &[&[u8]] = &[$($s.as_bytes(),)*];
+        const LEN: usize = $crate::private::hex_len(STRINGS);
+        const BYTES: [u8; LEN] = $crate::private::hex_decode(STRINGS);
+        $crate::Bytes::from_static(&BYTES)
+    }};
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Address, Bytes};
+    use crate::{uint, Address, Bytes, FixedBytes, B256, U256};
     use hex_literal::hex;
 
     #[test]
@@ -569,5 +704,28 @@ mod tests {
 
         static B: Bytes = bytes!("112233");
         assert_eq!(B[..], [0x11, 0x22, 0x33]);
+
+        static EMPTY: Bytes = bytes!();
+        assert!(EMPTY.is_empty());
+    }
+
+    #[test]
+    fn prefixed() {
+        const A: Address = address!("0x0102030405060708090a0b0c0d0e0f1011121314");
+        assert_eq!(A, address!("0102030405060708090a0b0c0d0e0f1011121314"));
+        assert_eq!(
+            b256!("0x" "01020304050607080910111213141516" "17181920212223242526272829303132"),
+            B256::new(hex!(
+                "0102030405060708091011121314151617181920212223242526272829303132"
+            ))
+        );
+        assert_eq!(fixed_bytes!("0x0a0b"), FixedBytes([0x0a, 0x0b]));
+        assert_eq!(bytes!("0x"), Bytes::new());
+        assert_eq!(bytes!("0x1122" "33")[..], [0x11, 0x22, 0x33]);
+
+        // decimal and hex integer literals
+        const ONE_ETHER: U256 = uint!(1_000_000_000_000_000_000_U256);
+        assert_eq!(ONE_ETHER, uint!(0x0de0b6b3a7640000_U256));
+        assert_eq!(ONE_ETHER, U256::from(10u64).pow(U256::from(18u64)));
     }
 }
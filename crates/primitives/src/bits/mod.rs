@@ -10,6 +10,21 @@ pub use bloom::{Bloom, BloomInput, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_S
 mod fixed;
 pub use fixed::FixedBytes;
 
+mod literal;
+pub use literal::{hex_decode, hex_len};
+
+mod parse;
+pub use parse::{ParseFixedBytesError, ParseOptions};
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
@@ -0,0 +1,243 @@
+use super::FixedBytes;
+use core::fmt;
+use hex::FromHexError;
+
+/// Error returned by [`FixedBytes::parse_with`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFixedBytesError {
+    /// The `0x` prefix is required but missing.
+    MissingPrefix,
+
+    /// Error while decoding hex.
+    Hex(FromHexError),
+}
+
+impl From<FromHexError> for ParseFixedBytesError {
+    fn from(value: FromHexError) -> Self {
+        Self::Hex(value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFixedBytesError {}
+
+impl fmt::Display for ParseFixedBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("missing `0x` prefix"),
+            Self::Hex(err) => err.fmt(f),
+        }
+    }
+}
+
+/// Options for parsing hex strings with [`FixedBytes::parse_with`] and
+/// [`Address::parse_with`](crate::Address::parse_with).
+///
+/// The default options are the same as [`FromStr`](core::str::FromStr): the
+/// `0x` prefix is optional, and the string must contain exactly two hex digits
+/// per byte.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{ParseOptions, B256};
+///
+/// let opts = ParseOptions::new().allow_short(true).allow_odd_length(true);
+/// assert_eq!(B256::parse_with("0x1", opts), Ok(B256::with_last_byte(1)));
+///
+/// let opts = ParseOptions::new().require_prefix(true);
+/// assert!(B256::parse_with("01", opts).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub(crate) require_prefix: bool,
+    pub(crate) require_checksum: bool,
+    pub(crate) chain_id: Option<u64>,
+    pub(crate) allow_odd_length: bool,
+    pub(crate) allow_short: bool,
+}
+
+impl ParseOptions {
+    /// Creates the default options.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            require_prefix: false,
+            require_checksum: false,
+            chain_id: None,
+            allow_odd_length: false,
+            allow_short: false,
+        }
+    }
+
+    /// Sets whether the string must start with `0x`.
+    #[inline]
+    pub const fn require_prefix(mut self, yes: bool) -> Self {
+        self.require_prefix = yes;
+        self
+    }
+
+    /// Sets whether an address must match its [EIP-55] checksum.
+    ///
+    /// This is ignored when parsing types other than
+    /// [`Address`](crate::Address).
+    ///
+    /// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+    #[inline]
+    pub const fn require_checksum(mut self, yes: bool) -> Self {
+        self.require_checksum = yes;
+        self
+    }
+
+    /// Sets the [EIP-155 chain ID] used to verify the checksum with
+    /// [EIP-1191].
+    ///
+    /// [EIP-155 chain ID]: https://eips.ethereum.org/EIPS/eip-155
+    /// [EIP-1191]: https://eips.ethereum.org/EIPS/eip-1191
+    #[inline]
+    pub const fn chain_id(mut self, chain_id: Option<u64>) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Sets whether an odd number of hex digits is allowed, in which case the
+    /// first digit is the low nibble of the first byte.
+    #[inline]
+    pub const fn allow_odd_length(mut self, yes: bool) -> Self {
+        self.allow_odd_length = yes;
+        self
+    }
+
+    /// Sets whether fewer digits than the length of the type are allowed, in
+    /// which case the value is left-padded with zeros.
+    #[inline]
+    pub const fn allow_short(mut self, yes: bool) -> Self {
+        self.allow_short = yes;
+        self
+    }
+}
+
+impl<const N: usize> FixedBytes<N> {
+    /// Parses a hex string with the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseFixedBytesError::MissingPrefix`] if the prefix is
+    /// required but missing, and [`FromHexError::InvalidStringLength`] if the
+    /// string has the wrong number of digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{FixedBytes, ParseOptions};
+    ///
+    /// let opts = ParseOptions::new().allow_short(true);
+    /// assert_eq!(FixedBytes::<4>::parse_with("0x0102", opts), Ok(FixedBytes([0, 0, 1, 2])));
+    /// ```
+    pub fn parse_with(s: &str, opts: ParseOptions) -> Result<Self, ParseFixedBytesError> {
+        let hex = match s.strip_prefix("0x") {
+            Some(hex) => hex,
+            None if opts.require_prefix => return Err(ParseFixedBytesError::MissingPrefix),
+            None => s,
+        };
+
+        let odd = hex.len() % 2 != 0;
+        if odd && !opts.allow_odd_length {
+            return Err(FromHexError::OddLength.into())
+        }
+        let len = (hex.len() + 1) / 2;
+        if len > N || (len < N && !opts.allow_short) {
+            return Err(FromHexError::InvalidStringLength.into())
+        }
+
+        let mut bytes = [0u8; N];
+        let out = &mut bytes[N - len..];
+        let (hex, out) = if odd {
+            let (first, rest) = hex.as_bytes().split_at(1);
+            hex::decode_to_slice([b'0', first[0]], &mut out[..1]).map_err(|_| {
+                FromHexError::InvalidHexCharacter {
+                    c: first[0] as char,
+                    index: 0,
+                }
+            })?;
+            (rest, &mut out[1..])
+        } else {
+            (hex.as_bytes(), out)
+        };
+        hex::decode_to_slice(hex, out).map_err(|e| match e {
+            FromHexError::InvalidHexCharacter { c, index } => FromHexError::InvalidHexCharacter {
+                c,
+                index: index + odd as usize,
+            },
+            e => e,
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::B256;
+
+    #[test]
+    fn defaults_match_from_str() {
+        let s = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        let opts = ParseOptions::new();
+        assert_eq!(B256::parse_with(s, opts), s.parse().map_err(Into::into));
+        assert_eq!(
+            B256::parse_with(&s[2..], opts),
+            s[2..].parse().map_err(Into::into)
+        );
+        assert_eq!(
+            B256::parse_with("0x01", opts),
+            "0x01".parse::<B256>().map_err(Into::into)
+        );
+        assert_eq!(
+            B256::parse_with("0x001", opts),
+            Err(FromHexError::OddLength.into())
+        );
+    }
+
+    #[test]
+    fn options() {
+        let one = B256::with_last_byte(1);
+
+        let opts = ParseOptions::new().require_prefix(true);
+        assert_eq!(
+            B256::parse_with(&"01".repeat(32), opts),
+            Err(ParseFixedBytesError::MissingPrefix)
+        );
+
+        let opts = ParseOptions::new().allow_short(true);
+        assert_eq!(B256::parse_with("0x01", opts), Ok(one));
+        assert_eq!(B256::parse_with("", opts), Ok(B256::ZERO));
+        assert_eq!(
+            B256::parse_with("0x1", opts),
+            Err(FromHexError::OddLength.into())
+        );
+        assert_eq!(
+            B256::parse_with(&"00".repeat(33), opts),
+            Err(FromHexError::InvalidStringLength.into())
+        );
+
+        let opts = ParseOptions::new().allow_odd_length(true);
+        let odd = "1".repeat(63);
+        assert_eq!(B256::parse_with(&odd, opts).unwrap()[..2], [0x01, 0x11]);
+        assert_eq!(
+            B256::parse_with("0x1", opts),
+            Err(FromHexError::InvalidStringLength.into())
+        );
+
+        let opts = opts.allow_short(true);
+        assert_eq!(B256::parse_with("0x1", opts), Ok(one));
+        assert_eq!(
+            B256::parse_with("0x1a2g", opts),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 3 }.into())
+        );
+        assert_eq!(
+            B256::parse_with("0xg12", opts),
+            Err(FromHexError::InvalidHexCharacter { c: 'g', index: 0 }.into())
+        );
+    }
+}
@@ -33,3 +33,60 @@ fixed_bytes_max_encoded_len!(0, 1, 2, 4, 8, 16, 20, 32, 64, 128, 256, 512, 1024)
 unsafe impl<const N: usize> MaxEncodedLenAssoc for FixedBytes<N> {
     const LEN: usize = N + length_of_length(N);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Address, Bloom, Bytes, FixedBytes, U256, U64};
+    use alloy_rlp::{decode_exact, encode};
+    use hex_literal::hex;
+
+    #[test]
+    fn fixed_bytes() {
+        let address = Address::repeat_byte(0x11);
+        let encoded = encode(address);
+        assert_eq!(encoded, [&[0x80 + 20][..], &[0x11; 20]].concat());
+        assert_eq!(decode_exact::<Address>(&encoded).unwrap(), address);
+
+        // single bytes below 0x80 are their own encoding
+        assert_eq!(encode(FixedBytes([0x7f])), [0x7f]);
+        assert_eq!(encode(FixedBytes([0x80])), [0x81, 0x80]);
+        assert_eq!(encode(FixedBytes::<0>::ZERO), [0x80]);
+
+        let bloom = Bloom::repeat_byte(0x22);
+        let encoded = encode(bloom);
+        assert_eq!(encoded[..3], [0xb9, 0x01, 0x00]);
+        assert_eq!(decode_exact::<Bloom>(&encoded).unwrap(), bloom);
+
+        // wrong length
+        assert!(decode_exact::<Address>(&encode(FixedBytes([0u8; 19]))).is_err());
+        assert!(decode_exact::<Address>(&encode(FixedBytes([0u8; 21]))).is_err());
+    }
+
+    #[test]
+    fn bytes() {
+        let bytes = Bytes::from_static(&hex!("0102"));
+        assert_eq!(encode(&bytes), hex!("820102"));
+        assert_eq!(decode_exact::<Bytes>(hex!("820102")).unwrap(), bytes);
+        assert_eq!(encode(Bytes::new()), [0x80]);
+    }
+
+    #[test]
+    fn uint() {
+        // integers are big-endian with the leading zeros trimmed
+        for (value, expected) in [
+            (U256::ZERO, &hex!("80")[..]),
+            (U256::from(1), &hex!("01")),
+            (U256::from(0x7f), &hex!("7f")),
+            (U256::from(0x80), &hex!("8180")),
+            (U256::from(0x0400), &hex!("820400")),
+            (U256::MAX, &[&[0xa0][..], &[0xff; 32]].concat()),
+        ] {
+            assert_eq!(encode(value), expected, "{value}");
+            assert_eq!(decode_exact::<U256>(expected).unwrap(), value);
+        }
+
+        assert_eq!(encode(U64::from(0x0400)), hex!("820400"));
+        // too large for the type
+        assert!(decode_exact::<U64>(encode(U256::MAX)).is_err());
+    }
+}
@@ -0,0 +1,17 @@
+use super::Bytes;
+use alloc::vec::Vec;
+use borsh::{io, BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Bytes {
+    #[inline]
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self[..].serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Bytes {
+    #[inline]
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        Vec::<u8>::deserialize_reader(reader).map(Into::into)
+    }
+}
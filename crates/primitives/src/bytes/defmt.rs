@@ -0,0 +1,13 @@
+use super::Bytes;
+
+impl defmt::Format for Bytes {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        // Logged as `0x` followed by two lowercase hex digits per byte, like
+        // `{:x}`. The bytes are written one at a time into the same frame:
+        // `{=[u8]:x}` would render them as an unpadded list, e.g. `[3, e]`.
+        defmt::write!(f, "0x");
+        for byte in self.iter() {
+            defmt::write!(f, "{=u8:02x}", *byte);
+        }
+    }
+}
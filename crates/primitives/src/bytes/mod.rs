@@ -1,10 +1,17 @@
-use alloc::{string::String, vec::Vec};
+use crate::utils;
+use alloc::vec::Vec;
 use core::{
     borrow::Borrow,
     fmt,
     ops::{Deref, DerefMut, RangeBounds},
 };
 
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
 #[cfg(feature = "rlp")]
 mod rlp;
 
@@ -13,6 +20,11 @@ mod serde;
 
 /// Wrapper type around Bytes to deserialize/serialize "0x" prefixed ethereum
 /// hex strings.
+///
+/// Like the primitive integers, the formatting traits honor the width, fill,
+/// alignment and `0` flag, and ignore the precision.
+/// [`LowerHex`](fmt::LowerHex) and [`UpperHex`](fmt::UpperHex) only write the
+/// `0x` prefix with the `#` flag.
 #[derive(Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct Bytes(pub bytes::Bytes);
@@ -20,20 +32,46 @@ pub struct Bytes(pub bytes::Bytes);
 impl fmt::Debug for Bytes {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("Bytes(")?;
-        f.write_str(&self.hex_encode())?;
+        self.write_hex(f)?;
         f.write_str(")")
     }
 }
 
 impl fmt::Display for Bytes {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.hex_encode())
+        utils::pad_integral(f, "0x", self.len() * 2, |f| {
+            utils::write_hex_digits::<false, _>(f, &self.0)
+        })
     }
 }
 
 impl fmt::LowerHex for Bytes {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "0x" } else { "" };
+        utils::pad_integral(f, prefix, self.len() * 2, |f| {
+            utils::write_hex_digits::<false, _>(f, &self.0)
+        })
+    }
+}
+
+impl fmt::UpperHex for Bytes {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.hex_encode())
+        let prefix = if f.alternate() { "0x" } else { "" };
+        utils::pad_integral(f, prefix, self.len() * 2, |f| {
+            utils::write_hex_digits::<true, _>(f, &self.0)
+        })
+    }
+}
+
+impl fmt::Binary for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if f.alternate() { "0b" } else { "" };
+        utils::pad_integral(f, prefix, self.len() * 8, |f| {
+            self.iter().try_for_each(|byte| write!(f, "{byte:08b}"))
+        })
     }
 }
 
@@ -264,9 +302,18 @@ impl Bytes {
         Self(self.0.split_to(at))
     }
 
+    /// Returns an adapter that [`Display`](fmt::Display)s these bytes as a
+    /// `0x`-prefixed hex string without allocating.
     #[inline]
-    fn hex_encode(&self) -> String {
-        hex::encode_prefixed(self.0.as_ref())
+    pub fn display_hex(&self) -> crate::DisplayHex<'_> {
+        crate::DisplayHex::new(&self.0)
+    }
+
+    /// Writes these bytes as a `0x`-prefixed hex string to the given writer
+    /// without allocating.
+    #[inline]
+    pub fn write_hex<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        crate::utils::write_hex(w, &self.0)
     }
 }
 
@@ -328,8 +375,26 @@ mod tests {
     fn hex() {
         let b = Bytes::from_static(&[1, 35, 69, 103, 137, 171, 205, 239]);
         let expected = "0x0123456789abcdef";
-        assert_eq!(format!("{b:x}"), expected);
+        assert_eq!(format!("{b:#x}"), expected);
         assert_eq!(format!("{b}"), expected);
+        assert_eq!(format!("{b:x}"), "0123456789abcdef");
+        assert_eq!(format!("{b:X}"), "0123456789ABCDEF");
+        assert_eq!(format!("{b:#X}"), "0x0123456789ABCDEF");
+    }
+
+    #[test]
+    fn padding() {
+        let b = Bytes::from_static(&[0xab, 0x05]);
+        assert_eq!(format!("[{b:8}]"), "[  0xab05]");
+        assert_eq!(format!("[{b:<8}]"), "[0xab05  ]");
+        assert_eq!(format!("[{b:*^9}]"), "[*0xab05**]");
+        assert_eq!(format!("[{b:08}]"), "[0x00ab05]");
+        assert_eq!(format!("[{b:#08x}] [{b:6X}]"), "[0x00ab05] [  AB05]");
+        assert_eq!(format!("{b:b}"), "1010101100000101");
+        assert_eq!(format!("{b:#020b}"), "0b001010101100000101");
+        assert_eq!(format!("[{:4}]", Bytes::new()), "[  0x]");
+        // the precision is ignored
+        assert_eq!(format!("[{b:8.1}] [{b:.1x}]"), "[  0xab05] [ab05]");
     }
 
     #[test]
@@ -23,6 +23,10 @@ use serde as _;
 #[cfg(test)]
 use serde_json as _;
 
+// Used in benchmarks.
+#[cfg(test)]
+use criterion as _;
+
 pub mod aliases;
 #[doc(no_inline)]
 pub use aliases::{
@@ -33,21 +37,27 @@ pub use aliases::{
 
 mod bits;
 pub use bits::{
-    Address, AddressError, Bloom, BloomInput, FixedBytes, BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS,
-    BLOOM_SIZE_BYTES,
+    Address, AddressError, Bloom, BloomInput, FixedBytes, ParseFixedBytesError, ParseOptions,
+    BLOOM_BITS_PER_ITEM, BLOOM_SIZE_BITS, BLOOM_SIZE_BYTES,
 };
 
 mod bytes;
 pub use self::bytes::Bytes;
 
+mod log;
+pub use log::{Log, LogData};
+
+#[cfg(feature = "map")]
+pub mod map;
+
 #[cfg(feature = "getrandom")]
 mod impl_core;
 
 mod signed;
 pub use signed::{BigIntConversionError, ParseSignedError, Sign, Signed};
 
-mod utils;
-pub use utils::keccak256;
+pub mod utils;
+pub use utils::{keccak256, DisplayHex};
 
 #[doc(no_inline)]
 pub use ::hex;
@@ -62,6 +72,9 @@ pub use tiny_keccak::{self, Hasher, Keccak};
 #[doc(no_inline)]
 pub use ::hex::serde as serde_hex;
 
+#[cfg(feature = "serde")]
+pub mod serde_quantity;
+
 // Not public API.
 #[doc(hidden)]
 pub mod private {
@@ -73,6 +86,17 @@ pub mod private {
     };
     pub use derive_more;
 
+    pub use crate::bits::{hex_decode, hex_len};
+
+    #[cfg(feature = "borsh")]
+    pub use borsh;
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
     #[cfg(feature = "getrandom")]
     pub use getrandom;
 
@@ -0,0 +1,87 @@
+use crate::{Address, Bytes, B256};
+use alloc::vec::Vec;
+
+/// The topics and data of an Ethereum event log.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct LogData {
+    /// The indexed topic list. For non-anonymous Solidity events, the first
+    /// topic is the event's signature hash.
+    pub topics: Vec<B256>,
+    /// The plain data.
+    pub data: Bytes,
+}
+
+impl LogData {
+    /// The maximum number of topics in a log, which is emitted with `LOG4`.
+    pub const MAX_TOPICS: usize = 4;
+
+    /// Creates a new log data from its topics and data.
+    #[inline]
+    pub const fn new(topics: Vec<B256>, data: Bytes) -> Self {
+        Self { topics, data }
+    }
+
+    /// Returns `true` if the log has at most [`MAX_TOPICS`](Self::MAX_TOPICS)
+    /// topics, which is the case for every log emitted by the EVM.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.topics.len() <= Self::MAX_TOPICS
+    }
+
+    /// Returns the topics of the log.
+    #[inline]
+    pub fn topics(&self) -> &[B256] {
+        &self.topics
+    }
+}
+
+/// An Ethereum event log: the address of the contract which emitted it, and
+/// its [`LogData`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{Address, Bytes, Log, LogData, B256};
+///
+/// let log = Log::new(
+///     Address::ZERO,
+///     LogData::new(vec![B256::ZERO], Bytes::from_static(&[1, 2, 3])),
+/// );
+/// assert_eq!(log.topics(), [B256::ZERO]);
+/// assert!(log.data.is_valid());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Log {
+    /// The address which emitted this log.
+    pub address: Address,
+    /// The topics and data of the log.
+    pub data: LogData,
+}
+
+impl Log {
+    /// Creates a new log.
+    #[inline]
+    pub const fn new(address: Address, data: LogData) -> Self {
+        Self { address, data }
+    }
+
+    /// Returns the topics of the log.
+    #[inline]
+    pub fn topics(&self) -> &[B256] {
+        self.data.topics()
+    }
+}
+
+impl AsRef<LogData> for Log {
+    #[inline]
+    fn as_ref(&self) -> &LogData {
+        &self.data
+    }
+}
+
+impl From<Log> for LogData {
+    #[inline]
+    fn from(log: Log) -> Self {
+        log.data
+    }
+}
@@ -0,0 +1,181 @@
+//! Hash maps and sets keyed by fixed-size byte arrays, such as [`Address`]
+//! and [`B256`].
+//!
+//! These use [`FbHasher`] instead of the default SipHash hasher of the
+//! standard library. Since the keys are usually the output of a cryptographic
+//! hash function, they are already uniformly distributed, and hashing them
+//! again is wasted work.
+//!
+//! Note that this trades denial-of-service resistance for speed: the hasher is
+//! not keyed, so an attacker who can choose arbitrary keys can also choose
+//! colliding ones. Use the standard library types for untrusted keys that are
+//! not hash outputs.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{address, map::AddressMap};
+//!
+//! let mut balances = AddressMap::default();
+//! balances.insert(address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"), 1u64);
+//! assert_eq!(balances.len(), 1);
+//! ```
+
+use crate::{Address, FixedBytes, B256};
+use core::hash::{BuildHasherDefault, Hasher};
+use std::collections::{HashMap, HashSet};
+
+/// A [`Hasher`] specialized for fixed-size byte arrays.
+///
+/// Instead of running a general purpose hash function over the whole key,
+/// each 8-byte word of the key is mixed into the state with a single
+/// multiplication, and the state is finalized with a widening multiplication.
+///
+/// This hasher only supports keys that are hashed as a single byte slice,
+/// like [`FixedBytes`] and its wrappers: length prefixes are ignored, and
+/// integers are hashed like their native-endian bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FbHasher {
+    hash: u64,
+}
+
+impl FbHasher {
+    /// Mixes a word into the state. The rotation and multiplication make the
+    /// result depend on the position of the word, so that keys with swapped
+    /// or repeated words do not collide.
+    #[inline]
+    fn add_word(&mut self, word: u64) {
+        self.hash = (self.hash ^ word)
+            .rotate_left(23)
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    }
+}
+
+impl Hasher for FbHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        // folded multiply: the high half of the product depends on every bit
+        // of the input, and the low half on its low bits
+        let full = self.hash as u128 * 0x9e37_79b9_7f4a_7c15u128;
+        (full as u64) ^ (full >> 64) as u64
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_word(u64::from_ne_bytes(chunk.try_into().unwrap()));
+        }
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut word = [0u8; 8];
+            word[..rem.len()].copy_from_slice(rem);
+            self.add_word(u64::from_ne_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_usize(&mut self, _len: usize) {
+        // slices are prefixed with their length, which is the same for all
+        // keys of a fixed-size type
+    }
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`FbHasher`].
+pub type FbBuildHasher = BuildHasherDefault<FbHasher>;
+
+/// A [`HashMap`] keyed by [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbMap<const N: usize, V> = HashMap<FixedBytes<N>, V, FbBuildHasher>;
+
+/// A [`HashSet`] of [`FixedBytes<N>`], using [`FbHasher`].
+pub type FbSet<const N: usize> = HashSet<FixedBytes<N>, FbBuildHasher>;
+
+/// A [`HashMap`] keyed by [`Address`], using [`FbHasher`].
+pub type AddressMap<V> = HashMap<Address, V, FbBuildHasher>;
+
+/// A [`HashSet`] of [`Address`]es, using [`FbHasher`].
+pub type AddressSet = HashSet<Address, FbBuildHasher>;
+
+/// A [`HashMap`] keyed by [`B256`], using [`FbHasher`].
+pub type B256Map<V> = HashMap<B256, V, FbBuildHasher>;
+
+/// A [`HashSet`] of [`B256`]s, using [`FbHasher`].
+pub type B256Set = HashSet<B256, FbBuildHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hex, keccak256};
+    use core::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        let mut hasher = FbBuildHasher::default().build_hasher();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn maps() {
+        let a = Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+        let mut addresses = AddressMap::default();
+        addresses.insert(a, 1);
+        addresses.insert(Address::ZERO, 2);
+        assert_eq!(addresses[&a], 1);
+        assert_eq!(addresses[&Address::ZERO], 2);
+        assert_eq!(addresses.get(&Address::repeat_byte(1)), None);
+
+        let h = keccak256("");
+        let set: B256Set = [h, h, B256::ZERO].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&h));
+
+        let selectors: FbMap<4, &str> = [(FixedBytes([0xa9, 0x05, 0x9c, 0xbb]), "transfer")]
+            .into_iter()
+            .collect();
+        assert_eq!(selectors[&FixedBytes([0xa9, 0x05, 0x9c, 0xbb])], "transfer");
+    }
+
+    #[test]
+    fn hasher() {
+        // every byte of the key changes the hash
+        let base = hash(Address::ZERO);
+        for i in 0..20 {
+            let mut a = Address::ZERO;
+            a[i] = 1;
+            assert_ne!(hash(a), base, "{i}");
+        }
+
+        // the position of each word matters
+        let mut a = Address::ZERO;
+        a[0] = 1;
+        a[8] = 1;
+        assert_ne!(hash(a), base);
+        let mut swapped = B256::ZERO;
+        swapped[..8].copy_from_slice(&1u64.to_ne_bytes());
+        swapped[8..16].copy_from_slice(&2u64.to_ne_bytes());
+        let mut b = B256::ZERO;
+        b[..8].copy_from_slice(&2u64.to_ne_bytes());
+        b[8..16].copy_from_slice(&1u64.to_ne_bytes());
+        assert_ne!(hash(swapped), hash(b));
+
+        // sequential keys, e.g. vanity addresses, spread over the low bits,
+        // which select the bucket, and the high bits, which are used as tags
+        let mut low = [0usize; 16];
+        let mut high = [0usize; 16];
+        for i in 0..1600u64 {
+            let mut a = Address::ZERO;
+            a[12..].copy_from_slice(&i.to_be_bytes());
+            low[(hash(a) % 16) as usize] += 1;
+            high[(hash(a) >> 60) as usize] += 1;
+        }
+        assert!(low.iter().all(|&n| n > 50), "{low:?}");
+        assert!(high.iter().all(|&n| n > 50), "{high:?}");
+
+        // as do hash outputs
+        let mut buckets = [0usize; 16];
+        for i in 0..1600u64 {
+            buckets[(hash(keccak256(i.to_be_bytes())) % 16) as usize] += 1;
+        }
+        assert!(buckets.iter().all(|&n| n > 50), "{buckets:?}");
+    }
+}
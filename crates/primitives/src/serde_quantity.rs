@@ -0,0 +1,244 @@
+//! Serde functions for Ethereum JSON-RPC quantities.
+//!
+//! In human-readable formats, quantities are serialized as minimal
+//! `0x`-prefixed lowercase hex strings (`0x0`, `0x400`, ...), as specified by
+//! the [Ethereum JSON-RPC API][spec]. When deserializing, hex strings, decimal
+//! strings and plain numbers are all accepted.
+//!
+//! Other formats use the [`Serialize`] and [`Deserialize`] implementations of
+//! the type itself, which keeps [`Uint`]s as fixed-width bytes.
+//!
+//! Supported types are all unsigned primitive integers and [`Uint`]s.
+//!
+//! [spec]: https://ethereum.org/en/developers/docs/apis/json-rpc/#quantities-encoding
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::U256;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Block {
+//!     #[serde(with = "alloy_primitives::serde_quantity")]
+//!     number: u64,
+//!     #[serde(with = "alloy_primitives::serde_quantity")]
+//!     difficulty: U256,
+//! }
+//!
+//! let block = Block {
+//!     number: 1024,
+//!     difficulty: U256::ZERO,
+//! };
+//! let json = serde_json::to_string(&block)?;
+//! assert_eq!(json, r#"{"number":"0x400","difficulty":"0x0"}"#);
+//! assert_eq!(serde_json::from_str::<Block>(&json)?, block);
+//!
+//! // decimal strings and numbers are accepted too
+//! let json = r#"{"number":1024,"difficulty":"0"}"#;
+//! assert_eq!(serde_json::from_str::<Block>(json)?, block);
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use ruint::Uint;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// An unsigned integer that can be (de)serialized as a quantity.
+///
+/// This trait is sealed and implemented for all unsigned primitive integers
+/// and [`Uint`]s.
+pub trait Quantity: Sized + Serialize + for<'de> Deserialize<'de> + private::Sealed {}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {
+        /// Returns the minimal `0x`-prefixed hex representation.
+        fn to_hex(&self) -> String;
+
+        /// Parses a string of digits in the given radix. The digits have
+        /// already been checked to be valid.
+        fn from_digits(digits: &str, radix: u32) -> Option<Self>
+        where
+            Self: Sized;
+
+        fn from_u128(n: u128) -> Option<Self>
+        where
+            Self: Sized;
+    }
+}
+
+macro_rules! impl_quantity {
+    ($($t:ty),+) => {$(
+        impl Quantity for $t {}
+
+        impl private::Sealed for $t {
+            fn to_hex(&self) -> String {
+                format!("{self:#x}")
+            }
+
+            fn from_digits(digits: &str, radix: u32) -> Option<Self> {
+                <$t>::from_str_radix(digits, radix).ok()
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                n.try_into().ok()
+            }
+        }
+    )+};
+}
+
+impl_quantity!(u8, u16, u32, u64, u128, usize);
+
+impl<const BITS: usize, const LIMBS: usize> Quantity for Uint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> private::Sealed for Uint<BITS, LIMBS> {
+    fn to_hex(&self) -> String {
+        // `LowerHex` pads to the full width
+        let hex = format!("{self:x}");
+        match hex.trim_start_matches('0') {
+            "" => String::from("0x0"),
+            digits => format!("0x{digits}"),
+        }
+    }
+
+    fn from_digits(digits: &str, radix: u32) -> Option<Self> {
+        Self::from_str_radix(digits, radix as u64).ok()
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+}
+
+/// Serializes a quantity. See the [module-level documentation](self).
+pub fn serialize<T: Quantity, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&value.to_hex())
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+/// Deserializes a quantity. See the [module-level documentation](self).
+pub fn deserialize<'de, T: Quantity, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(QuantityVisitor(core::marker::PhantomData))
+    } else {
+        T::deserialize(deserializer)
+    }
+}
+
+struct QuantityVisitor<T>(core::marker::PhantomData<T>);
+
+impl<T: Quantity> Visitor<'_> for QuantityVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex or decimal quantity")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        self.visit_u128(v as u128)
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        T::from_u128(v).ok_or_else(|| de::Error::custom(format_args!("quantity {v} is too large")))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let (digits, radix) = match v.strip_prefix("0x").or_else(|| v.strip_prefix("0X")) {
+            Some(digits) => (digits, 16),
+            None => (v, 10),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(de::Error::invalid_value(de::Unexpected::Str(v), &self))
+        }
+        T::from_digits(digits, radix)
+            .ok_or_else(|| de::Error::custom(format_args!("quantity {v} is too large")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U256, U64, U8};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Q<T: super::Quantity>(#[serde(with = "super")] T);
+
+    fn json<T: super::Quantity>(value: T) -> String {
+        serde_json::to_string(&Q(value)).unwrap()
+    }
+
+    fn from_json<T: super::Quantity>(s: &str) -> Result<T, serde_json::Error> {
+        serde_json::from_str::<Q<T>>(s).map(|q| q.0)
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(json(0u8), r#""0x0""#);
+        assert_eq!(json(0x400u64), r#""0x400""#);
+        assert_eq!(json(u128::MAX), format!(r#""{:#x}""#, u128::MAX));
+        assert_eq!(json(U8::ZERO), r#""0x0""#);
+        assert_eq!(json(U64::from(0x400)), r#""0x400""#);
+        assert_eq!(json(U256::from(1)), r#""0x1""#);
+        assert_eq!(json(U256::MAX), format!(r#""0x{}""#, "f".repeat(64)));
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(from_json::<u64>(r#""0x400""#).unwrap(), 0x400);
+        assert_eq!(from_json::<u64>(r#""0X00400""#).unwrap(), 0x400);
+        assert_eq!(from_json::<u64>(r#""0xAbC""#).unwrap(), 0xabc);
+        assert_eq!(from_json::<u64>(r#""1024""#).unwrap(), 1024);
+        assert_eq!(from_json::<u64>("1024").unwrap(), 1024);
+        assert_eq!(from_json::<U256>(r#""0x0""#).unwrap(), U256::ZERO);
+        assert_eq!(from_json::<U256>("1024").unwrap(), U256::from(1024));
+        assert_eq!(
+            from_json::<U256>(&format!(r#""0x{}""#, "f".repeat(64))).unwrap(),
+            U256::MAX
+        );
+        assert_eq!(
+            from_json::<U256>(&format!(r#""{}""#, U256::MAX)).unwrap(),
+            U256::MAX
+        );
+
+        for s in [
+            r#""""#,
+            r#""0x""#,
+            r#""0x_1""#,
+            r#""+1""#,
+            r#""-1""#,
+            r#""0x1g""#,
+            r#""1a""#,
+        ] {
+            assert!(from_json::<u64>(s).is_err(), "{s}");
+            assert!(from_json::<U64>(s).is_err(), "{s}");
+        }
+        assert!(from_json::<u8>(r#""0x100""#).is_err());
+        assert!(from_json::<u8>("256").is_err());
+        assert!(from_json::<U8>(r#""0x100""#).is_err());
+        assert!(from_json::<U8>(r#""256""#).is_err());
+        assert!(from_json::<U256>(&format!(r#""0x1{}""#, "0".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn binary() {
+        let bin = bincode::serialize(&Q(U256::from(1))).unwrap();
+        assert_eq!(bin.len(), 8 + 32);
+        assert_eq!(
+            bincode::deserialize::<Q<U256>>(&bin).unwrap(),
+            Q(U256::from(1))
+        );
+
+        let bin = bincode::serialize(&Q(1u64)).unwrap();
+        assert_eq!(bin, 1u64.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Q<u64>>(&bin).unwrap(), Q(1));
+    }
+}
@@ -0,0 +1,41 @@
+use super::Signed;
+use bytemuck::{Pod, Zeroable};
+use ruint::Uint;
+
+// SAFETY: `Signed` is `repr(transparent)` for `Uint`, which is zero when all
+// of its limbs are zero.
+unsafe impl<const BITS: usize, const LIMBS: usize> Zeroable for Signed<BITS, LIMBS> {}
+
+// SAFETY: `Signed` is `repr(transparent)` for `Uint`.
+unsafe impl<const BITS: usize, const LIMBS: usize> Pod for Signed<BITS, LIMBS> where
+    Uint<BITS, LIMBS>: Pod
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{I256, U256};
+
+    #[test]
+    fn zeroed() {
+        assert_eq!(<I256 as bytemuck::Zeroable>::zeroed(), I256::ZERO);
+        assert_eq!(<U256 as bytemuck::Zeroable>::zeroed(), U256::ZERO);
+    }
+
+    #[test]
+    fn cast() {
+        let uints = [U256::from(1), U256::MAX];
+        let bytes: &[u8] = bytemuck::cast_slice(&uints);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[..32], U256::from(1).as_le_slice());
+        assert!(bytes[32..].iter().all(|&b| b == 0xff));
+
+        let ints = [I256::MINUS_ONE, I256::MIN, I256::MAX, I256::ZERO];
+        let bytes: &[u8] = bytemuck::cast_slice(&ints);
+        assert_eq!(&bytes[..32], &[0xff; 32]);
+        let mut aligned = [I256::ZERO; 4];
+        bytemuck::cast_slice_mut::<_, u8>(&mut aligned).copy_from_slice(bytes);
+        assert_eq!(aligned, ints);
+        assert_eq!(bytemuck::cast_slice::<u8, I256>(bytes), ints);
+    }
+}
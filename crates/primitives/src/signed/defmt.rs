@@ -0,0 +1,18 @@
+use super::{Sign, Signed};
+
+impl defmt::Format for Sign {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Positive => defmt::write!(f, "+"),
+            Self::Negative => defmt::write!(f, "-"),
+        }
+    }
+}
+
+// `Uint` does not implement `defmt::Format`, so this goes through the decimal
+// `Display` implementation.
+impl<const BITS: usize, const LIMBS: usize> defmt::Format for Signed<BITS, LIMBS> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", defmt::Display2Format(self));
+    }
+}
@@ -59,6 +59,12 @@ use ruint::Uint;
 /// assert_eq!(I256::MINUS_ONE, I256::unchecked_from(-1));
 /// ```
 ///
+/// # Layout
+///
+/// `Signed` is guaranteed to have the same layout as the underlying [`Uint`].
+/// With the `bytemuck` feature enabled, it implements `Zeroable`, and `Pod`
+/// whenever the underlying [`Uint`] does.
+///
 /// # Note on [`std::str::FromStr`]
 ///
 /// The parse function first tries the string as a decimal string, then as a
@@ -76,6 +82,7 @@ use ruint::Uint;
     feature = "arbitrary",
     derive(derive_arbitrary::Arbitrary, proptest_derive::Arbitrary)
 )]
+#[repr(transparent)]
 pub struct Signed<const BITS: usize, const LIMBS: usize>(pub(crate) Uint<BITS, LIMBS>);
 
 // formatting
@@ -89,10 +96,13 @@ impl<const BITS: usize, const LIMBS: usize> fmt::Debug for Signed<BITS, LIMBS> {
 impl<const BITS: usize, const LIMBS: usize> fmt::Display for Signed<BITS, LIMBS> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (sign, abs) = self.into_sign_and_abs();
-        // sign must be formatted directly, instead of with `write!` due to the
-        // `sign_positive` flag
-        sign.fmt(f)?;
-        write!(f, "{abs}")
+        let sign = match sign {
+            Sign::Negative => "-",
+            Sign::Positive if f.sign_plus() => "+",
+            Sign::Positive => "",
+        };
+        let len = abs.to_base_le(10).count().max(1);
+        crate::utils::pad_integral(f, sign, len, |f| write!(f, "{abs}"))
     }
 }
 
@@ -247,34 +257,34 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
 
     /// Returns the number of ones in the binary representation of `self`.
     #[inline(always)]
-    pub fn count_ones(&self) -> usize {
+    pub const fn count_ones(&self) -> usize {
         self.0.count_ones()
     }
 
     /// Returns the number of zeros in the binary representation of `self`.
     #[inline(always)]
-    pub fn count_zeros(&self) -> usize {
+    pub const fn count_zeros(&self) -> usize {
         self.0.count_zeros()
     }
 
     /// Returns the number of leading zeros in the binary representation of
     /// `self`.
     #[inline(always)]
-    pub fn leading_zeros(&self) -> usize {
+    pub const fn leading_zeros(&self) -> usize {
         self.0.leading_zeros()
     }
 
     /// Returns the number of leading zeros in the binary representation of
     /// `self`.
     #[inline(always)]
-    pub fn trailing_zeros(&self) -> usize {
+    pub const fn trailing_zeros(&self) -> usize {
         self.0.trailing_zeros()
     }
 
     /// Returns the number of leading ones in the binary representation of
     /// `self`.
     #[inline(always)]
-    pub fn trailing_ones(&self) -> usize {
+    pub const fn trailing_ones(&self) -> usize {
         self.0.trailing_ones()
     }
 
@@ -430,7 +440,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// [#60551]: https://github.com/rust-lang/rust/issues/60551
     #[inline(always)]
     #[track_caller]
-    pub fn to_be_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+    pub const fn to_be_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
         self.0.to_be_bytes()
     }
 
@@ -446,7 +456,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// [#60551]: https://github.com/rust-lang/rust/issues/60551
     #[inline(always)]
     #[track_caller]
-    pub fn to_le_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
+    pub const fn to_le_bytes<const BYTES: usize>(self) -> [u8; BYTES] {
         self.0.to_le_bytes()
     }
 
@@ -457,7 +467,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// If the given array is not the correct length.
     #[inline(always)]
     #[track_caller]
-    pub fn from_be_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
+    pub const fn from_be_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
         Self(Uint::from_be_bytes::<BYTES>(bytes))
     }
 
@@ -468,7 +478,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// If the given array is not the correct length.
     #[inline(always)]
     #[track_caller]
-    pub fn from_le_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
+    pub const fn from_le_bytes<const BYTES: usize>(bytes: [u8; BYTES]) -> Self {
         Self(Uint::from_le_bytes::<BYTES>(bytes))
     }
 
@@ -506,7 +516,7 @@ mod tests {
     use core::ops::Neg;
     use ruint::{
         aliases::{U0, U1, U128, U160, U256},
-        BaseConvertError, ParseError,
+        ParseError,
     };
 
     // type U2 = Uint<2, 1>;
@@ -622,12 +632,7 @@ mod tests {
                 assert_eq!(value.into_sign_and_abs(), (Sign::Positive, unsigned));
 
                 let err = <$i_struct>::from_dec_str("invalid string").unwrap_err();
-                assert_eq!(
-                    err,
-                    ParseSignedError::Ruint(ParseError::BaseConvertError(
-                        BaseConvertError::InvalidDigit(18, 10)
-                    ))
-                );
+                assert_eq!(err, ParseSignedError::Ruint(ParseError::InvalidDigit('i')));
 
                 let err = <$i_struct>::from_dec_str(&format!("1{}", <$u_struct>::MAX)).unwrap_err();
                 assert_eq!(err, ParseSignedError::IntegerOverflow);
@@ -748,8 +753,8 @@ mod tests {
 
                 assert_eq!(format!("{positive:x}"), format!("{unsigned:x}"));
                 assert_eq!(format!("{negative:x}"), format!("{unsigned_negative:x}"));
-                assert_eq!(format!("{positive:+x}"), format!("{unsigned:x}"));
-                assert_eq!(format!("{negative:+x}"), format!("{unsigned_negative:x}"));
+                assert_eq!(format!("{positive:+x}"), format!("+{unsigned:x}"));
+                assert_eq!(format!("{negative:+x}"), format!("+{unsigned_negative:x}"));
 
                 assert_eq!(
                     format!("{positive:X}"),
@@ -761,11 +766,11 @@ mod tests {
                 );
                 assert_eq!(
                     format!("{positive:+X}"),
-                    format!("{unsigned:x}").to_uppercase()
+                    format!("+{unsigned:x}").to_uppercase()
                 );
                 assert_eq!(
                     format!("{negative:+X}"),
-                    format!("{unsigned_negative:x}").to_uppercase()
+                    format!("+{unsigned_negative:x}").to_uppercase()
                 );
             };
         }
@@ -775,6 +780,29 @@ mod tests {
         let m = I1::MINUS_ONE;
         assert_eq!(format!("{z} {o} {m}"), "0 0 -1");
 
+        // like the primitive integers, hex is two's complement and `+` is
+        // always printed
+        let p = I256::try_from(255).unwrap();
+        assert_eq!(format!("{p:x} {p:+x} {p:+X}"), "ff +ff +FF");
+        assert_eq!(
+            format!("{:+x}", I256::MINUS_ONE),
+            format!("+{}", "f".repeat(64))
+        );
+        assert_eq!(format!("{:+x} {:+x}", 255i32, -1i32), "+ff +ffffffff");
+
+        // padding behaves like the primitive integers
+        let n = I256::try_from(-42).unwrap();
+        let p = I256::try_from(42).unwrap();
+        assert_eq!(
+            format!("[{n:6}] [{p:<6}] [{p:^+7}]"),
+            "[   -42] [42    ] [  +42  ]"
+        );
+        assert_eq!(format!("[{n:06}] [{p:*>5}]"), "[-00042] [***42]");
+        assert_eq!(
+            format!("[{:+04}] [{z:3}] [{:.1}]", I256::ZERO, I256::MIN),
+            format!("[+000] [  0] [{}]", I256::MIN)
+        );
+
         run_test!(I96, U96);
         run_test!(I128, U128);
         run_test!(I160, U160);
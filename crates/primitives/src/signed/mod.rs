@@ -18,6 +18,14 @@ mod ops;
 mod sign;
 pub use sign::Sign;
 
+/// Bytemuck support.
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+/// Defmt support.
+#[cfg(feature = "defmt")]
+mod defmt;
+
 /// Serde support.
 #[cfg(feature = "serde")]
 mod serde;
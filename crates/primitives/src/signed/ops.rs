@@ -138,7 +138,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// occurred then the wrapped value is returned.
     #[inline(always)]
     #[must_use]
-    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
         let (unsigned, _) = self.0.overflowing_add(rhs.0);
         let result = Self(unsigned);
 
@@ -157,7 +157,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// overflow occurred.
     #[inline(always)]
     #[must_use]
-    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
         match self.overflowing_add(rhs) {
             (value, false) => Some(value),
             _ => None,
@@ -168,7 +168,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// numeric bounds instead of overflowing.
     #[inline(always)]
     #[must_use]
-    pub fn saturating_add(self, rhs: Self) -> Self {
+    pub const fn saturating_add(self, rhs: Self) -> Self {
         let (result, overflow) = self.overflowing_add(rhs);
         if overflow {
             match result.sign() {
@@ -184,7 +184,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// the boundary of the type.
     #[inline(always)]
     #[must_use]
-    pub fn wrapping_add(self, rhs: Self) -> Self {
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
         self.overflowing_add(rhs).0
     }
 
@@ -195,7 +195,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// have occurred then the wrapped value is returned.
     #[inline(always)]
     #[must_use]
-    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
         // NOTE: We can't just compute the `self + (-rhs)` because `-rhs` does
         //   not always exist, specifically this would be a problem in case
         //   `rhs == Self::MIN`
@@ -218,7 +218,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// overflow occurred.
     #[inline(always)]
     #[must_use]
-    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
         match self.overflowing_sub(rhs) {
             (value, false) => Some(value),
             _ => None,
@@ -229,7 +229,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// numeric bounds instead of overflowing.
     #[inline(always)]
     #[must_use]
-    pub fn saturating_sub(self, rhs: Self) -> Self {
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
         let (result, overflow) = self.overflowing_sub(rhs);
         if overflow {
             match result.sign() {
@@ -245,7 +245,7 @@ impl<const BITS: usize, const LIMBS: usize> Signed<BITS, LIMBS> {
     /// at the boundary of the type.
     #[inline(always)]
     #[must_use]
-    pub fn wrapping_sub(self, rhs: Self) -> Self {
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
         self.overflowing_sub(rhs).0
     }
 
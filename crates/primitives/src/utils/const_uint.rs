@@ -0,0 +1,261 @@
+//! `const fn` arithmetic and construction for [`Uint`].
+//!
+//! These allow defining protocol constants from expressions, evaluated at
+//! compile time. Panicking functions cause a compile error when evaluated in a
+//! `const` context.
+
+use ruint::Uint;
+
+/// Creates a new [`Uint`] from the given big-endian bytes.
+///
+/// # Panics
+///
+/// Panics if the value is too large for the bit-size of the [`Uint`].
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::const_from_be_bytes, U256};
+///
+/// const X: U256 = const_from_be_bytes([0x01, 0x00]);
+/// assert_eq!(X, U256::from(256u64));
+/// ```
+#[track_caller]
+pub const fn const_from_be_bytes<const BITS: usize, const LIMBS: usize, const N: usize>(
+    bytes: [u8; N],
+) -> Uint<BITS, LIMBS> {
+    let mut limbs = [0u64; LIMBS];
+    let mut i = 0;
+    while i < N {
+        let byte = bytes[N - 1 - i];
+        let limb = i / 8;
+        if limb < LIMBS {
+            limbs[limb] |= (byte as u64) << ((i % 8) * 8);
+        } else if byte != 0 {
+            panic!("value too large for Uint")
+        }
+        i += 1;
+    }
+    if LIMBS > 0 && limbs[LIMBS - 1] > ruint::mask(BITS) {
+        panic!("value too large for Uint")
+    }
+    Uint::from_limbs(limbs)
+}
+
+/// Computes `lhs + rhs`, returning [`None`] if overflow occurred.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::const_checked_add, U256};
+///
+/// assert_eq!(const_checked_add(U256::from(1u64), U256::from(2u64)), Some(U256::from(3u64)));
+/// assert_eq!(const_checked_add(U256::MAX, U256::from(1u64)), None);
+/// ```
+#[must_use]
+pub const fn const_checked_add<const BITS: usize, const LIMBS: usize>(
+    lhs: Uint<BITS, LIMBS>,
+    rhs: Uint<BITS, LIMBS>,
+) -> Option<Uint<BITS, LIMBS>> {
+    let a = lhs.as_limbs();
+    let b = rhs.as_limbs();
+    let mut limbs = [0u64; LIMBS];
+    let mut carry = false;
+    let mut i = 0;
+    while i < LIMBS {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        limbs[i] = sum;
+        carry = c1 | c2;
+        i += 1;
+    }
+    if carry || (LIMBS > 0 && limbs[LIMBS - 1] > ruint::mask(BITS)) {
+        return None
+    }
+    Some(Uint::from_limbs(limbs))
+}
+
+/// Computes `lhs - rhs`, returning [`None`] if underflow occurred.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::const_checked_sub, U256};
+///
+/// assert_eq!(const_checked_sub(U256::from(3u64), U256::from(2u64)), Some(U256::from(1u64)));
+/// assert_eq!(const_checked_sub(U256::ZERO, U256::from(1u64)), None);
+/// ```
+#[must_use]
+pub const fn const_checked_sub<const BITS: usize, const LIMBS: usize>(
+    lhs: Uint<BITS, LIMBS>,
+    rhs: Uint<BITS, LIMBS>,
+) -> Option<Uint<BITS, LIMBS>> {
+    let a = lhs.as_limbs();
+    let b = rhs.as_limbs();
+    let mut limbs = [0u64; LIMBS];
+    let mut borrow = false;
+    let mut i = 0;
+    while i < LIMBS {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        limbs[i] = diff;
+        borrow = b1 | b2;
+        i += 1;
+    }
+    if borrow {
+        return None
+    }
+    Some(Uint::from_limbs(limbs))
+}
+
+/// Computes `lhs * rhs`, returning [`None`] if overflow occurred.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::const_checked_mul, U256};
+///
+/// assert_eq!(const_checked_mul(U256::from(3u64), U256::from(2u64)), Some(U256::from(6u64)));
+/// assert_eq!(const_checked_mul(U256::MAX, U256::from(2u64)), None);
+/// ```
+#[must_use]
+pub const fn const_checked_mul<const BITS: usize, const LIMBS: usize>(
+    lhs: Uint<BITS, LIMBS>,
+    rhs: Uint<BITS, LIMBS>,
+) -> Option<Uint<BITS, LIMBS>> {
+    let a = lhs.as_limbs();
+    let b = rhs.as_limbs();
+    let mut limbs = [0u64; LIMBS];
+    let mut overflow = false;
+    let mut i = 0;
+    while i < LIMBS {
+        if a[i] != 0 {
+            let mut carry = 0u64;
+            let mut j = 0;
+            while j < LIMBS {
+                if i + j < LIMBS {
+                    let t = limbs[i + j] as u128 + a[i] as u128 * b[j] as u128 + carry as u128;
+                    limbs[i + j] = t as u64;
+                    carry = (t >> 64) as u64;
+                } else if b[j] != 0 {
+                    overflow = true;
+                }
+                j += 1;
+            }
+            overflow |= carry != 0;
+        }
+        i += 1;
+    }
+    if overflow || (LIMBS > 0 && limbs[LIMBS - 1] > ruint::mask(BITS)) {
+        return None
+    }
+    Some(Uint::from_limbs(limbs))
+}
+
+macro_rules! strict_ops {
+    ($($(#[$attr:meta])* $name:ident => $checked:ident, $msg:literal;)*) => {$(
+        $(#[$attr])*
+        ///
+        /// # Panics
+        ///
+        /// Panics on overflow, which is a compile error in `const` contexts.
+        #[must_use]
+        #[track_caller]
+        pub const fn $name<const BITS: usize, const LIMBS: usize>(
+            lhs: Uint<BITS, LIMBS>,
+            rhs: Uint<BITS, LIMBS>,
+        ) -> Uint<BITS, LIMBS> {
+            match $checked(lhs, rhs) {
+                Some(x) => x,
+                None => panic!($msg),
+            }
+        }
+    )*};
+}
+
+strict_ops! {
+    /// Computes `lhs + rhs`.
+    const_add => const_checked_add, "attempt to add with overflow";
+    /// Computes `lhs - rhs`.
+    const_sub => const_checked_sub, "attempt to subtract with overflow";
+    /// Computes `lhs * rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use alloy_primitives::{uint, utils::{const_add, const_mul}, U256};
+    ///
+    /// const GWEI: U256 = U256::from_limbs([1_000_000_000, 0, 0, 0]);
+    /// const ETHER: U256 = const_mul(GWEI, GWEI);
+    /// const X: U256 = const_add(ETHER, U256::from_limbs([1, 0, 0, 0]));
+    /// assert_eq!(X, uint!(1_000_000_000_000_000_001_U256));
+    /// ```
+    const_mul => const_checked_mul, "attempt to multiply with overflow";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U160, U256, U64};
+    use alloc::string::ToString;
+
+    // Uniswap V3 `TickMath` bounds
+    const MIN_SQRT_RATIO: U160 = const_from_be_bytes(4295128739u64.to_be_bytes());
+    const MAX_SQRT_RATIO: U160 =
+        const_from_be_bytes(crate::hex!("fffd8963efd1fc6a506488495d951d5263988d26"));
+    const RANGE: U160 = const_sub(MAX_SQRT_RATIO, MIN_SQRT_RATIO);
+
+    #[test]
+    fn constants() {
+        assert_eq!(MIN_SQRT_RATIO, U160::from(4295128739u64));
+        assert_eq!(
+            MAX_SQRT_RATIO.to_string(),
+            "1461446703485210103287273052203988822378723970342"
+        );
+        assert_eq!(RANGE, MAX_SQRT_RATIO - MIN_SQRT_RATIO);
+    }
+
+    #[test]
+    fn checked_ops() {
+        let values = [
+            U256::ZERO,
+            U256::from(1u64),
+            U256::from(u64::MAX),
+            U256::from(u128::MAX),
+            U256::MAX >> 128,
+            U256::MAX >> 1,
+            U256::MAX,
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(const_checked_add(a, b), a.checked_add(b), "{a} + {b}");
+                assert_eq!(const_checked_sub(a, b), a.checked_sub(b), "{a} - {b}");
+                assert_eq!(const_checked_mul(a, b), a.checked_mul(b), "{a} * {b}");
+            }
+        }
+
+        // non-multiple of 64 bit-sizes
+        assert_eq!(const_checked_add(U160::MAX, U160::from(1u64)), None);
+        assert_eq!(const_checked_mul(U160::MAX >> 80, U160::MAX >> 79), None);
+        assert_eq!(
+            const_checked_mul(U160::MAX >> 80, U160::MAX >> 80),
+            (U160::MAX >> 80usize).checked_mul(U160::MAX >> 80)
+        );
+    }
+
+    #[test]
+    fn from_be_bytes() {
+        assert_eq!(const_from_be_bytes::<64, 1, 0>([]), U64::ZERO);
+        assert_eq!(
+            const_from_be_bytes::<64, 1, 10>([0, 0, 1, 2, 3, 4, 5, 6, 7, 8]),
+            U64::from(0x0102030405060708u64)
+        );
+        assert_eq!(const_from_be_bytes::<256, 4, 32>([0xff; 32]), U256::MAX);
+    }
+
+    #[test]
+    #[should_panic = "value too large for Uint"]
+    fn from_be_bytes_too_large() {
+        let _ = const_from_be_bytes::<64, 1, 9>([1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
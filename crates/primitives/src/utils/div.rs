@@ -0,0 +1,224 @@
+//! Fast division of [`Uint`]s by a single machine word.
+//!
+//! Uses the reciprocal-based 2-by-1 division from Möller and Granlund,
+//! ["Improved division by invariant integers"][paper], which replaces the
+//! hardware division instruction in the inner loop with multiplications.
+//!
+//! [paper]: https://gmplib.org/~tege/division-paper.pdf
+
+use alloc::string::String;
+use ruint::Uint;
+
+/// `10^19`, the largest power of 10 that fits in a `u64`.
+const POW10_19: u64 = 10_000_000_000_000_000_000;
+
+/// A normalized divisor and its precomputed reciprocal.
+#[derive(Clone, Copy)]
+struct Reciprocal {
+    /// The divisor, shifted left so that its most significant bit is set.
+    d: u64,
+    /// `floor((2^128 - 1) / d) - 2^64`
+    v: u64,
+    /// The number of bits `d` was shifted by.
+    shift: u32,
+}
+
+impl Reciprocal {
+    #[inline]
+    const fn new(d: u64) -> Self {
+        assert!(d != 0, "attempt to divide by zero");
+        let shift = d.leading_zeros();
+        let d = d << shift;
+        let v = (u128::MAX / d as u128 - (1 << 64)) as u64;
+        Self { d, v, shift }
+    }
+
+    /// Divides the two-word number `u1:u0` by the normalized divisor.
+    ///
+    /// Requires `u1 < self.d`.
+    #[inline(always)]
+    const fn div_2by1(&self, u1: u64, u0: u64) -> (u64, u64) {
+        let q = (self.v as u128 * u1 as u128).wrapping_add(((u1 as u128) << 64) | u0 as u128);
+        let mut q1 = ((q >> 64) as u64).wrapping_add(1);
+        let q0 = q as u64;
+        let mut r = u0.wrapping_sub(q1.wrapping_mul(self.d));
+        if r > q0 {
+            q1 = q1.wrapping_sub(1);
+            r = r.wrapping_add(self.d);
+        }
+        if r >= self.d {
+            q1 += 1;
+            r -= self.d;
+        }
+        (q1, r)
+    }
+
+    /// Divides `limbs` in place, returning the remainder.
+    #[inline]
+    fn div_rem_limbs(&self, limbs: &mut [u64]) -> u64 {
+        let Some(&top) = limbs.last() else { return 0 };
+        if self.shift == 0 {
+            let mut rem = 0;
+            for limb in limbs.iter_mut().rev() {
+                (*limb, rem) = self.div_2by1(rem, *limb);
+            }
+            return rem
+        }
+
+        // divide `limbs << shift` by `d << shift`; the quotient is unchanged and
+        // the remainder has to be shifted back
+        let s = self.shift;
+        let mut rem = top >> (64 - s);
+        for i in (0..limbs.len()).rev() {
+            let lower = if i > 0 { limbs[i - 1] >> (64 - s) } else { 0 };
+            let u0 = (limbs[i] << s) | lower;
+            (limbs[i], rem) = self.div_2by1(rem, u0);
+        }
+        rem >> s
+    }
+}
+
+/// Divides `x` by `d`, returning the quotient and the remainder.
+///
+/// This is faster than the generic [`Uint`] long division when the divisor
+/// fits in a single limb.
+///
+/// # Panics
+///
+/// Panics if `d` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::div_rem_u64, U256};
+///
+/// let (q, r) = div_rem_u64(U256::from(1_000_003u64), 1_000);
+/// assert_eq!(q, U256::from(1_000u64));
+/// assert_eq!(r, 3);
+/// ```
+#[inline]
+pub fn div_rem_u64<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    d: u64,
+) -> (Uint<BITS, LIMBS>, u64) {
+    let mut limbs = x.into_limbs();
+    let rem = Reciprocal::new(d).div_rem_limbs(&mut limbs);
+    (Uint::from_limbs(limbs), rem)
+}
+
+/// Divides `x` by 10, returning the quotient and the remainder.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::divmod_10, U256};
+///
+/// assert_eq!(divmod_10(U256::from(1234u64)), (U256::from(123u64), 4));
+/// ```
+#[inline]
+pub fn divmod_10<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+) -> (Uint<BITS, LIMBS>, u64) {
+    const TEN: Reciprocal = Reciprocal::new(10);
+    let mut limbs = x.into_limbs();
+    let rem = TEN.div_rem_limbs(&mut limbs);
+    (Uint::from_limbs(limbs), rem)
+}
+
+/// Formats `x` as a decimal string.
+///
+/// Equivalent to `x.to_string()`, but splits the number into 19-digit chunks
+/// using reciprocal division instead of the generic long division.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::to_decimal_string, U256};
+///
+/// assert_eq!(to_decimal_string(U256::ZERO), "0");
+/// assert_eq!(to_decimal_string(U256::MAX), U256::MAX.to_string());
+/// ```
+pub fn to_decimal_string<const BITS: usize, const LIMBS: usize>(x: Uint<BITS, LIMBS>) -> String {
+    const POW: Reciprocal = Reciprocal::new(POW10_19);
+
+    let mut chunks = alloc::vec::Vec::with_capacity(LIMBS + 1);
+    let mut limbs = x.into_limbs();
+    let mut len = LIMBS;
+    loop {
+        while len > 0 && limbs[len - 1] == 0 {
+            len -= 1;
+        }
+        if len == 0 {
+            break
+        }
+        chunks.push(POW.div_rem_limbs(&mut limbs[..len]));
+    }
+
+    let Some((&first, rest)) = chunks.split_last() else {
+        return String::from("0")
+    };
+    let mut s = String::with_capacity(chunks.len() * 19);
+    let mut buf = itoa::Buffer::new();
+    s.push_str(buf.format(first));
+    for &chunk in rest.iter().rev() {
+        let digits = buf.format(chunk);
+        for _ in digits.len()..19 {
+            s.push('0');
+        }
+        s.push_str(digits);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U512, U256, U64};
+    use alloc::string::ToString;
+
+    #[test]
+    fn div_rem() {
+        let values = [
+            U256::ZERO,
+            U256::from(1u64),
+            U256::from(u64::MAX),
+            U256::from(u128::MAX),
+            U256::MAX,
+            U256::MAX >> 1,
+            U256::from_limbs([1, 2, 3, 4]),
+        ];
+        let divisors = [1, 2, 3, 7, 10, 1 << 32, POW10_19, u64::MAX, u64::MAX >> 1];
+        for x in values {
+            for d in divisors {
+                let (q, r) = div_rem_u64(x, d);
+                let expected = x.div_rem(U256::from(d));
+                assert_eq!((q, U256::from(r)), expected, "{x} / {d}");
+            }
+            let (q, r) = divmod_10(x);
+            assert_eq!((q, U256::from(r)), x.div_rem(U256::from(10u64)));
+        }
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide by zero"]
+    fn div_by_zero() {
+        let _ = div_rem_u64(U256::from(1u64), 0);
+    }
+
+    #[test]
+    fn decimal_string() {
+        for x in [
+            U256::ZERO,
+            U256::from(9u64),
+            U256::from(POW10_19),
+            U256::from(POW10_19 - 1),
+            U256::from(POW10_19) * U256::from(POW10_19),
+            U256::MAX,
+        ] {
+            assert_eq!(to_decimal_string(x), x.to_string());
+        }
+        assert_eq!(to_decimal_string(U512::MAX), U512::MAX.to_string());
+        assert_eq!(to_decimal_string(U64::MAX), U64::MAX.to_string());
+        assert_eq!(to_decimal_string(crate::aliases::U0::ZERO), "0");
+    }
+}
@@ -0,0 +1,277 @@
+//! Common Ethereum utilities.
+
+use crate::{bits::FixedBytes, B256};
+use alloc::vec::Vec;
+use core::{fmt, str};
+
+mod const_uint;
+pub use const_uint::{
+    const_add, const_checked_add, const_checked_mul, const_checked_sub, const_from_be_bytes,
+    const_mul, const_sub,
+};
+
+mod div;
+pub use div::{div_rem_u64, divmod_10, to_decimal_string};
+
+mod mul_div;
+pub use mul_div::{mul_div, Rounding};
+
+mod notation;
+pub use notation::{format_with_separators, to_engineering_string, truncate_to_significant};
+
+mod units;
+pub use units::{format_units, parse_units, Unit, UnitsError};
+
+#[cfg(feature = "bytemuck")]
+pub mod records;
+
+#[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))]
+#[link(wasm_import_module = "vm_hooks")]
+extern "C" {
+    /// When targeting VMs with native keccak hooks, the `native-keccak` feature
+    /// can be enabled to import and use the host environment's implementation
+    /// of [`keccak256`] in place of [`tiny_keccak`]. This is overridden when
+    /// the `tiny-keccak` feature is enabled.
+    ///
+    /// # SAFETY
+    ///
+    /// The VM accepts the preimage by pointer and length, and writes the
+    /// 32-byte hash.
+    /// - `bytes` must point to an input buffer at least `len` long.
+    /// - `output` must point to a buffer that is at least 32-bytes long.
+    ///
+    /// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+    /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
+    fn native_keccak256(bytes: *const u8, len: usize, output: *mut u8);
+}
+
+/// Simple interface to the [`keccak256`] hash function.
+///
+/// [`keccak256`]: https://en.wikipedia.org/wiki/SHA-3
+pub fn keccak256<T: AsRef<[u8]>>(bytes: T) -> FixedBytes<32> {
+    cfg_if::cfg_if! {
+        if #[cfg(all(feature = "native-keccak", not(feature = "tiny-keccak")))] {
+            /// Calls an external native keccak hook when `native-keccak` is enabled.
+            /// This is overridden when `tiny-keccak` is enabled.
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                let mut output = [0u8; 32];
+
+                // SAFETY: The output is 32-bytes, and the input comes from a slice.
+                unsafe { native_keccak256(bytes.as_ptr(), bytes.len(), output.as_mut_ptr()) };
+                output.into()
+            }
+        } else {
+            /// Calls [`tiny-keccak`] when the `tiny-keccak` feature is enabled or
+            /// when no particular keccak feature flag is specified.
+            ///
+            /// [`tiny_keccak`]: https://docs.rs/tiny-keccak/latest/tiny_keccak/
+            fn keccak256(bytes: &[u8]) -> FixedBytes<32> {
+                use tiny_keccak::{Hasher, Keccak};
+
+                let mut output = [0u8; 32];
+                let mut hasher = Keccak::v256();
+                hasher.update(bytes);
+                hasher.finalize(&mut output);
+                output.into()
+            }
+        }
+    }
+
+    keccak256(bytes.as_ref())
+}
+
+/// The prefix of messages signed with [EIP-191] version `0x45`, also known as
+/// `personal_sign`.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+pub const EIP191_PREFIX: &str = "\x19Ethereum Signed Message:\n";
+
+/// Constructs a message according to [EIP-191] version `0x45`, also known as
+/// `personal_sign`: `"\x19Ethereum Signed Message:\n" + len(message) +
+/// message`, where the length is in decimal.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::utils::eip191_message;
+///
+/// assert_eq!(eip191_message("Hello"), b"\x19Ethereum Signed Message:\n5Hello");
+/// ```
+pub fn eip191_message<T: AsRef<[u8]>>(message: T) -> Vec<u8> {
+    fn eip191_message(message: &[u8]) -> Vec<u8> {
+        let mut len = itoa::Buffer::new();
+        let len = len.format(message.len());
+        let mut out = Vec::with_capacity(EIP191_PREFIX.len() + len.len() + message.len());
+        out.extend_from_slice(EIP191_PREFIX.as_bytes());
+        out.extend_from_slice(len.as_bytes());
+        out.extend_from_slice(message);
+        out
+    }
+
+    eip191_message(message.as_ref())
+}
+
+/// Hashes a message according to [EIP-191] version `0x45`, also known as
+/// `personal_sign`: the [`keccak256`] of [`eip191_message`].
+///
+/// This is the digest that is signed by `personal_sign`, and recovered from
+/// when verifying its signatures.
+///
+/// [EIP-191]: https://eips.ethereum.org/EIPS/eip-191
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, utils::eip191_hash_message};
+///
+/// assert_eq!(
+///     eip191_hash_message("Hello World"),
+///     b256!("a1de988600a42c4b4ab089b619297c17d53cffae5d5120d82d8a92d0bb3b78f2")
+/// );
+/// ```
+pub fn eip191_hash_message<T: AsRef<[u8]>>(message: T) -> B256 {
+    keccak256(eip191_message(message))
+}
+
+/// Writes `bytes` as a `0x`-prefixed, lowercase hex string to `f` without
+/// allocating an intermediate [`String`](alloc::string::String).
+pub(crate) fn write_hex<W: fmt::Write + ?Sized>(f: &mut W, bytes: &[u8]) -> fmt::Result {
+    f.write_str("0x")?;
+    write_hex_digits::<false, W>(f, bytes)
+}
+
+/// Writes `bytes` as an unprefixed hex string to `f` without allocating.
+pub(crate) fn write_hex_digits<const UPPER: bool, W: fmt::Write + ?Sized>(
+    f: &mut W,
+    bytes: &[u8],
+) -> fmt::Result {
+    const CHUNK: usize = 64;
+
+    let mut buf = [0u8; CHUNK * 2];
+    for chunk in bytes.chunks(CHUNK) {
+        let out = &mut buf[..chunk.len() * 2];
+        if UPPER {
+            hex::encode_to_slice_upper(chunk, out).unwrap();
+        } else {
+            hex::encode_to_slice(chunk, out).unwrap();
+        }
+        // SAFETY: hex-encoded output is always valid UTF-8
+        f.write_str(unsafe { str::from_utf8_unchecked(out) })?;
+    }
+    Ok(())
+}
+
+/// Writes a prefix followed by `len` characters of digits, padded like the
+/// standard library pads integers.
+///
+/// If `f.width()` is larger than the output, it is padded with `f.fill()`
+/// according to `f.align()`, right-aligned by default, or with zeros between
+/// the prefix and the digits if the `0` flag is set.
+///
+/// Precision is not supported, and is ignored, like it is by the standard
+/// library's integer formatting.
+pub(crate) fn pad_integral(
+    f: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    digits: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let len = prefix.len() + len;
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => {
+            f.write_str(prefix)?;
+            return digits(f)
+        }
+    };
+
+    if f.sign_aware_zero_pad() {
+        f.write_str(prefix)?;
+        write_fill(f, '0', padding)?;
+        return digits(f)
+    }
+
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    write_fill(f, fill, pre)?;
+    f.write_str(prefix)?;
+    digits(f)?;
+    write_fill(f, fill, post)
+}
+
+fn write_fill(f: &mut fmt::Formatter<'_>, fill: char, n: usize) -> fmt::Result {
+    (0..n).try_for_each(|_| fmt::Write::write_char(f, fill))
+}
+
+/// A [`Display`](fmt::Display) adapter that formats a byte slice as a
+/// `0x`-prefixed, lowercase hex string without allocating.
+///
+/// Created by the `display_hex` methods on the byte types of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{bytes, fixed_bytes};
+///
+/// let b = bytes!("0123456789abcdef");
+/// assert_eq!(format!("{}", b.display_hex()), "0x0123456789abcdef");
+///
+/// let f = fixed_bytes!("dead");
+/// assert_eq!(format!("[{}]", f.display_hex()), "[0xdead]");
+/// ```
+#[derive(Clone, Copy)]
+pub struct DisplayHex<'a>(&'a [u8]);
+
+impl<'a> DisplayHex<'a> {
+    /// Creates a new hex display adapter over the given bytes.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl fmt::Debug for DisplayHex<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.0)
+    }
+}
+
+impl fmt::Display for DisplayHex<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn eip191() {
+        assert_eq!(eip191_message(""), b"\x19Ethereum Signed Message:\n0");
+        let message = [0xab; 100];
+        let expected = [&b"\x19Ethereum Signed Message:\n100"[..], &message].concat();
+        assert_eq!(eip191_message(message), expected);
+        assert_eq!(eip191_hash_message(message), keccak256(expected));
+    }
+
+    #[test]
+    fn write_hex_chunks() {
+        for len in [0, 1, 63, 64, 65, 200] {
+            let bytes: alloc::vec::Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let mut s = String::new();
+            write_hex(&mut s, &bytes).unwrap();
+            assert_eq!(s, hex::encode_prefixed(&bytes));
+            assert_eq!(DisplayHex::new(&bytes).to_string(), s);
+        }
+    }
+}
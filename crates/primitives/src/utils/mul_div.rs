@@ -0,0 +1,161 @@
+//! Full-precision `a * b / denominator` for [`U256`].
+//!
+//! The other wide and modular operations are provided by [`Uint`] itself:
+//! [`widening_mul`](Uint::widening_mul), [`checked_pow`](Uint::checked_pow),
+//! [`mul_mod`](Uint::mul_mod), [`add_mod`](Uint::add_mod) and
+//! [`pow_mod`](Uint::pow_mod).
+//!
+//! [`Uint`]: ruint::Uint
+
+use crate::{U256, U512};
+
+/// The rounding mode of [`mul_div`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Round towards zero, like integer division.
+    #[default]
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// Computes `a * b / denominator`, rounded according to `rounding`.
+///
+/// The intermediate product is computed with 512 bits, so it never overflows:
+/// the result is exact as long as it fits in a [`U256`]. Returns `None` if
+/// `denominator` is zero, or if the result does not fit in 256 bits.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     utils::{mul_div, Rounding},
+///     U256, U512,
+/// };
+///
+/// // the product overflows 256 bits, but the result does not
+/// let (a, b) = (U256::MAX, U256::from(3));
+/// assert_eq!(a.checked_mul(b), None);
+/// let half = U256::MAX / U256::from(2);
+/// assert_eq!(mul_div(a, b, U256::from(6), Rounding::Down), Some(half));
+/// assert_eq!(mul_div(a, b, U256::from(6), Rounding::Up), Some(half + U256::from(1)));
+///
+/// assert_eq!(mul_div(a, b, U256::ZERO, Rounding::Down), None);
+/// assert_eq!(mul_div(a, b, U256::from(1), Rounding::Down), None);
+///
+/// // other wide and modular operations are methods of `Uint`
+/// let wide: U512 = a.widening_mul(b);
+/// assert_eq!(wide, U512::from(a) * U512::from(3));
+/// assert_eq!(U256::from(2).checked_pow(U256::from(256)), None);
+/// assert_eq!(a.mul_mod(b, U256::from(7)), (wide % U512::from(7)).to::<U256>());
+/// assert_eq!(a.add_mod(a, U256::from(7)), U256::from(2));
+/// ```
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Option<U256> {
+    if denominator == U256::ZERO {
+        return None
+    }
+    let product: U512 = a.widening_mul(b);
+    let (mut quotient, remainder) = product.div_rem(U512::from(denominator));
+    if rounding == Rounding::Up && remainder != U512::ZERO {
+        // `quotient <= product < 2^512 - 1`, so this cannot overflow
+        quotient += U512::from(1);
+    }
+    U256::checked_from_limbs_slice(quotient.as_limbs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        let cases = [
+            (0u64, 0u64, 1u64, 0u64, 0u64),
+            (1, 1, 1, 1, 1),
+            (10, 10, 3, 33, 34),
+            (7, 3, 21, 1, 1),
+            (7, 3, 22, 0, 1),
+            (u64::MAX, u64::MAX, u64::MAX, u64::MAX, u64::MAX),
+        ];
+        for (a, b, d, down, up) in cases {
+            let (a, b, d) = (U256::from(a), U256::from(b), U256::from(d));
+            assert_eq!(mul_div(a, b, d, Rounding::Down), Some(U256::from(down)));
+            assert_eq!(mul_div(a, b, d, Rounding::Up), Some(U256::from(up)));
+            assert_eq!(mul_div(b, a, d, Rounding::Down), Some(U256::from(down)));
+        }
+    }
+
+    #[test]
+    fn wide() {
+        let max = U256::MAX;
+        assert_eq!(mul_div(max, max, max, Rounding::Down), Some(max));
+        assert_eq!(mul_div(max, max, max, Rounding::Up), Some(max));
+        assert_eq!(
+            mul_div(max, max - U256::from(1), max, Rounding::Up),
+            Some(max - U256::from(1))
+        );
+        assert_eq!(
+            mul_div(
+                max - U256::from(1),
+                max,
+                max - U256::from(1),
+                Rounding::Down
+            ),
+            Some(max)
+        );
+
+        // 2^255 * 2 / 2 == 2^255
+        let half = U256::from(1) << 255;
+        assert_eq!(
+            mul_div(half, U256::from(2), U256::from(2), Rounding::Down),
+            Some(half)
+        );
+
+        // 2^256 - 1 is divisible by 3, but not by 7
+        let q = mul_div(max, U256::from(2), U256::from(3), Rounding::Down).unwrap();
+        assert_eq!(q, max / U256::from(3) * U256::from(2));
+        assert_eq!(
+            mul_div(max, U256::from(2), U256::from(3), Rounding::Up),
+            Some(q)
+        );
+        let q = mul_div(max, U256::from(2), U256::from(7), Rounding::Down).unwrap();
+        assert_eq!(
+            mul_div(max, U256::from(2), U256::from(7), Rounding::Up),
+            Some(q + U256::from(1))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let max = U256::MAX;
+        assert_eq!(
+            mul_div(max, U256::from(1), U256::ZERO, Rounding::Down),
+            None
+        );
+        assert_eq!(
+            mul_div(U256::ZERO, U256::ZERO, U256::ZERO, Rounding::Up),
+            None
+        );
+        assert_eq!(
+            mul_div(max, U256::from(2), U256::from(1), Rounding::Down),
+            None
+        );
+        assert_eq!(mul_div(max, max, max - U256::from(1), Rounding::Down), None);
+
+        // 23 * b == 3 * max + 2, so the quotient is exactly max, with a
+        // remainder, and rounding it up overflows
+        let b = U256::from_str_radix(
+            "21642c8590b21642c8590b21642c8590b21642c8590b21642c8590b21642c859",
+            16,
+        )
+        .unwrap();
+        assert_eq!(
+            mul_div(U256::from(23), b, U256::from(3), Rounding::Down),
+            Some(max)
+        );
+        assert_eq!(
+            mul_div(U256::from(23), b, U256::from(3), Rounding::Up),
+            None
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! Human-readable formatting of [`Uint`]s, such as token balances.
+//!
+//! All functions take a number of `decimals`, and treat `x` as the fixed-point
+//! number `x / 10^decimals`. The output does not depend on the locale: the
+//! decimal separator is always a period (`.`).
+
+use super::to_decimal_string;
+use alloc::string::String;
+use ruint::Uint;
+
+/// Formats the fixed-point number `x / 10^decimals` in decimal, inserting
+/// `separator` between every group of three digits of the integer part.
+///
+/// Trailing zeros of the fractional part are omitted, as is the decimal point
+/// if the number is an integer.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::format_with_separators, U256};
+///
+/// let x = U256::from(1234567u64);
+/// assert_eq!(format_with_separators(x, 0, ','), "1,234,567");
+/// assert_eq!(format_with_separators(x, 2, '_'), "12_345.67");
+/// assert_eq!(format_with_separators(x, 9, ','), "0.001234567");
+/// ```
+pub fn format_with_separators<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    decimals: usize,
+    separator: char,
+) -> String {
+    let mut digits = to_decimal_string(x);
+    if digits.len() <= decimals {
+        // pad with the leading zeros of the fraction, and the integer zero
+        digits.insert_str(0, &"0".repeat(decimals + 1 - digits.len()));
+    }
+    let (int, frac) = digits.split_at(digits.len() - decimals);
+
+    let mut s = String::with_capacity(int.len() + int.len() / 3 + frac.len() + 1);
+    for (i, digit) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            s.push(separator);
+        }
+        s.push(digit);
+    }
+    push_fraction(&mut s, frac);
+    s
+}
+
+/// Formats the fixed-point number `x / 10^decimals` in engineering notation,
+/// keeping at most `significant` significant digits.
+///
+/// Engineering notation is scientific notation with an exponent that is a
+/// multiple of three, so the mantissa has one to three integer digits and the
+/// exponent maps directly to SI prefixes (`e3` is kilo, `e-6` is micro, ...).
+/// Extra digits are truncated, not rounded, and the exponent is omitted if it
+/// is zero. At least the integer digits of the mantissa are always kept, so a
+/// `significant` of zero behaves like one.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::to_engineering_string, U256};
+///
+/// let x = U256::from(1234567u64);
+/// assert_eq!(to_engineering_string(x, 0, 3), "1.23e6");
+/// assert_eq!(to_engineering_string(x, 0, 10), "1.234567e6");
+/// assert_eq!(to_engineering_string(x, 3, 4), "1.234e3");
+/// assert_eq!(to_engineering_string(x, 4, 4), "123.4");
+/// assert_eq!(to_engineering_string(x, 12, 2), "1.2e-6");
+/// // 1.5 gwei, in ether
+/// assert_eq!(to_engineering_string(U256::from(1_500_000_000u64), 18, 3), "1.5e-9");
+/// ```
+pub fn to_engineering_string<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    decimals: usize,
+    significant: usize,
+) -> String {
+    if x == Uint::ZERO {
+        return String::from("0")
+    }
+
+    let digits = to_decimal_string(x);
+    // the power of ten of the leading digit
+    let exp = digits.len() as isize - 1 - decimals as isize;
+    let eng_exp = exp.div_euclid(3) * 3;
+    let int_len = (exp - eng_exp) as usize + 1;
+
+    let kept = &digits[..significant.clamp(1, digits.len())];
+    let mut s = String::with_capacity(kept.len().max(int_len) + 8);
+    if kept.len() < int_len {
+        s.push_str(kept);
+        s.extend(core::iter::repeat('0').take(int_len - kept.len()));
+    } else {
+        s.push_str(&kept[..int_len]);
+        push_fraction(&mut s, &kept[int_len..]);
+    }
+    if eng_exp != 0 {
+        s.push('e');
+        s.push_str(itoa::Buffer::new().format(eng_exp));
+    }
+    s
+}
+
+/// Truncates `x` to its `significant` most significant decimal digits,
+/// replacing the remaining digits with zeros.
+///
+/// This is useful in combination with [`format_with_separators`] to display
+/// approximate amounts.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{
+///     utils::{format_with_separators, truncate_to_significant},
+///     U256,
+/// };
+///
+/// let x = U256::from(1234567u64);
+/// assert_eq!(truncate_to_significant(x, 3), U256::from(1230000u64));
+/// assert_eq!(truncate_to_significant(x, 10), x);
+/// assert_eq!(
+///     format_with_separators(truncate_to_significant(x, 2), 3, ','),
+///     "1,200"
+/// );
+/// ```
+pub fn truncate_to_significant<const BITS: usize, const LIMBS: usize>(
+    x: Uint<BITS, LIMBS>,
+    significant: usize,
+) -> Uint<BITS, LIMBS> {
+    if significant == 0 {
+        return Uint::ZERO
+    }
+    let len = to_decimal_string(x).len();
+    if len <= significant {
+        return x
+    }
+    // `x >= 10^(len - 1) > 10^(len - significant)`, so the power fits
+    let pow = Uint::from(10u64).pow(Uint::from(len - significant));
+    x - x % pow
+}
+
+/// Pushes `.` and `frac` without its trailing zeros, if any digits remain.
+pub(super) fn push_fraction(s: &mut String, frac: &str) {
+    let frac = frac.trim_end_matches('0');
+    if !frac.is_empty() {
+        s.push('.');
+        s.push_str(frac);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{aliases::U8, U256};
+
+    #[test]
+    fn separators() {
+        let cases = [
+            (0u64, 0, "0"),
+            (0, 18, "0"),
+            (1, 0, "1"),
+            (123, 0, "123"),
+            (1234, 0, "1,234"),
+            (123456, 0, "123,456"),
+            (1234567, 0, "1,234,567"),
+            (1000000, 3, "1,000"),
+            (1000001, 3, "1,000.001"),
+            (1, 3, "0.001"),
+            (100, 3, "0.1"),
+            (1000, 3, "1"),
+        ];
+        for (x, decimals, expected) in cases {
+            assert_eq!(
+                format_with_separators(U256::from(x), decimals, ','),
+                expected,
+                "{x} {decimals}"
+            );
+        }
+        assert_eq!(
+            format_with_separators(U256::MAX, 18, '\''),
+            "115'792'089'237'316'195'423'570'985'008'687'907'853'269'984'665'640'564'039'457.\
+             584007913129639935"
+        );
+    }
+
+    #[test]
+    fn engineering() {
+        let cases = [
+            (0u64, 0, 3, "0"),
+            (0, 18, 3, "0"),
+            (1, 0, 3, "1"),
+            (12, 0, 3, "12"),
+            (999, 0, 3, "999"),
+            (1000, 0, 3, "1e3"),
+            (123456, 0, 3, "123e3"),
+            (123456, 0, 1, "100e3"),
+            (123456, 0, 0, "100e3"),
+            (1000, 3, 3, "1"),
+            (1500, 3, 3, "1.5"),
+            (15, 3, 3, "15e-3"),
+            (1, 18, 3, "1e-18"),
+            (10, 18, 3, "10e-18"),
+            (100, 18, 3, "100e-18"),
+            (1000, 18, 3, "1e-15"),
+        ];
+        for (x, decimals, significant, expected) in cases {
+            assert_eq!(
+                to_engineering_string(U256::from(x), decimals, significant),
+                expected,
+                "{x} {decimals} {significant}"
+            );
+        }
+        assert_eq!(to_engineering_string(U256::MAX, 0, 3), "115e75");
+        assert_eq!(to_engineering_string(U256::MAX, 18, 5), "115.79e57");
+    }
+
+    #[test]
+    fn significant() {
+        assert_eq!(truncate_to_significant(U256::ZERO, 1), U256::ZERO);
+        assert_eq!(truncate_to_significant(U256::from(999u64), 0), U256::ZERO);
+        assert_eq!(
+            truncate_to_significant(U256::from(999u64), 1),
+            U256::from(900u64)
+        );
+        assert_eq!(truncate_to_significant(U8::MAX, 1), U8::from(200));
+        assert_eq!(truncate_to_significant(U8::MAX, 3), U8::MAX);
+        assert_eq!(
+            truncate_to_significant(U256::MAX, 3),
+            U256::from(115u64) * U256::from(10u64).pow(U256::from(75u64))
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! Zero-copy readers for flat binary files of fixed-size records.
+//!
+//! Historical chain data is often stored as flat files of fixed-size records,
+//! such as storage words, addresses, or `#[repr(C)]` log structs. This module
+//! reinterprets such data as slices of [`Pod`] types without copying, either
+//! directly from a byte slice (for example a memory-mapped file), or in batches
+//! from any [`std::io::Read`] implementation.
+//!
+//! Variable-length records, such as calldata, can be stored with a big-endian
+//! `u32` length prefix and read with [`LengthPrefixed`].
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_primitives::{utils::records, B256};
+//!
+//! // e.g. `memmap2::Mmap` derefs to `[u8]`
+//! let file = [[1u8; 32], [2u8; 32], [3u8; 32]].concat();
+//!
+//! let words: &[B256] = records::cast_records(&file).unwrap();
+//! assert_eq!(words, [B256::repeat_byte(1), B256::repeat_byte(2), B256::repeat_byte(3)]);
+//!
+//! let mut batches = records::batches::<B256>(&file, 2).unwrap();
+//! assert_eq!(batches.next().unwrap().len(), 2);
+//! assert_eq!(batches.next().unwrap().len(), 1);
+//! assert!(batches.next().is_none());
+//! ```
+
+use bytemuck::Pod;
+use core::{fmt, mem, slice};
+
+#[cfg(feature = "std")]
+use {alloc::vec::Vec, std::io};
+
+/// Error returned when bytes cannot be read as records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordError {
+    /// The data length is not a multiple of the record size.
+    TrailingBytes {
+        /// The size of a single record.
+        record_size: usize,
+        /// The number of bytes left over after the last full record.
+        trailing: usize,
+    },
+
+    /// The data is not sufficiently aligned for the record type.
+    Misaligned {
+        /// The alignment required by the record type.
+        align: usize,
+    },
+
+    /// A length-prefixed record is longer than the remaining data.
+    Truncated {
+        /// The length read from the prefix.
+        len: usize,
+        /// The number of bytes remaining after the prefix.
+        remaining: usize,
+    },
+
+    /// The record type is zero-sized.
+    ZeroSized,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordError {}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TrailingBytes {
+                record_size,
+                trailing,
+            } => write!(
+                f,
+                "{trailing} trailing bytes after the last record of size {record_size}"
+            ),
+            Self::Misaligned { align } => write!(f, "data is not aligned to {align} bytes"),
+            Self::Truncated { len, remaining } => write!(
+                f,
+                "record of length {len} is longer than the remaining {remaining} bytes"
+            ),
+            Self::ZeroSized => f.write_str("zero-sized records are not supported"),
+        }
+    }
+}
+
+/// Reinterprets `bytes` as a slice of records without copying.
+///
+/// Types built on [`FixedBytes`](crate::FixedBytes), such as
+/// [`Address`](crate::Address) and [`B256`](crate::B256), have an alignment of
+/// 1 and can be read from any offset.
+///
+/// # Errors
+///
+/// Returns an error if `T` is zero-sized, if the length of `bytes` is not a
+/// multiple of the record size, or if `bytes` is not sufficiently aligned for
+/// `T`.
+pub fn cast_records<T: Pod>(bytes: &[u8]) -> Result<&[T], RecordError> {
+    let record_size = mem::size_of::<T>();
+    if record_size == 0 {
+        return Err(RecordError::ZeroSized)
+    }
+    let trailing = bytes.len() % record_size;
+    if trailing != 0 {
+        return Err(RecordError::TrailingBytes {
+            record_size,
+            trailing,
+        })
+    }
+    bytemuck::try_cast_slice(bytes).map_err(|_| RecordError::Misaligned {
+        align: mem::align_of::<T>(),
+    })
+}
+
+/// Returns an iterator over batches of at most `batch_size` records in
+/// `bytes`.
+///
+/// # Errors
+///
+/// See [`cast_records`].
+///
+/// # Panics
+///
+/// Panics if `batch_size` is zero.
+#[inline]
+pub fn batches<T: Pod>(
+    bytes: &[u8],
+    batch_size: usize,
+) -> Result<slice::Chunks<'_, T>, RecordError> {
+    cast_records(bytes).map(|records| records.chunks(batch_size))
+}
+
+/// An iterator over variable-length records, each prefixed with its length as
+/// a big-endian `u32`.
+///
+/// Yields an error and stops if a record is truncated.
+#[derive(Clone, Debug)]
+pub struct LengthPrefixed<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> LengthPrefixed<'a> {
+    /// Creates a new iterator over the records in `bytes`.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bytes that have not been read yet.
+    #[inline]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> Iterator for LengthPrefixed<'a> {
+    type Item = Result<&'a [u8], RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None
+        }
+
+        let bytes = mem::take(&mut self.bytes);
+        if bytes.len() < 4 {
+            return Some(Err(RecordError::Truncated {
+                len: 4,
+                remaining: bytes.len(),
+            }))
+        }
+        let (prefix, rest) = bytes.split_at(4);
+        let len = u32::from_be_bytes(prefix.try_into().unwrap()) as usize;
+        if len > rest.len() {
+            return Some(Err(RecordError::Truncated {
+                len,
+                remaining: rest.len(),
+            }))
+        }
+        let (record, rest) = rest.split_at(len);
+        self.bytes = rest;
+        Some(Ok(record))
+    }
+}
+
+/// Reads batches of fixed-size records from a reader into a reusable buffer.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::records::RecordReader, Address};
+///
+/// let file = [[1u8; 20], [2u8; 20], [3u8; 20]].concat();
+/// let mut reader = RecordReader::<_, Address>::new(&file[..], 2);
+///
+/// let mut n = 0;
+/// while let Some(batch) = reader.next_batch().unwrap() {
+///     n += batch.len();
+/// }
+/// assert_eq!(n, 3);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct RecordReader<R, T> {
+    reader: R,
+    buf: Vec<T>,
+    /// The number of bytes of a truncated record after the last batch, which
+    /// is reported on the next call to `next_batch`.
+    trailing: usize,
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read, T: Pod> RecordReader<R, T> {
+    /// Creates a new reader which reads up to `batch_size` records at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero or `T` is zero-sized.
+    pub fn new(reader: R, batch_size: usize) -> Self {
+        assert!(batch_size != 0, "batch size must be non-zero");
+        assert!(
+            mem::size_of::<T>() != 0,
+            "zero-sized records are not supported"
+        );
+        Self {
+            reader,
+            buf: vec![T::zeroed(); batch_size],
+            trailing: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    #[inline]
+    pub const fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes this reader, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next batch of records.
+    ///
+    /// Returns `Ok(None)` once the reader is exhausted. Every batch except the
+    /// last one is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::UnexpectedEof`] error if the reader ends in
+    /// the middle of a record, and propagates any other I/O errors. The
+    /// complete records read before the truncated one are returned first, and
+    /// the error on the next call.
+    pub fn next_batch(&mut self) -> io::Result<Option<&[T]>> {
+        let record_size = mem::size_of::<T>();
+        if self.trailing != 0 {
+            let trailing = mem::take(&mut self.trailing);
+            return Err(trailing_bytes(record_size, trailing))
+        }
+
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut self.buf);
+        let mut filled = 0;
+        while filled < bytes.len() {
+            match self.reader.read(&mut bytes[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let n = filled / record_size;
+        let trailing = filled % record_size;
+        if trailing != 0 {
+            if n == 0 {
+                return Err(trailing_bytes(record_size, trailing))
+            }
+            self.trailing = trailing;
+        }
+        Ok((n != 0).then(|| &self.buf[..n]))
+    }
+}
+
+#[cfg(feature = "std")]
+fn trailing_bytes(record_size: usize, trailing: usize) -> io::Error {
+    let err = RecordError::TrailingBytes {
+        record_size,
+        trailing,
+    };
+    io::Error::new(io::ErrorKind::UnexpectedEof, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, B256, U256};
+    use alloc::vec::Vec;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Transfer {
+        from: Address,
+        to: Address,
+        value: B256,
+    }
+
+    // SAFETY: all fields are `Pod` and there is no padding.
+    unsafe impl bytemuck::Zeroable for Transfer {}
+    unsafe impl Pod for Transfer {}
+
+    #[test]
+    fn cast() {
+        let bytes = [[1u8; 20], [2u8; 20]].concat();
+        let addresses: &[Address] = cast_records(&bytes).unwrap();
+        assert_eq!(
+            addresses,
+            [Address::repeat_byte(1), Address::repeat_byte(2)]
+        );
+
+        // unaligned offsets are fine for byte arrays
+        let words: &[B256] = cast_records(&bytes[8..]).unwrap();
+        assert_eq!(words, [B256::from_slice(&bytes[8..])]);
+
+        assert_eq!(
+            cast_records::<B256>(&bytes),
+            Err(RecordError::TrailingBytes {
+                record_size: 32,
+                trailing: 8
+            })
+        );
+        assert_eq!(cast_records::<B256>(&[]), Ok(&[][..]));
+
+        assert_eq!(cast_records::<()>(&bytes), Err(RecordError::ZeroSized));
+        assert_eq!(cast_records::<[u64; 0]>(&[]), Err(RecordError::ZeroSized));
+        assert!(batches::<()>(&bytes, 1).is_err());
+    }
+
+    #[test]
+    fn cast_structs() {
+        let transfer = Transfer {
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            value: U256::from(3).into(),
+        };
+        let bytes = bytemuck::bytes_of(&transfer).repeat(5);
+        let mut batches = batches::<Transfer>(&bytes, 2).unwrap();
+        assert_eq!(batches.next(), Some(&[transfer; 2][..]));
+        assert_eq!(batches.next(), Some(&[transfer; 2][..]));
+        assert_eq!(batches.next(), Some(&[transfer][..]));
+        assert_eq!(batches.next(), None);
+    }
+
+    #[test]
+    fn misaligned() {
+        let limbs = [0u64; 5];
+        let bytes: &[u8] = bytemuck::cast_slice(&limbs);
+        assert_eq!(cast_records::<u64>(&bytes[..32]), Ok(&[0u64; 4][..]));
+        assert_eq!(
+            cast_records::<u64>(&bytes[1..33]),
+            Err(RecordError::Misaligned { align: 8 })
+        );
+    }
+
+    #[test]
+    fn length_prefixed() {
+        let bytes = [
+            &[0, 0, 0, 2, 0xaa, 0xbb][..],
+            &[0, 0, 0, 0],
+            &[0, 0, 0, 1, 0xcc],
+        ]
+        .concat();
+        let records = LengthPrefixed::new(&bytes)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(records, [&[0xaa, 0xbb][..], &[], &[0xcc]]);
+
+        let mut iter = LengthPrefixed::new(&[0, 0, 0, 3, 0xaa]);
+        assert_eq!(
+            iter.next(),
+            Some(Err(RecordError::Truncated {
+                len: 3,
+                remaining: 1
+            }))
+        );
+        assert_eq!(iter.next(), None);
+
+        let mut iter = LengthPrefixed::new(&[0, 0]);
+        assert_eq!(
+            iter.next(),
+            Some(Err(RecordError::Truncated {
+                len: 4,
+                remaining: 2
+            }))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reader() {
+        let bytes = (0..10u8).flat_map(|i| [i; 32]).collect::<Vec<_>>();
+        let mut reader = RecordReader::<_, B256>::new(&bytes[..], 4);
+        let mut words = Vec::new();
+        while let Some(batch) = reader.next_batch().unwrap() {
+            assert!(batch.len() == 4 || words.len() == 8);
+            words.extend_from_slice(batch);
+        }
+        assert_eq!(words, cast_records::<B256>(&bytes).unwrap());
+
+        let mut reader = RecordReader::<_, B256>::new(&bytes[..8], 4);
+        let err = reader.next_batch().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn reader_truncated() {
+        let bytes = (0..10u8).flat_map(|i| [i; 32]).collect::<Vec<_>>();
+        let words = cast_records::<B256>(&bytes).unwrap();
+
+        // 6 full records and 8 trailing bytes
+        let mut reader = RecordReader::<_, B256>::new(&bytes[..200], 4);
+        assert_eq!(reader.next_batch().unwrap(), Some(&words[..4]));
+        assert_eq!(reader.next_batch().unwrap(), Some(&words[4..6]));
+        let err = reader.next_batch().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            err.into_inner().unwrap().downcast_ref::<RecordError>(),
+            Some(&RecordError::TrailingBytes {
+                record_size: 32,
+                trailing: 8
+            })
+        );
+        assert_eq!(reader.next_batch().unwrap(), None);
+
+        // the truncated record ends a full batch
+        let mut reader = RecordReader::<_, B256>::new(&bytes[..136], 4);
+        assert_eq!(reader.next_batch().unwrap(), Some(&words[..4]));
+        assert!(reader.next_batch().is_err());
+        assert_eq!(reader.next_batch().unwrap(), None);
+    }
+}
@@ -0,0 +1,368 @@
+//! Conversion between token amounts and their decimal representation in a
+//! given unit, such as ether or gwei.
+//!
+//! All arithmetic is exact: no floating point numbers are involved, and any
+//! loss of precision is reported as an error.
+
+use super::{notation::push_fraction, to_decimal_string};
+use crate::U256;
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+use ruint::Uint;
+
+/// The error type that is returned when parsing or formatting units fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitsError {
+    /// The unit name is unknown, or the number of decimals is too large.
+    InvalidUnit,
+
+    /// The amount is not a non-negative decimal number.
+    InvalidNumber,
+
+    /// The amount has more fractional digits than the unit has decimals.
+    PrecisionLoss,
+
+    /// The amount does not fit in a [`U256`].
+    Overflow,
+}
+
+impl From<core::convert::Infallible> for UnitsError {
+    fn from(never: core::convert::Infallible) -> Self {
+        match never {}
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitsError {}
+
+impl fmt::Display for UnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidUnit => "invalid unit",
+            Self::InvalidNumber => "invalid decimal number",
+            Self::PrecisionLoss => "amount has more decimals than the unit",
+            Self::Overflow => "amount does not fit in 256 bits",
+        })
+    }
+}
+
+/// A unit of an amount, defined by its number of decimals.
+///
+/// Units can be created from their number of decimals, or from the names of
+/// the ether denominations (`wei`, `gwei`, `ether`, ...), case-insensitively.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::utils::Unit;
+///
+/// assert_eq!("gwei".parse::<Unit>(), Ok(Unit::GWEI));
+/// assert_eq!(Unit::try_from(18), Ok(Unit::ETHER));
+/// assert_eq!(Unit::ETHER.decimals(), 18);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unit(u8);
+
+impl Unit {
+    /// Wei, the smallest denomination of ether. 0 decimals.
+    pub const WEI: Self = Self(0);
+    /// Kwei, or babbage. 3 decimals.
+    pub const KWEI: Self = Self(3);
+    /// Mwei, or lovelace. 6 decimals.
+    pub const MWEI: Self = Self(6);
+    /// Gwei, or shannon. 9 decimals.
+    pub const GWEI: Self = Self(9);
+    /// Szabo, or twei. 12 decimals.
+    pub const SZABO: Self = Self(12);
+    /// Finney, or pwei. 15 decimals.
+    pub const FINNEY: Self = Self(15);
+    /// Ether. 18 decimals.
+    pub const ETHER: Self = Self(18);
+
+    /// The largest number of decimals: `10^77` is the largest power of ten
+    /// that fits in a [`U256`].
+    pub const MAX_DECIMALS: u8 = 77;
+
+    /// Creates a new unit with the given number of decimals. Returns `None` if
+    /// `decimals` is larger than [`MAX_DECIMALS`](Self::MAX_DECIMALS).
+    #[inline]
+    pub const fn new(decimals: u8) -> Option<Self> {
+        if decimals <= Self::MAX_DECIMALS {
+            Some(Self(decimals))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of decimals of this unit.
+    #[inline]
+    pub const fn decimals(self) -> u8 {
+        self.0
+    }
+
+    /// Returns `10^decimals`, the number of base units in one of this unit.
+    #[inline]
+    pub fn wei(self) -> U256 {
+        U256::from(10u64).pow(U256::from(self.0))
+    }
+}
+
+impl TryFrom<u8> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(decimals: u8) -> Result<Self, Self::Error> {
+        Self::new(decimals).ok_or(UnitsError::InvalidUnit)
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = UnitsError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Unit {
+    type Err = UnitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const NAMES: &[(&str, Unit)] = &[
+            ("wei", Unit::WEI),
+            ("kwei", Unit::KWEI),
+            ("babbage", Unit::KWEI),
+            ("mwei", Unit::MWEI),
+            ("lovelace", Unit::MWEI),
+            ("gwei", Unit::GWEI),
+            ("shannon", Unit::GWEI),
+            ("szabo", Unit::SZABO),
+            ("twei", Unit::SZABO),
+            ("finney", Unit::FINNEY),
+            ("pwei", Unit::FINNEY),
+            ("ether", Unit::ETHER),
+        ];
+        NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+            .map(|&(_, unit)| unit)
+            .ok_or(UnitsError::InvalidUnit)
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::WEI => f.write_str("wei"),
+            Self::KWEI => f.write_str("kwei"),
+            Self::MWEI => f.write_str("mwei"),
+            Self::GWEI => f.write_str("gwei"),
+            Self::SZABO => f.write_str("szabo"),
+            Self::FINNEY => f.write_str("finney"),
+            Self::ETHER => f.write_str("ether"),
+            Self(decimals) => write!(f, "{decimals} decimals"),
+        }
+    }
+}
+
+/// Parses a decimal `amount` in `unit` into the number of base units, e.g.
+/// ether into wei.
+///
+/// `unit` can be a [`Unit`], its name, or its number of decimals. The amount
+/// must be a non-negative decimal number, without sign or exponent, and must
+/// not have more significant fractional digits than the unit has decimals.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::{parse_units, Unit, UnitsError}, U256};
+///
+/// assert_eq!(parse_units("1.5", "ether")?, U256::from(1_500_000_000_000_000_000u64));
+/// assert_eq!(parse_units("21", Unit::GWEI)?, U256::from(21_000_000_000u64));
+/// assert_eq!(parse_units("0.50", 2)?, U256::from(50));
+///
+/// assert_eq!(parse_units("0.001", 2), Err(UnitsError::PrecisionLoss));
+/// assert_eq!(parse_units("1e18", "wei"), Err(UnitsError::InvalidNumber));
+/// assert_eq!(parse_units("1", "eth"), Err(UnitsError::InvalidUnit));
+/// # Ok::<_, UnitsError>(())
+/// ```
+pub fn parse_units<U>(amount: &str, unit: U) -> Result<U256, UnitsError>
+where
+    U: TryInto<Unit>,
+    UnitsError: From<U::Error>,
+{
+    let decimals = unit.try_into()?.decimals() as usize;
+
+    let (int, frac) = amount.split_once('.').unwrap_or((amount, ""));
+    if (int.is_empty() && frac.is_empty())
+        || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return Err(UnitsError::InvalidNumber)
+    }
+
+    let frac = frac.trim_end_matches('0');
+    if frac.len() > decimals {
+        return Err(UnitsError::PrecisionLoss)
+    }
+
+    // `int` and `frac` only contain digits, so parsing can only overflow
+    let parse = |digits: &str| U256::from_str_radix(digits, 10).map_err(|_| UnitsError::Overflow);
+    let scale = |decimals: usize| U256::from(10u64).pow(U256::from(decimals));
+    let int = parse(int)?
+        .checked_mul(scale(decimals))
+        .ok_or(UnitsError::Overflow)?;
+    let frac = parse(frac)? * scale(decimals - frac.len());
+    int.checked_add(frac).ok_or(UnitsError::Overflow)
+}
+
+/// Formats an amount of base units as a decimal number in `unit`, e.g. wei as
+/// ether.
+///
+/// `unit` can be a [`Unit`], its name, or its number of decimals. The output
+/// is exact: trailing zeros of the fractional part are omitted, as is the
+/// decimal point if the amount is an integer.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{utils::{format_units, parse_units, Unit}, U256};
+///
+/// let wei = U256::from(1_500_000_000_000_000_000u64);
+/// assert_eq!(format_units(wei, "ether")?, "1.5");
+/// assert_eq!(format_units(wei, Unit::GWEI)?, "1500000000");
+/// assert_eq!(format_units(U256::from(1), 18)?, "0.000000000000000001");
+///
+/// // round-trips with `parse_units`
+/// assert_eq!(parse_units(&format_units(wei, 18)?, 18)?, wei);
+/// # Ok::<_, alloy_primitives::utils::UnitsError>(())
+/// ```
+pub fn format_units<const BITS: usize, const LIMBS: usize, U>(
+    amount: Uint<BITS, LIMBS>,
+    unit: U,
+) -> Result<String, UnitsError>
+where
+    U: TryInto<Unit>,
+    UnitsError: From<U::Error>,
+{
+    let decimals = unit.try_into()?.decimals() as usize;
+
+    let mut digits = to_decimal_string(amount);
+    if digits.len() <= decimals {
+        // pad with the leading zeros of the fraction, and the integer zero
+        digits.insert_str(0, &"0".repeat(decimals + 1 - digits.len()));
+    }
+    let (int, frac) = digits.split_at(digits.len() - decimals);
+
+    let mut s = String::with_capacity(digits.len() + 1);
+    s.push_str(int);
+    push_fraction(&mut s, frac);
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::U8;
+
+    #[test]
+    fn units() {
+        for (name, unit) in [
+            ("wei", Unit::WEI),
+            ("Kwei", Unit::KWEI),
+            ("babbage", Unit::KWEI),
+            ("MWEI", Unit::MWEI),
+            ("gwei", Unit::GWEI),
+            ("shannon", Unit::GWEI),
+            ("szabo", Unit::SZABO),
+            ("finney", Unit::FINNEY),
+            ("ether", Unit::ETHER),
+        ] {
+            assert_eq!(name.parse::<Unit>(), Ok(unit), "{name}");
+        }
+        assert_eq!("eth".parse::<Unit>(), Err(UnitsError::InvalidUnit));
+        assert_eq!("".parse::<Unit>(), Err(UnitsError::InvalidUnit));
+
+        assert_eq!(Unit::try_from(0), Ok(Unit::WEI));
+        assert_eq!(Unit::try_from(77).map(Unit::decimals), Ok(77));
+        assert_eq!(Unit::try_from(78), Err(UnitsError::InvalidUnit));
+        assert_eq!(Unit::ETHER.wei(), U256::from(10u64.pow(18)));
+        assert_eq!(to_decimal_string(Unit::new(77).unwrap().wei()).len(), 78);
+    }
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("0", 0, 0u64),
+            ("1", 0, 1),
+            ("1.", 0, 1),
+            ("1.000", 0, 1),
+            ("0.5", 1, 5),
+            (".5", 1, 5),
+            ("1.5", 9, 1_500_000_000),
+            ("1.500", 3, 1500),
+            ("00012.3400", 4, 123400),
+            ("18446744073709551615", 0, u64::MAX),
+        ];
+        for (amount, decimals, expected) in cases {
+            assert_eq!(
+                parse_units(amount, decimals),
+                Ok(U256::from(expected)),
+                "{amount} {decimals}"
+            );
+        }
+
+        for amount in [
+            "", ".", "-1", "+1", "1.2.3", "1,5", "1_000", " 1", "0x10", "1e3",
+        ] {
+            assert_eq!(
+                parse_units(amount, 18),
+                Err(UnitsError::InvalidNumber),
+                "{amount}"
+            );
+        }
+
+        assert_eq!(parse_units("1.5", 0), Err(UnitsError::PrecisionLoss));
+        assert_eq!(
+            parse_units("0.0000000000000000001", "ether"),
+            Err(UnitsError::PrecisionLoss)
+        );
+        assert_eq!(parse_units("1", 78), Err(UnitsError::InvalidUnit));
+
+        let max = to_decimal_string(U256::MAX);
+        assert_eq!(parse_units(&max, 0), Ok(U256::MAX));
+        assert_eq!(
+            parse_units(&format!("{max}0"), 0),
+            Err(UnitsError::Overflow)
+        );
+        assert_eq!(parse_units(&max, 1), Err(UnitsError::Overflow));
+        assert_eq!(parse_units("1", 77), Ok(Unit::new(77).unwrap().wei()));
+        assert_eq!(parse_units("1000", 77), Err(UnitsError::Overflow));
+    }
+
+    #[test]
+    fn format() {
+        let cases = [
+            (0u64, 0, "0"),
+            (0, 18, "0"),
+            (1, 0, "1"),
+            (1, 3, "0.001"),
+            (1500, 3, "1.5"),
+            (1000, 3, "1"),
+            (1_000_000_001, 9, "1.000000001"),
+        ];
+        for (amount, decimals, expected) in cases {
+            assert_eq!(
+                format_units(U256::from(amount), decimals).as_deref(),
+                Ok(expected),
+                "{amount} {decimals}"
+            );
+        }
+        assert_eq!(format_units(U8::MAX, "kwei").as_deref(), Ok("0.255"));
+        assert_eq!(
+            format_units(U256::MAX, "ether").as_deref(),
+            Ok("115792089237316195423570985008687907853269984665640564039457.584007913129639935")
+        );
+        assert_eq!(format_units(U256::MAX, 78), Err(UnitsError::InvalidUnit));
+    }
+}
@@ -27,6 +27,10 @@ pub struct SolAttrs {
 
     pub bytecode: Option<LitStr>,
     pub deployed_bytecode: Option<LitStr>,
+
+    pub rename_type: Option<Vec<(ast::Type, syn::Type)>>,
+    pub use_crate_types: Option<syn::Path>,
+    pub bitflags: Option<syn::Ident>,
 }
 
 impl SolAttrs {
@@ -74,6 +78,37 @@ impl SolAttrs {
                     }
                     Ok(LitStr::new(v, lit.span()))
                 };
+                let type_map = || {
+                    let mut map = Vec::new();
+                    meta.parse_nested_meta(|meta| {
+                        let ident = meta
+                            .path
+                            .get_ident()
+                            .ok_or_else(|| meta.error("expected Solidity type name"))?;
+                        let sol_ty = syn::parse2::<ast::Type>(quote::quote!(#ident))?;
+                        if !sol_ty.is_one_word() || matches!(sol_ty, ast::Type::Function(_)) {
+                            return Err(meta.error("only value types can be renamed"))
+                        }
+                        let sol_ty = normalize_type(sol_ty);
+                        if map.iter().any(|(ty, _)| *ty == sol_ty) {
+                            return Err(meta.error("duplicate type"))
+                        }
+                        let rust_ty = meta.value()?.parse::<syn::Type>()?;
+                        map.push((sol_ty, rust_ty));
+                        Ok(())
+                    })?;
+                    Ok::<_, Error>(map)
+                };
+                let path = || {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<syn::Path>()
+                };
+                let ident = || {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<syn::Ident>()
+                };
 
                 match_! {
                     all_derives => (),
@@ -82,6 +117,10 @@ impl SolAttrs {
 
                     bytecode => bytes()?,
                     deployed_bytecode => bytes()?,
+
+                    rename_type => type_map()?,
+                    use_crate_types => path()?,
+                    bitflags => ident()?,
                 };
                 Ok(())
             })?;
@@ -90,6 +129,17 @@ impl SolAttrs {
     }
 }
 
+/// Normalizes a type so that equivalent spellings compare equal, e.g. `uint`
+/// and `uint256`.
+pub fn normalize_type(ty: ast::Type) -> ast::Type {
+    use std::num::NonZeroU16;
+    match ty {
+        ast::Type::Int(span, None) => ast::Type::Int(span, NonZeroU16::new(256)),
+        ast::Type::Uint(span, None) => ast::Type::Uint(span, NonZeroU16::new(256)),
+        ty => ty,
+    }
+}
+
 /// Defines the casing for the attributes long representation.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CasingStyle {
@@ -224,6 +274,31 @@ mod tests {
             #[sol(bytecode = "12 34")] => Err("expected hex literal"),
             #[sol(bytecode = "xyz")] => Err("expected hex literal"),
             #[sol(bytecode = "123")] => Err("expected even number of hex digits"),
+
+            #[sol(rename_type())] => Err("unexpected end of input, expected nested attribute"),
+            #[sol(rename_type(uint48 = u64, bytes32 = MyHash))] => Ok(sol_attrs! { rename_type: vec![
+                (parse_quote!(uint48), parse_quote!(u64)),
+                (parse_quote!(bytes32), parse_quote!(MyHash)),
+            ] }),
+            #[sol(rename_type(uint = MyUint))] => Ok(sol_attrs! { rename_type: vec![
+                (parse_quote!(uint256), parse_quote!(MyUint)),
+            ] }),
+            #[sol(rename_type(uint = A, uint256 = B))] => Err("duplicate type"),
+            #[sol(rename_type(string = String))] => Err("only value types can be renamed"),
+            #[sol(rename_type(uint48))] => Err("expected `=`"),
+            #[sol(rename_type(uint48 = u64))] #[sol(rename_type(bool = bool))] => Err("duplicate attribute"),
+
+            #[sol(use_crate_types(crate::types))] => Ok(sol_attrs! { use_crate_types: parse_quote!(crate::types) }),
+            #[sol(use_crate_types(::other::types))] => Ok(sol_attrs! { use_crate_types: parse_quote!(::other::types) }),
+            #[sol(use_crate_types())] => Err("unexpected end of input, expected identifier"),
+            #[sol(use_crate_types = "a")] => Err("expected parentheses"),
+            #[sol(use_crate_types(a))] #[sol(use_crate_types(b))] => Err("duplicate attribute"),
+
+            #[sol(bitflags(Roles))] => Ok(sol_attrs! { bitflags: parse_quote!(Roles) }),
+            #[sol(bitflags())] => Err("unexpected end of input, expected identifier"),
+            #[sol(bitflags(a::b))] => Err("unexpected token, expected `)`"),
+            #[sol(bitflags = "Roles")] => Err("expected parentheses"),
+            #[sol(bitflags(A))] #[sol(bitflags(B))] => Err("duplicate attribute"),
         }
     }
 }
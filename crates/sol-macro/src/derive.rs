@@ -0,0 +1,128 @@
+//! `#[derive(SolStruct)]` implementation.
+
+use crate::expand::expand_type;
+use ast::{File, Item, ItemStruct, SolIdent, Type, VariableDeclaration};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, LitStr, Result};
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    if let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("sol")) {
+        let msg = "`sol` attributes are only supported on fields";
+        return Err(Error::new_spanned(attr, msg))
+    }
+    if !generics.params.is_empty() {
+        let msg = "`SolStruct` cannot be derived for generic structs";
+        return Err(Error::new_spanned(generics, msg))
+    }
+    let fields = match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => fields.named,
+            fields => {
+                let msg = "`SolStruct` can only be derived for structs with named fields";
+                return Err(Error::new(fields.span(), msg))
+            }
+        },
+        Data::Enum(data) => {
+            let msg = "`SolStruct` cannot be derived for enums";
+            return Err(Error::new(data.enum_token.span, msg))
+        }
+        Data::Union(data) => {
+            let msg = "`SolStruct` cannot be derived for unions";
+            return Err(Error::new(data.union_token.span, msg))
+        }
+    };
+
+    let mut errors = Vec::new();
+    let mut params = Vec::with_capacity(fields.len());
+    let mut assert_types = Vec::with_capacity(fields.len());
+    for field in &fields {
+        let name = field.ident.clone().unwrap();
+        match field_type(&name, &field.attrs) {
+            Ok(ty) => {
+                // errors in the type check point to the field's type
+                let sol_ty = expand_type(&ty);
+                assert_types.push(quote_spanned! {field.ty.span()=>
+                    let _: <#sol_ty as ::alloy_sol_types::SolType>::RustType = value.#name;
+                });
+                params.push(VariableDeclaration {
+                    attrs: Vec::new(),
+                    ty,
+                    storage: None,
+                    name: Some(SolIdent::from(name)),
+                });
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+    if let Some(e) = crate::utils::combine_errors(errors) {
+        return Err(e)
+    }
+
+    let span = ident.span();
+    let name = ident.clone();
+    let file = File {
+        attrs: Vec::new(),
+        items: vec![Item::Struct(ItemStruct {
+            attrs: Vec::new(),
+            struct_token: syn::Token![struct](span),
+            name: name.into(),
+            brace_token: Default::default(),
+            fields: params.into_iter().collect(),
+        })],
+    };
+    let impls = crate::expand::expand_struct_impls(file)?;
+    Ok(quote! {
+        #[allow(non_snake_case, clippy::style)]
+        const _: () = {
+            // the fields must have the Rust types of their Solidity types
+            fn _assert_field_types(value: #ident) {
+                #(#assert_types)*
+            }
+        };
+
+        #impls
+    })
+}
+
+/// Parses the Solidity type of a field from its `#[sol(type = "...")]`
+/// attribute.
+fn field_type(name: &syn::Ident, attrs: &[Attribute]) -> Result<Type> {
+    let mut ty = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sol")) {
+        attr.meta.require_list()?.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("type") {
+                return Err(meta.error("unknown `sol` attribute"))
+            }
+            if ty.is_some() {
+                return Err(meta.error("duplicate attribute"))
+            }
+            let lit = meta.value()?.parse::<LitStr>()?;
+            let parsed = lit.parse_with(|input: syn::parse::ParseStream<'_>| {
+                let mut ty = input.parse::<Type>()?;
+                ty.set_span(lit.span());
+                Ok(ty)
+            })?;
+            if matches!(parsed, Type::Function(_) | Type::Mapping(_)) {
+                return Err(Error::new(lit.span(), "expected a value type"))
+            }
+            ty = Some(parsed);
+            Ok(())
+        })?;
+    }
+    ty.ok_or_else(|| {
+        let msg = format!(
+            "missing Solidity type for field `{name}`; \
+             specify it with `#[sol(type = \"...\")]`"
+        );
+        Error::new(name.span(), msg)
+    })
+}
@@ -2,7 +2,7 @@
 
 use super::{ty, ExpCtxt};
 use crate::{attr, utils::ExprArray};
-use ast::{Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent};
+use ast::{FunctionKind, Item, ItemContract, ItemError, ItemEvent, ItemFunction, SolIdent};
 use heck::ToSnakeCase;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
@@ -28,7 +28,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
 
     let (sol_attrs, attrs) = crate::attr::SolAttrs::parse(attrs)?;
 
-    let bytecode = sol_attrs.bytecode.map(|lit| {
+    let bytecode = sol_attrs.bytecode.as_ref().map(|lit| {
         let name = Ident::new("BYTECODE", lit.span());
         quote! {
             /// The creation / init code of the contract.
@@ -43,6 +43,16 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         }
     });
 
+    let deploy_code = match &sol_attrs.bytecode {
+        Some(lit) => Some(expand_deploy_code(cx, lit.span(), body)?),
+        None => None,
+    };
+
+    let bitflags = match &sol_attrs.bitflags {
+        Some(ident) => Some(super::flags::expand(ident, name, body)?),
+        None => None,
+    };
+
     let mut functions = Vec::with_capacity(contract.body.len());
     let mut errors = Vec::with_capacity(contract.body.len());
     let mut events = Vec::with_capacity(contract.body.len());
@@ -56,10 +66,12 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
             Item::Event(event) => events.push(event),
             _ => {}
         }
-        if !d_attrs.is_empty() {
+        let tokens = cx.expand_item(item)?;
+        // items like state variables and constructors expand to nothing
+        if !d_attrs.is_empty() && !tokens.is_empty() {
             item_tokens.extend(quote!(#(#d_attrs)*));
         }
-        item_tokens.extend(cx.expand_item(item)?);
+        item_tokens.extend(tokens);
     }
 
     let functions_enum = (functions.len() > 1).then(|| {
@@ -90,6 +102,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
         pub mod #name {
             #bytecode
             #deployed_bytecode
+            #deploy_code
+            #bitflags
 
             #item_tokens
             #functions_enum
@@ -100,6 +114,40 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, contract: &ItemContract) -> Result<TokenS
     Ok(tokens)
 }
 
+/// Expands the `deploy_code` function, which appends the ABI-encoded
+/// constructor arguments to the contract's creation code.
+fn expand_deploy_code(cx: &ExpCtxt<'_>, span: Span, body: &[Item]) -> Result<TokenStream> {
+    let constructor = body.iter().find_map(|item| match item {
+        Item::Function(f) if matches!(f.kind, FunctionKind::Constructor(_)) => Some(f),
+        _ => None,
+    });
+    let params = constructor.map(|c| &c.arguments);
+    if let Some(params) = params {
+        cx.assert_resolved(params)?;
+    }
+    let params = params.into_iter().flatten();
+    let names: Vec<_> = params
+        .clone()
+        .enumerate()
+        .map(|(i, p)| super::anon_name((i, p.name.as_ref())))
+        .collect();
+    let types: Vec<_> = params.map(|p| super::expand_type(&p.ty)).collect();
+
+    let name = Ident::new("deploy_code", span);
+    let bytecode = Ident::new("BYTECODE", span);
+    Ok(quote! {
+        /// Returns the creation code of the contract followed by the
+        /// ABI-encoded constructor arguments, which is the input data of the
+        /// deployment transaction.
+        pub fn #name(
+            #(#names: <#types as ::alloy_sol_types::SolType>::RustType),*
+        ) -> ::alloy_sol_types::private::Vec<u8> {
+            let args = <(#(#types,)*) as ::alloy_sol_types::SolType>::encode_params(&(#(#names,)*));
+            [&#bytecode[..], &args[..]].concat()
+        }
+    })
+}
+
 // note that item impls generated here do not need to be wrapped in an anonymous
 // constant (`const _: () = { ... };`) because they are in one already
 
@@ -141,6 +189,7 @@ enum CallLikeExpanderData {
     },
     Event {
         selectors: Vec<ExprArray<u8, 32>>,
+        anonymous: Vec<bool>,
     },
 }
 
@@ -206,7 +255,10 @@ impl<'a> CallLikeExpander<'a> {
                 .min()
                 .unwrap(),
             trait_: Ident::new("SolEvent", Span::call_site()),
-            data: CallLikeExpanderData::Event { selectors },
+            data: CallLikeExpanderData::Event {
+                selectors,
+                anonymous: events.iter().map(|e| e.is_anonymous()).collect(),
+            },
         }
     }
 
@@ -304,7 +356,70 @@ impl<'a> CallLikeExpander<'a> {
 
     fn expand_event(self, attrs: Vec<Attribute>) -> TokenStream {
         // TODO: SolInterface for events
-        self.generate_enum(attrs)
+        let def = self.generate_enum(attrs);
+        let Self {
+            name,
+            variants,
+            data: CallLikeExpanderData::Event { anonymous, .. },
+            ..
+        } = &self
+        else {
+            unreachable!()
+        };
+        let name_s = name.to_string();
+
+        let (anon, named): (Vec<_>, Vec<_>) = variants
+            .iter()
+            .zip(anonymous)
+            .partition(|(_, &anonymous)| anonymous);
+        let anon = anon.into_iter().map(|(v, _)| v);
+        let named = named.into_iter().map(|(v, _)| v).collect::<Vec<_>>();
+
+        let match_signature = (!named.is_empty()).then(|| {
+            quote! {
+                if let Some(&topic0) = topics.first() {
+                    #(
+                        if topic0 == <#named as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH {
+                            return <#named as ::alloy_sol_types::SolEvent>::decode_raw_log(topics, data, validate)
+                                .map(Self::#named);
+                        }
+                    )*
+                }
+            }
+        });
+
+        let try_from_log = super::event::expand_try_from_log(
+            name,
+            quote!(Self::decode_log(log.topics(), &log.data, true)),
+        );
+
+        quote! {
+            #def
+
+            #[automatically_derived]
+            impl #name {
+                /// Decodes a raw log into the event whose signature hash matches the
+                /// first topic.
+                ///
+                /// If no signature hash matches, the anonymous events are tried in
+                /// definition order.
+                pub fn decode_log(
+                    topics: &[::alloy_sol_types::private::B256],
+                    data: &[u8],
+                    validate: bool,
+                ) -> ::alloy_sol_types::Result<Self> {
+                    #match_signature
+                    #(
+                        if let Ok(event) = <#anon as ::alloy_sol_types::SolEvent>::decode_raw_log(topics, data, validate) {
+                            return Ok(Self::#anon(event));
+                        }
+                    )*
+                    Err(::alloy_sol_types::Error::InvalidLog { name: #name_s })
+                }
+            }
+
+            #try_from_log
+        }
     }
 
     fn generate_enum(&self, mut attrs: Vec<Attribute>) -> TokenStream {
@@ -319,7 +434,7 @@ impl<'a> CallLikeExpander<'a> {
             | CallLikeExpanderData::Error { selectors } => {
                 (quote!(#(#selectors,)*), quote!([u8; 4]))
             }
-            CallLikeExpanderData::Event { selectors } => {
+            CallLikeExpanderData::Event { selectors, .. } => {
                 (quote!(#(#selectors,)*), quote!([u8; 32]))
             }
         };
@@ -336,11 +451,15 @@ impl<'a> CallLikeExpander<'a> {
             .zip(types)
             .map(|(v, t)| generate_variant_conversions(name, v, t));
         let methods = variants.iter().zip(types).map(generate_variant_methods);
+        let docs = types.iter().map(|ty| format!("[`{ty}`]"));
 
         quote! {
             #(#attrs)*
             pub enum #name {
-                #(#variants(#types),)*
+                #(
+                    #[doc = #docs]
+                    #variants(#types),
+                )*
             }
 
             #(#conversions)*
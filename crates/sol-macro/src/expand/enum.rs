@@ -42,9 +42,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
     let has_invalid_variant = max != u8::MAX;
     let invalid_variant = has_invalid_variant.then(|| {
-        let comma = (!variants.trailing_punct()).then(syn::token::Comma::default);
         quote! {
-            #comma
             /// Invalid variant.
             ///
             /// This is only used when decoding an out-of-range `u8` value.
@@ -58,6 +56,42 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         quote! { expect("unreachable") }
     };
 
+    let indices = 0..=max;
+    let variant_names = variants.iter().collect::<Vec<_>>();
+    let variant_docs = variants.iter().map(|v| format!("`{name_s}.{v}`"));
+
+    // see `struct.rs`
+    let arbitrary = (cfg!(feature = "arbitrary") && cx.attrs.all_derives.is_some()).then(|| {
+        let arbitrary = quote!(::alloy_sol_types::arbitrary::ArbitrarySolType);
+        quote! {
+            #[automatically_derived]
+            impl #arbitrary for #name {
+                type Strategy = ::alloy_sol_types::private::proptest::strategy::Map<
+                    ::core::ops::RangeInclusive<u8>,
+                    fn(u8) -> Self,
+                >;
+
+                #[inline]
+                fn strategy() -> Self::Strategy {
+                    let from: fn(u8) -> Self = |v| {
+                        <Self as ::core::convert::TryFrom<u8>>::try_from(v).expect("unreachable")
+                    };
+                    ::alloy_sol_types::private::proptest::strategy::Strategy::prop_map(0..=#max, from)
+                }
+
+                #[inline]
+                fn arbitrary(
+                    u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'_>,
+                ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                    let v = u.int_in_range(0..=#max)?;
+                    ::core::result::Result::Ok(
+                        <Self as ::core::convert::TryFrom<u8>>::try_from(v).expect("unreachable")
+                    )
+                }
+            }
+        }
+    });
+
     let uint8 = quote!(::alloy_sol_types::sol_data::Uint<8>);
     let uint8_st = quote!(<#uint8 as ::alloy_sol_types::SolType>);
 
@@ -67,7 +101,10 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
         #[derive(Clone, Copy)]
         #[repr(u8)]
         pub enum #name {
-            #variants
+            #(
+                #[doc = #variant_docs]
+                #variant_names,
+            )*
             #invalid_variant
         }
 
@@ -85,17 +122,15 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
             impl ::core::convert::TryFrom<u8> for #name {
                 type Error = ::alloy_sol_types::Error;
 
-                #[allow(unsafe_code)]
                 #[inline]
                 fn try_from(v: u8) -> ::alloy_sol_types::Result<Self> {
-                    if v <= #max {
-                        ::core::result::Result::Ok(unsafe { ::core::mem::transmute(v) })
-                    } else {
-                        ::core::result::Result::Err(::alloy_sol_types::Error::InvalidEnumValue {
+                    match v {
+                        #(#indices => ::core::result::Result::Ok(Self::#variant_names),)*
+                        _ => ::core::result::Result::Err(::alloy_sol_types::Error::InvalidEnumValue {
                             name: #name_s,
                             value: v,
                             max: #max,
-                        })
+                        }),
                     }
                 }
             }
@@ -137,7 +172,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
 
                 #[inline]
                 fn eip712_data_word(rust: &Self::RustType) -> ::alloy_sol_types::Word {
-                    #uint8_st::eip712_data_word(rust.as_u8())
+                    #uint8_st::eip712_data_word(&(*rust as u8))
                 }
 
                 #[inline]
@@ -151,14 +186,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, enumm: &ItemEnum) -> Result<TokenStream>
                 const COUNT: usize = #count;
             }
 
-            #[automatically_derived]
-            impl #name {
-                #[allow(unsafe_code, clippy::inline_always)]
-                #[inline(always)]
-                fn as_u8(&self) -> &u8 {
-                    unsafe { ::core::mem::transmute::<&Self, &u8>(self) }
-                }
-            }
+            #arbitrary
         };
     };
     Ok(tokens)
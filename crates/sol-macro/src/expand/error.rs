@@ -29,19 +29,19 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, error: &ItemError) -> Result<TokenStream>
     let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, params, true);
 
-    let tokenize_impl = expand_tokenize_func(params.iter());
+    let tokenize_impl = expand_tokenize_func(cx, params.iter());
 
     let signature = cx.error_signature(error);
     let selector = crate::utils::selector(&signature);
 
-    let converts = expand_from_into_tuples(&name.0, params);
-    let fields = expand_fields(params);
+    let converts = expand_from_into_tuples(cx, &name.0, params);
+    let fields = expand_fields(cx, params);
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -1,10 +1,10 @@
 //! [`ItemEvent`] expansion.
 
-use super::{anon_name, expand_tuple_types, expand_type, ExpCtxt};
+use super::{anon_name, expand_tuple_types, expand_type, field_docs, ExpCtxt};
 use crate::expand::ty::expand_event_tokenize_func;
 use ast::{EventParameter, ItemEvent, SolIdent};
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::Result;
 
 /// Expands an [`ItemEvent`]:
@@ -37,7 +37,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
     let topic_list = event.indexed_params().map(expand_event_topic_type);
     let topic_list = first_topic.into_iter().chain(topic_list);
 
-    let (data_tuple, _) = expand_tuple_types(event.non_indexed_params().map(|p| &p.ty));
+    let (data_tuple, _) =
+        expand_tuple_types(event.non_indexed_params().map(|p| expand_type(&p.ty)));
 
     // skip first topic if not anonymous, which is the hash of the signature
     let mut topic_i = !anonymous as usize;
@@ -69,6 +70,23 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         quote! {(Self::SIGNATURE_HASH.into(), #(self.#topic_tuple_names.clone(),)*)}
     };
 
+    let check_signature = (!anonymous).then(|| {
+        quote! {
+            #[inline]
+            fn check_signature(
+                topics: &<Self::TopicList as ::alloy_sol_types::SolType>::RustType,
+            ) -> ::alloy_sol_types::Result<()> {
+                if topics.0 != Self::SIGNATURE_HASH.0 {
+                    return Err(::alloy_sol_types::Error::EventSignatureMismatch {
+                        expected: Self::SIGNATURE_HASH,
+                        got: topics.0.into(),
+                    });
+                }
+                Ok(())
+            }
+        }
+    });
+
     let encode_first_topic =
         (!anonymous).then(|| quote!(::alloy_sol_types::token::WordToken(Self::SIGNATURE_HASH)));
 
@@ -101,11 +119,18 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
         .enumerate()
         .map(|(i, assign)| quote!(out[#i] = #assign;));
 
+    let try_from_log = expand_try_from_log(
+        name,
+        quote!(<Self as ::alloy_sol_types::SolEvent>::decode_log_data(
+            log, true
+        )),
+    );
+
     let tokens = quote! {
         #(#attrs)*
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         pub struct #name {
-            #(pub #fields,)*
+            #(#fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -143,6 +168,8 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     #topics_impl
                 }
 
+                #check_signature
+
                 #[inline]
                 fn encode_topics_raw(
                     &self,
@@ -155,11 +182,60 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, event: &ItemEvent) -> Result<TokenStream>
                     Ok(())
                 }
             }
+
+
+            #try_from_log
         };
     };
     Ok(tokens)
 }
 
+/// Expands the `TryFrom` impls for `LogData` and `Log`, given the expression
+/// which decodes `log: &LogData`.
+pub(super) fn expand_try_from_log(name: &impl ToTokens, decode: TokenStream) -> TokenStream {
+    quote! {
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::LogData> for #name {
+            type Error = ::alloy_sol_types::Error;
+
+            #[inline]
+            fn try_from(log: &::alloy_sol_types::private::LogData) -> ::alloy_sol_types::Result<Self> {
+                #decode
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::LogData> for #name {
+            type Error = ::alloy_sol_types::Error;
+
+            #[inline]
+            fn try_from(log: ::alloy_sol_types::private::LogData) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for #name {
+            type Error = ::alloy_sol_types::Error;
+
+            #[inline]
+            fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::Log> for #name {
+            type Error = ::alloy_sol_types::Error;
+
+            #[inline]
+            fn try_from(log: ::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+    }
+}
+
 fn expand_event_topic_type(param: &EventParameter) -> TokenStream {
     debug_assert!(param.is_indexed());
     if param.is_abi_dynamic() {
@@ -174,16 +250,27 @@ fn expand_event_topic_field(
     param: &EventParameter,
     name: Option<&SolIdent>,
 ) -> TokenStream {
+    let mut decl = param.ty.to_string();
+    if param.is_indexed() {
+        decl.push_str(" indexed");
+    }
+    if let Some(name) = name {
+        decl.push(' ');
+        decl.push_str(&name.to_string());
+    }
+    let docs = field_docs(&param.attrs, decl);
     let name = anon_name((i, name));
 
     if param.indexed_as_hash() {
         quote! {
-            #name: <::alloy_sol_types::sol_data::FixedBytes<32> as ::alloy_sol_types::SolType>::RustType
+            #docs
+            pub #name: <::alloy_sol_types::sol_data::FixedBytes<32> as ::alloy_sol_types::SolType>::RustType
         }
     } else {
         let ty = expand_type(&param.ty);
         quote! {
-            #name: <#ty as ::alloy_sol_types::SolType>::RustType
+            #docs
+            pub #name: <#ty as ::alloy_sol_types::SolType>::RustType
         }
     }
 }
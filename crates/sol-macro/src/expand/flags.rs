@@ -0,0 +1,543 @@
+//! `#[sol(bitflags(...))]` expansion.
+
+use ast::{Item, SolIdent, Type, VariableDefinition};
+use proc_macro2::{Delimiter, Ident, Literal, Spacing, TokenStream, TokenTree};
+use quote::quote;
+use std::{iter::Peekable, num::NonZeroU16};
+use syn::{Error, Result};
+
+/// Expands the `constant` integer variables of a contract or library into a
+/// bitflags-style newtype named `name`:
+///
+/// ```ignore (pseudo-code)
+/// pub struct #name(#rust_ty);
+///
+/// impl #name {
+///     #(pub const #flag: Self = Self(#value);)*
+///
+///     pub fn empty() -> Self { ... }
+///     pub fn all() -> Self { ... }
+///     pub fn contains(&self, other: Self) -> bool { ... }
+///     ...
+/// }
+///
+/// impl BitOr, BitAnd, BitXor, Sub, Not for #name { ... }
+/// ```
+pub(super) fn expand(name: &Ident, contract: &SolIdent, body: &[Item]) -> Result<TokenStream> {
+    let mut bits = None;
+    let mut flags: Vec<(&VariableDefinition, Value)> = Vec::new();
+    for item in body {
+        let Item::Variable(var) = item else { continue };
+        if !var.attributes.has_constant() {
+            continue
+        }
+        let size = match &var.ty {
+            Type::Uint(_, size) => size.map_or(256, NonZeroU16::get),
+            _ => {
+                return Err(Error::new(
+                    var.ty.span(),
+                    "bitflags constants must have an unsigned integer type",
+                ))
+            }
+        };
+        match bits {
+            None => bits = Some(size),
+            Some(bits) if bits != size => {
+                let msg = format!("bitflags constants must all have the same type, `uint{bits}`");
+                return Err(Error::new(var.ty.span(), msg))
+            }
+            Some(_) => {}
+        }
+
+        let Some((_, init)) = &var.initializer else {
+            return Err(Error::new(
+                var.name.span(),
+                "bitflags constants must have a value",
+            ))
+        };
+        let value = Evaluator::new(init.clone(), &flags).eval()?;
+        if value.bits() > size as u32 {
+            let msg = format!("value does not fit in `uint{size}`");
+            return Err(Error::new(var.name.span(), msg))
+        }
+        if flags.iter().any(|(f, _)| f.name == var.name) {
+            return Err(Error::new(var.name.span(), "duplicate flag"))
+        }
+        flags.push((var, value));
+    }
+
+    let Some(bits) = bits else {
+        let msg = format!("`{contract}` has no constants to generate bitflags from");
+        return Err(Error::new(name.span(), msg))
+    };
+    let rust_ty = match bits {
+        0..=8 => quote!(u8),
+        9..=16 => quote!(u16),
+        17..=32 => quote!(u32),
+        33..=64 => quote!(u64),
+        65..=128 => quote!(u128),
+        _ => quote!(::alloy_sol_types::private::U256),
+    };
+
+    let flag_names = flags.iter().map(|(var, _)| &var.name);
+    let consts = flags.iter().map(|(var, value)| {
+        let name = &var.name;
+        let doc = format!("The `{name}` flag, with value `{}`.", value.to_hex());
+        let value = if bits > 128 {
+            let limbs = value.0.iter().map(|&limb| Literal::u64_suffixed(limb));
+            quote!(::alloy_sol_types::private::U256::from_limbs([#(#limbs),*]))
+        } else {
+            let limb = Literal::u128_unsuffixed(value.0[0] as u128 | (value.0[1] as u128) << 64);
+            quote!(#limb)
+        };
+        quote! {
+            #[doc = #doc]
+            pub const #name: Self = Self(#value);
+        }
+    });
+
+    let doc = format!("Set of the flags defined as constants in `{contract}`.");
+    let tokens = quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct #name(#rust_ty);
+
+        #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+        const _: () = {
+            impl #name {
+                #(#consts)*
+
+                /// Returns an empty set of flags.
+                #[inline]
+                pub fn empty() -> Self {
+                    Self::default()
+                }
+
+                /// Returns the set containing all the defined flags.
+                #[inline]
+                pub fn all() -> Self {
+                    Self::empty() #(| Self::#flag_names)*
+                }
+
+                /// Creates a set of flags from raw bits, keeping any bits that do
+                /// not correspond to a defined flag.
+                #[inline]
+                pub const fn from_bits_retain(bits: #rust_ty) -> Self {
+                    Self(bits)
+                }
+
+                /// Creates a set of flags from raw bits, returning `None` if any bit
+                /// does not correspond to a defined flag.
+                #[inline]
+                pub fn from_bits(bits: #rust_ty) -> ::core::option::Option<Self> {
+                    let flags = Self(bits);
+                    if flags.difference(Self::all()).is_empty() {
+                        ::core::option::Option::Some(flags)
+                    } else {
+                        ::core::option::Option::None
+                    }
+                }
+
+                /// Creates a set of flags from raw bits, removing any bits that do
+                /// not correspond to a defined flag.
+                #[inline]
+                pub fn from_bits_truncate(bits: #rust_ty) -> Self {
+                    Self(bits) & Self::all()
+                }
+
+                /// Returns the raw bits of this set of flags.
+                #[inline]
+                pub const fn bits(&self) -> #rust_ty {
+                    self.0
+                }
+
+                /// Returns `true` if no flags are set.
+                #[inline]
+                pub fn is_empty(&self) -> bool {
+                    *self == Self::empty()
+                }
+
+                /// Returns `true` if all the defined flags are set.
+                #[inline]
+                pub fn is_all(&self) -> bool {
+                    Self::all().difference(*self).is_empty()
+                }
+
+                /// Returns `true` if all the flags in `other` are also set in `self`.
+                #[inline]
+                pub fn contains(&self, other: Self) -> bool {
+                    *self & other == other
+                }
+
+                /// Returns `true` if any of the flags in `other` are also set in
+                /// `self`.
+                #[inline]
+                pub fn intersects(&self, other: Self) -> bool {
+                    !(*self & other).is_empty()
+                }
+
+                /// Sets the flags in `other`.
+                #[inline]
+                pub fn insert(&mut self, other: Self) {
+                    *self |= other;
+                }
+
+                /// Clears the flags in `other`.
+                #[inline]
+                pub fn remove(&mut self, other: Self) {
+                    *self -= other;
+                }
+
+                /// Toggles the flags in `other`.
+                #[inline]
+                pub fn toggle(&mut self, other: Self) {
+                    *self ^= other;
+                }
+
+                /// Sets or clears the flags in `other` depending on `value`.
+                #[inline]
+                pub fn set(&mut self, other: Self, value: bool) {
+                    if value {
+                        self.insert(other)
+                    } else {
+                        self.remove(other)
+                    }
+                }
+
+                /// Returns the flags that are set in both `self` and `other`.
+                #[inline]
+                #[must_use]
+                pub fn intersection(self, other: Self) -> Self {
+                    Self(self.0 & other.0)
+                }
+
+                /// Returns the flags that are set in either `self` or `other`.
+                #[inline]
+                #[must_use]
+                pub fn union(self, other: Self) -> Self {
+                    Self(self.0 | other.0)
+                }
+
+                /// Returns the flags that are set in `self` but not in `other`.
+                #[inline]
+                #[must_use]
+                pub fn difference(self, other: Self) -> Self {
+                    Self(self.0 & !other.0)
+                }
+
+                /// Returns the flags that are set in exactly one of `self` and
+                /// `other`.
+                #[inline]
+                #[must_use]
+                pub fn symmetric_difference(self, other: Self) -> Self {
+                    Self(self.0 ^ other.0)
+                }
+
+                /// Returns the defined flags that are not set in `self`.
+                #[inline]
+                #[must_use]
+                pub fn complement(self) -> Self {
+                    Self::all().difference(self)
+                }
+            }
+
+            impl ::core::convert::From<#rust_ty> for #name {
+                #[inline]
+                fn from(bits: #rust_ty) -> Self {
+                    Self(bits)
+                }
+            }
+
+            impl ::core::convert::From<#name> for #rust_ty {
+                #[inline]
+                fn from(flags: #name) -> Self {
+                    flags.0
+                }
+            }
+
+            impl ::core::ops::BitOr for #name {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, other: Self) -> Self {
+                    self.union(other)
+                }
+            }
+
+            impl ::core::ops::BitOrAssign for #name {
+                #[inline]
+                fn bitor_assign(&mut self, other: Self) {
+                    *self = self.union(other);
+                }
+            }
+
+            impl ::core::ops::BitAnd for #name {
+                type Output = Self;
+
+                #[inline]
+                fn bitand(self, other: Self) -> Self {
+                    self.intersection(other)
+                }
+            }
+
+            impl ::core::ops::BitAndAssign for #name {
+                #[inline]
+                fn bitand_assign(&mut self, other: Self) {
+                    *self = self.intersection(other);
+                }
+            }
+
+            impl ::core::ops::BitXor for #name {
+                type Output = Self;
+
+                #[inline]
+                fn bitxor(self, other: Self) -> Self {
+                    self.symmetric_difference(other)
+                }
+            }
+
+            impl ::core::ops::BitXorAssign for #name {
+                #[inline]
+                fn bitxor_assign(&mut self, other: Self) {
+                    *self = self.symmetric_difference(other);
+                }
+            }
+
+            impl ::core::ops::Sub for #name {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, other: Self) -> Self {
+                    self.difference(other)
+                }
+            }
+
+            impl ::core::ops::SubAssign for #name {
+                #[inline]
+                fn sub_assign(&mut self, other: Self) {
+                    *self = self.difference(other);
+                }
+            }
+
+            impl ::core::ops::Not for #name {
+                type Output = Self;
+
+                #[inline]
+                fn not(self) -> Self {
+                    self.complement()
+                }
+            }
+        };
+    };
+    Ok(tokens)
+}
+
+/// A 256-bit unsigned integer, as little-endian limbs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Value([u64; 4]);
+
+impl Value {
+    const ONE: Self = Self([1, 0, 0, 0]);
+
+    /// The number of significant bits.
+    fn bits(&self) -> u32 {
+        self.0
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, &limb)| limb != 0)
+            .map_or(0, |(i, limb)| i as u32 * 64 + 64 - limb.leading_zeros())
+    }
+
+    fn to_hex(self) -> String {
+        let mut s = String::from("0x");
+        let mut limbs = self.0.iter().rev().skip_while(|&&limb| limb == 0);
+        match limbs.next() {
+            Some(first) => s.push_str(&format!("{first:x}")),
+            None => s.push('0'),
+        }
+        for limb in limbs {
+            s.push_str(&format!("{limb:016x}"));
+        }
+        s
+    }
+
+    fn shl(self, n: Self) -> Option<Self> {
+        let n = n.to_u32().filter(|&n| n < 256)?;
+        let (limbs, bits) = ((n / 64) as usize, n % 64);
+        let mut out = [0u64; 4];
+        for i in (limbs..4).rev() {
+            out[i] = self.0[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                out[i] |= self.0[i - limbs - 1] >> (64 - bits);
+            }
+        }
+        // check that no bits were shifted out
+        (out_shr(out, n) == self.0).then_some(Self(out))
+    }
+
+    fn shr(self, n: Self) -> Self {
+        match n.to_u32().filter(|&n| n < 256) {
+            Some(n) => Self(out_shr(self.0, n)),
+            None => Self::default(),
+        }
+    }
+
+    fn to_u32(self) -> Option<u32> {
+        match self.0 {
+            [n, 0, 0, 0] => n.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    fn zip(self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        Self(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+}
+
+fn out_shr(limbs: [u64; 4], n: u32) -> [u64; 4] {
+    let (skip, bits) = ((n / 64) as usize, n % 64);
+    let mut out = [0u64; 4];
+    for i in 0..4 - skip {
+        out[i] = limbs[i + skip] >> bits;
+        if bits > 0 && i + skip + 1 < 4 {
+            out[i] |= limbs[i + skip + 1] << (64 - bits);
+        }
+    }
+    out
+}
+
+/// Evaluates the constant integer expressions used to define flags.
+///
+/// Supports integer literals, references to previously defined flags,
+/// parentheses, and the `**`, `<<`, `>>`, `&`, `^` and `|` operators with
+/// Solidity's precedence.
+struct Evaluator<'a> {
+    tokens: Peekable<proc_macro2::token_stream::IntoIter>,
+    flags: &'a [(&'a VariableDefinition, Value)],
+    span: proc_macro2::Span,
+}
+
+impl<'a> Evaluator<'a> {
+    fn new(tokens: TokenStream, flags: &'a [(&'a VariableDefinition, Value)]) -> Self {
+        let span = tokens
+            .clone()
+            .into_iter()
+            .next()
+            .map_or_else(proc_macro2::Span::call_site, |tt| tt.span());
+        Self {
+            tokens: tokens.into_iter().peekable(),
+            flags,
+            span,
+        }
+    }
+
+    fn eval(mut self) -> Result<Value> {
+        let value = self.binary(0)?;
+        match self.tokens.next() {
+            Some(tt) => Err(Error::new(
+                tt.span(),
+                "unexpected token in bitflags constant",
+            )),
+            None => Ok(value),
+        }
+    }
+
+    /// Parses a binary expression whose operators bind tighter than `min`.
+    fn binary(&mut self, min: u8) -> Result<Value> {
+        let mut lhs = self.unary()?;
+        while let Some((op, prec)) = self.peek_op() {
+            if prec <= min {
+                break
+            }
+            let span = self.span;
+            for _ in 0..op.len() {
+                self.tokens.next();
+            }
+            // `**` is right-associative
+            let rhs = self.binary(if op == "**" { prec - 1 } else { prec })?;
+            lhs = match op {
+                "**" => {
+                    if lhs != Value([2, 0, 0, 0]) {
+                        return Err(Error::new(span, "only powers of 2 are supported"))
+                    }
+                    Value::ONE.shl(rhs)
+                }
+                "<<" => lhs.shl(rhs),
+                ">>" => Some(lhs.shr(rhs)),
+                "&" => Some(lhs.zip(rhs, |a, b| a & b)),
+                "^" => Some(lhs.zip(rhs, |a, b| a ^ b)),
+                "|" => Some(lhs.zip(rhs, |a, b| a | b)),
+                _ => unreachable!(),
+            }
+            .ok_or_else(|| Error::new(span, "bitflags constant overflows `uint256`"))?;
+        }
+        Ok(lhs)
+    }
+
+    fn peek_op(&self) -> Option<(&'static str, u8)> {
+        let mut tokens = self.tokens.clone();
+        let TokenTree::Punct(p) = tokens.next()? else {
+            return None
+        };
+        let next = match tokens.next() {
+            Some(TokenTree::Punct(next)) if p.spacing() == Spacing::Joint => Some(next.as_char()),
+            _ => None,
+        };
+        match (p.as_char(), next) {
+            ('*', Some('*')) => Some(("**", 5)),
+            ('<', Some('<')) => Some(("<<", 4)),
+            ('>', Some('>')) => Some((">>", 4)),
+            ('&', None) => Some(("&", 3)),
+            ('^', None) => Some(("^", 2)),
+            ('|', None) => Some(("|", 1)),
+            _ => None,
+        }
+    }
+
+    fn unary(&mut self) -> Result<Value> {
+        let Some(tt) = self.tokens.next() else {
+            return Err(Error::new(self.span, "unexpected end of bitflags constant"))
+        };
+        self.span = tt.span();
+        match tt {
+            TokenTree::Literal(lit) => parse_literal(&lit),
+            TokenTree::Ident(ident) => self
+                .flags
+                .iter()
+                .find(|(var, _)| var.name.0 == ident)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| {
+                    let msg = format!("`{ident}` is not a previously defined flag");
+                    Error::new(ident.span(), msg)
+                }),
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis => {
+                Evaluator::new(group.stream(), self.flags).eval()
+            }
+            tt => Err(Error::new(
+                tt.span(),
+                "unsupported expression in bitflags constant",
+            )),
+        }
+    }
+}
+
+fn parse_literal(lit: &Literal) -> Result<Value> {
+    let s = lit.to_string().replace('_', "");
+    let err = || Error::new(lit.span(), "expected an integer literal");
+    let value = if let Some(hex) = s.strip_prefix("0x") {
+        if hex.is_empty() || hex.len() > 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(err())
+        }
+        let mut limbs = [0u64; 4];
+        for (i, chunk) in hex.as_bytes().rchunks(16).enumerate() {
+            let chunk = std::str::from_utf8(chunk).unwrap();
+            limbs[i] = u64::from_str_radix(chunk, 16).map_err(|_| err())?;
+        }
+        Value(limbs)
+    } else {
+        let n = s.parse::<u128>().map_err(|_| err())?;
+        Value([n as u64, (n >> 64) as u64, 0, 0])
+    };
+    Ok(value)
+}
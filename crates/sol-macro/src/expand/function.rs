@@ -53,43 +53,43 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, function: &ItemFunction) -> Result<TokenS
     let call_name = cx.call_name(function);
     let return_name = cx.return_name(function);
 
-    let call_fields = expand_fields(arguments);
+    let call_fields = expand_fields(cx, arguments);
     let return_fields = if let Some(returns) = returns {
-        expand_fields(&returns.returns).collect::<Vec<_>>()
+        expand_fields(cx, &returns.returns).collect::<Vec<_>>()
     } else {
         vec![]
     };
 
-    let call_tuple = expand_tuple_types(arguments.types()).0;
+    let call_tuple = expand_tuple_types(arguments.types().map(|ty| cx.expand_field_type(ty))).0;
     let return_tuple = if let Some(returns) = returns {
-        expand_tuple_types(returns.returns.types()).0
+        expand_tuple_types(returns.returns.types().map(|ty| cx.expand_field_type(ty))).0
     } else {
         quote! { () }
     };
 
-    let converts = expand_from_into_tuples(&call_name, arguments);
+    let converts = expand_from_into_tuples(cx, &call_name, arguments);
     let return_converts = returns
         .as_ref()
-        .map(|returns| expand_from_into_tuples(&return_name, &returns.returns))
+        .map(|returns| expand_from_into_tuples(cx, &return_name, &returns.returns))
         .unwrap_or_else(|| expand_from_into_unit(&return_name));
 
     let signature = cx.function_signature(function);
     let selector = crate::utils::selector(&signature);
-    let tokenize_impl = expand_tokenize_func(arguments.iter());
+    let tokenize_impl = expand_tokenize_func(cx, arguments.iter());
 
     let tokens = quote! {
         #(#call_attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #call_name {
-            #(pub #call_fields,)*
+            #(#call_fields,)*
         }
 
         #(#return_attrs)*
         #[allow(non_camel_case_types, non_snake_case)]
         #[derive(Clone)]
         pub struct #return_name {
-            #(pub #return_fields,)*
+            #(#return_fields,)*
         }
 
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
@@ -20,6 +20,7 @@ mod contract;
 mod r#enum;
 mod error;
 mod event;
+mod flags;
 mod function;
 mod r#struct;
 mod udt;
@@ -33,6 +34,17 @@ pub fn expand(mut ast: File) -> Result<TokenStream> {
     ExpCtxt::new(&ast).expand()
 }
 
+/// The [`SolStruct`][crate::SolStruct] derive implementation, given a file
+/// containing only the struct declaration.
+pub fn expand_struct_impls(ast: File) -> Result<TokenStream> {
+    let mut cx = ExpCtxt::new(&ast);
+    cx.visit_file(&ast);
+    match &ast.items[..] {
+        [Item::Struct(s)] => Ok(r#struct::expand_impls(&cx, s)),
+        _ => unreachable!("expected a single struct"),
+    }
+}
+
 struct ExpCtxt<'ast> {
     all_items: Vec<&'ast Item>,
     custom_types: HashMap<SolIdent, Type>,
@@ -86,6 +98,7 @@ impl<'ast> ExpCtxt<'ast> {
     }
 
     fn expand_item(&self, item: &Item) -> Result<TokenStream> {
+        check_item_attributes(item)?;
         match item {
             Item::Contract(contract) => contract::expand(self, contract),
             Item::Enum(enumm) => r#enum::expand(self, enumm),
@@ -391,6 +404,37 @@ impl ExpCtxt<'_> {
         crate::utils::event_selector(self.event_signature(event))
     }
 
+    /// Returns the Rust type that replaces the given Solidity type in generated
+    /// fields, as specified with `#[sol(rename_type(...))]`.
+    fn rust_type_override(&self, ty: &Type) -> Option<&syn::Type> {
+        let overrides = self.attrs.rename_type.as_ref()?;
+        let ty = attr::normalize_type(ty.clone());
+        overrides
+            .iter()
+            .find(|(sol_ty, _)| *sol_ty == ty)
+            .map(|(_, rust_ty)| rust_ty)
+    }
+
+    /// Returns the path to the module which contains the shared Rust types to
+    /// use instead of generating new ones, as specified with
+    /// `#[sol(use_crate_types(...))]` on the item or the whole input.
+    fn crate_types_path<'a>(&'a self, item_attrs: &'a SolAttrs) -> Option<&'a syn::Path> {
+        item_attrs
+            .use_crate_types
+            .as_ref()
+            .or(self.attrs.use_crate_types.as_ref())
+    }
+
+    /// Expands the Solidity type of a generated field, which wraps `ty` in
+    /// `Renamed` if its Rust type is overridden.
+    fn expand_field_type(&self, ty: &Type) -> TokenStream {
+        let sol_ty = expand_type(ty);
+        match self.rust_type_override(ty) {
+            Some(rust_ty) => quote!(::alloy_sol_types::private::Renamed<#sol_ty, #rust_ty>),
+            None => sol_ty,
+        }
+    }
+
     /// Extends `attrs` with all possible derive attributes for the given type
     /// if `#[sol(all_derives)]` was passed.
     ///
@@ -479,22 +523,66 @@ impl ExpCtxt<'_> {
 }
 
 // helper functions
-/// Expands a list of parameters into a list of struct fields.
+/// Rejects `#[sol(...)]` attributes which are only supported on the whole
+/// input, as they would otherwise be silently ignored.
+fn check_item_attributes(item: &Item) -> Result<()> {
+    let attrs = match item {
+        Item::Contract(c) => &c.attrs,
+        Item::Enum(e) => &e.attrs,
+        Item::Error(e) => &e.attrs,
+        Item::Event(e) => &e.attrs,
+        Item::Function(f) => &f.attrs,
+        Item::Struct(s) => &s.attrs,
+        Item::Udt(u) => &u.attrs,
+        Item::Variable(_) | Item::Import(_) | Item::Pragma(_) | Item::Using(_) => return Ok(()),
+    };
+    for attr in attrs {
+        let (sol_attrs, _) = SolAttrs::parse(std::slice::from_ref(attr))?;
+        if sol_attrs.rename_type.is_some() {
+            let msg = "`rename_type` is only supported as an inner attribute, e.g. `#![sol(rename_type(...))]`";
+            return Err(Error::new_spanned(attr, msg))
+        }
+    }
+    Ok(())
+}
+
+/// Expands a list of parameters into a list of public struct fields.
 ///
 /// See [`expand_field`].
-fn expand_fields<P>(params: &Parameters<P>) -> impl Iterator<Item = TokenStream> + '_ {
+fn expand_fields<'a, P>(
+    cx: &'a ExpCtxt<'_>,
+    params: &'a Parameters<P>,
+) -> impl Iterator<Item = TokenStream> + 'a {
     params
         .iter()
         .enumerate()
-        .map(|(i, var)| expand_field(i, &var.ty, var.name.as_ref()))
+        .map(|(i, var)| expand_field(cx, i, var))
 }
 
-/// Expands a single parameter into a struct field.
-fn expand_field(i: usize, ty: &Type, name: Option<&SolIdent>) -> TokenStream {
-    let name = anon_name((i, name));
-    let ty = expand_type(ty);
+/// Expands a single parameter into a public struct field.
+fn expand_field(cx: &ExpCtxt<'_>, i: usize, var: &VariableDeclaration) -> TokenStream {
+    let docs = field_docs(&var.attrs, var);
+    let name = anon_name((i, var.name.as_ref()));
+    if let Some(rust_ty) = cx.rust_type_override(&var.ty) {
+        return quote!(#docs pub #name: #rust_ty)
+    }
+    let ty = expand_type(&var.ty);
     quote! {
-        #name: <#ty as ::alloy_sol_types::SolType>::RustType
+        #docs
+        pub #name: <#ty as ::alloy_sol_types::SolType>::RustType
+    }
+}
+
+/// Returns the doc attributes of a field, or a generated doc containing its
+/// Solidity declaration if there are none, so that the field is always
+/// documented.
+fn field_docs(attrs: &[Attribute], decl: impl std::fmt::Display) -> TokenStream {
+    let mut docs = attr::docs(attrs).peekable();
+    if docs.peek().is_some() {
+        quote!(#(#docs)*)
+    } else {
+        let doc = format!("`{decl}`");
+        quote!(#[doc = #doc])
     }
 }
 
@@ -542,7 +630,11 @@ fn expand_from_into_unit(name: &Ident) -> TokenStream {
 /// Expands `From` impls for a list of types and the corresponding tuple.
 ///
 /// See [`expand_from_into_tuples`].
-fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStream {
+fn expand_from_into_tuples<P>(
+    cx: &ExpCtxt<'_>,
+    name: &Ident,
+    fields: &Parameters<P>,
+) -> TokenStream {
     if fields.is_empty() {
         return expand_from_into_unit(name)
     }
@@ -553,9 +645,12 @@ fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStre
     let idxs = (0..fields.len()).map(syn::Index::from);
 
     let names3 = names.clone();
-    let field_tys = fields.types().map(expand_type);
+    let field_tys = fields
+        .types()
+        .map(|ty| cx.expand_field_type(ty))
+        .collect::<Vec<_>>();
 
-    let (sol_tuple, rust_tuple) = expand_tuple_types(fields.types());
+    let (sol_tuple, rust_tuple) = expand_tuple_types(field_tys.iter());
 
     quote! {
         #[doc(hidden)]
@@ -595,13 +690,13 @@ fn expand_from_into_tuples<P>(name: &Ident, fields: &Parameters<P>) -> TokenStre
 /// Returns
 /// - `(#(#expanded,)*)`
 /// - `(#(<#expanded as ::alloy_sol_types::SolType>::RustType,)*)`
-fn expand_tuple_types<'a, I: IntoIterator<Item = &'a Type>>(
-    types: I,
-) -> (TokenStream, TokenStream) {
+fn expand_tuple_types<I: IntoIterator>(expanded_types: I) -> (TokenStream, TokenStream)
+where
+    I::Item: quote::ToTokens,
+{
     let mut sol_tuple = TokenStream::new();
     let mut rust_tuple = TokenStream::new();
-    for ty in types {
-        let expanded = expand_type(ty);
+    for expanded in expanded_types {
         sol_tuple.extend(quote!(#expanded,));
         rust_tuple.extend(quote!(<#expanded as ::alloy_sol_types::SolType>::RustType,));
     }
@@ -5,9 +5,9 @@ use super::{
 };
 use ast::{Item, ItemStruct, Type, VariableDeclaration};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use std::num::NonZeroU16;
-use syn::Result;
+use syn::{punctuated::Punctuated, Attribute, Result, Token};
 
 /// Expands an [`ItemStruct`]:
 ///
@@ -24,6 +24,11 @@ use syn::Result;
 /// impl EventTopic for #name {
 ///     ...
 /// }
+///
+/// // With the `arbitrary` feature and `#[sol(all_derives)]`
+/// impl ArbitrarySolType for #name {
+///     ...
+/// }
 /// ```
 pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
     let ItemStruct {
@@ -33,23 +38,126 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         ..
     } = s;
 
-    let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
+    let (sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     cx.derives(&mut attrs, fields, true);
 
+    let field_types = fields
+        .iter()
+        .map(|f| cx.expand_field_type(&f.ty))
+        .collect::<Vec<_>>();
+
+    if let Some(path) = cx.crate_types_path(&sol_attrs) {
+        let docs = crate::attr::docs(&attrs);
+        // errors in the layout check point to the item's name
+        let assert_layout = quote_spanned! {name.span()=>
+            #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+            const _: () = {
+                // the shared type must have the declared fields
+                fn _assert_layout<'a>(
+                    tuple: <#name as ::alloy_sol_types::SolStruct>::Tuple<'a>,
+                ) -> (#(#field_types,)*) {
+                    tuple
+                }
+            };
+        };
+        return Ok(quote! {
+            #(#docs)*
+            pub use #path::#name;
+
+            #assert_layout
+        })
+    }
+
+    let impls = expand_impls(cx, s);
+    let arbitrary = expand_arbitrary(cx, s, &attrs);
+    let attrs = attrs.iter();
+    let fields = expand_fields(cx, fields);
+    let tokens = quote! {
+        #(#attrs)*
+        #[allow(non_camel_case_types, non_snake_case)]
+        #[derive(Clone)]
+        pub struct #name {
+            #(#fields),*
+        }
+
+        #impls
+
+        #arbitrary
+    };
+    Ok(tokens)
+}
+
+/// Expands the `ArbitrarySolType` implementation of an [`ItemStruct`], which
+/// maps the strategy of its underlying tuple.
+///
+/// This requires the `arbitrary` feature and `#[sol(all_derives)]`, since
+/// [`proptest`] strategies can only generate `Debug` values. Like the tuple
+/// implementations, it is only emitted for structs of up to 12 fields.
+fn expand_arbitrary(cx: &ExpCtxt<'_>, s: &ItemStruct, attrs: &[Attribute]) -> Option<TokenStream> {
+    let derives_debug = crate::attr::derives(attrs).any(|attr| {
+        attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+            .map_or(false, |paths| {
+                paths.iter().any(|path| path.is_ident("Debug"))
+            })
+    });
+    if !cfg!(feature = "arbitrary")
+        || cx.attrs.all_derives.is_none()
+        || !derives_debug
+        || s.fields.len() > 12
+    {
+        return None
+    }
+
+    let name = &s.name;
+    let tuple = quote!(<#name as ::alloy_sol_types::SolStruct>::Tuple<'static>);
+    let arbitrary = quote!(::alloy_sol_types::arbitrary::ArbitrarySolType);
+    Some(quote! {
+        #[automatically_derived]
+        impl #arbitrary for #name {
+            type Strategy = ::alloy_sol_types::private::proptest::strategy::Map<
+                <#tuple as #arbitrary>::Strategy,
+                fn(<#tuple as ::alloy_sol_types::SolType>::RustType) -> Self,
+            >;
+
+            #[inline]
+            fn strategy() -> Self::Strategy {
+                let new: fn(_) -> Self = <Self as ::alloy_sol_types::SolStruct>::new;
+                ::alloy_sol_types::private::proptest::strategy::Strategy::prop_map(
+                    <#tuple as #arbitrary>::strategy(),
+                    new,
+                )
+            }
+
+            #[inline]
+            fn arbitrary(
+                u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'_>,
+            ) -> ::alloy_sol_types::private::arbitrary::Result<Self> {
+                <#tuple as #arbitrary>::arbitrary(u).map(<Self as ::alloy_sol_types::SolStruct>::new)
+            }
+        }
+    })
+}
+
+/// Expands the trait implementations of an [`ItemStruct`] for an existing Rust
+/// struct with the same name and fields. This is also used by
+/// `#[derive(SolStruct)]`.
+pub(super) fn expand_impls(cx: &ExpCtxt<'_>, s: &ItemStruct) -> TokenStream {
+    let ItemStruct { name, fields, .. } = s;
+
     let (field_types, field_names): (Vec<_>, Vec<_>) = fields
         .iter()
-        .map(|f| (expand_type(&f.ty), f.name.as_ref().unwrap()))
+        .map(|f| (cx.expand_field_type(&f.ty), f.name.as_ref().unwrap()))
         .unzip();
 
     let eip712_encode_type_fns = expand_encode_type_fns(cx, fields, name);
 
-    let tokenize_impl = expand_tokenize_func(fields.iter());
+    let tokenize_impl = expand_tokenize_func(cx, fields.iter());
 
     let encode_data_impl = match fields.len() {
         0 => unreachable!("struct with zero fields"),
         1 => {
             let VariableDeclaration { ty, name, .. } = fields.first().unwrap();
-            let ty = expand_type(ty);
+            let ty = cx.expand_field_type(ty);
             quote!(<#ty as ::alloy_sol_types::SolType>::eip712_data_word(&self.#name).0.to_vec())
         }
         _ => quote! {
@@ -59,19 +167,10 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
         },
     };
 
-    let attrs = attrs.iter();
-    let convert = expand_from_into_tuples(&name.0, fields);
+    let convert = expand_from_into_tuples(cx, &name.0, fields);
     let name_s = name.to_string();
-    let fields = expand_fields(fields);
-
-    let tokens = quote! {
-        #(#attrs)*
-        #[allow(non_camel_case_types, non_snake_case)]
-        #[derive(Clone)]
-        pub struct #name {
-            #(pub #fields),*
-        }
 
+    quote! {
         #[allow(non_camel_case_types, non_snake_case, clippy::style)]
         const _: () = {
             #convert
@@ -97,7 +196,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
 
                 #eip712_encode_type_fns
 
-                fn eip712_encode_data(&self) -> Vec<u8> {
+                fn eip712_encode_data(&self) -> ::alloy_sol_types::private::Vec<u8> {
                     #encode_data_impl
                 }
             }
@@ -113,7 +212,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 }
 
                 #[inline]
-                fn encode_topic_preimage(rust: &Self::RustType, out: &mut Vec<u8>) {
+                fn encode_topic_preimage(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
                     out.reserve(<Self as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust));
                     #(
                         <#field_types as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.#field_names, out);
@@ -124,7 +223,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 fn encode_topic(
                     rust: &Self::RustType
                 ) -> ::alloy_sol_types::token::WordToken {
-                    let mut out = Vec::new();
+                    let mut out = ::alloy_sol_types::private::Vec::new();
                     <Self as ::alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
                     ::alloy_sol_types::token::WordToken(
                         ::alloy_sol_types::private::keccak256(out)
@@ -132,8 +231,7 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, s: &ItemStruct) -> Result<TokenStream> {
                 }
             }
         };
-    };
-    Ok(tokens)
+    }
 }
 
 fn expand_encode_type_fns(
@@ -16,8 +16,8 @@ pub fn expand_type(ty: &Type) -> TokenStream {
 
 /// Expands a [`VariableDeclaration`] into an invocation of its types tokenize
 /// method.
-fn expand_tokenize_statement(var: &VariableDeclaration, i: usize) -> TokenStream {
-    let ty = expand_type(&var.ty);
+fn expand_tokenize_statement(cx: &ExpCtxt<'_>, var: &VariableDeclaration, i: usize) -> TokenStream {
+    let ty = cx.expand_field_type(&var.ty);
     let name = var.name.clone().unwrap_or_else(|| generate_name(i).into());
     quote! {
         <#ty as ::alloy_sol_types::SolType>::tokenize(&self.#name)
@@ -26,11 +26,12 @@ fn expand_tokenize_statement(var: &VariableDeclaration, i: usize) -> TokenStream
 
 /// Expand the tokenization function from an iterator of [`VariableDeclaration`]
 pub fn expand_tokenize_func<'a>(
+    cx: &ExpCtxt<'_>,
     iter: impl Iterator<Item = &'a VariableDeclaration>,
 ) -> TokenStream {
     let statements = iter
         .enumerate()
-        .map(|(i, var)| expand_tokenize_statement(var, i));
+        .map(|(i, var)| expand_tokenize_statement(cx, var, i));
     quote! {
         (#(#statements,)*)
     }
@@ -3,7 +3,7 @@
 use super::{expand_type, ExpCtxt};
 use ast::ItemUdt;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::Result;
 
 pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
@@ -11,18 +11,69 @@ pub(super) fn expand(cx: &ExpCtxt<'_>, udt: &ItemUdt) -> Result<TokenStream> {
         name, ty, attrs, ..
     } = udt;
 
+    let (sol_attrs, attrs) = crate::attr::SolAttrs::parse(attrs)?;
     // TODO: Uncomment after migrating `define_udt!`
-    let _ = cx;
-    // let (_sol_attrs, mut attrs) = crate::attr::SolAttrs::parse(attrs)?;
     // cx.type_derives(&mut attrs, Some(ty), true);
 
     let ty = expand_type(ty);
+
+    if let Some(path) = cx.crate_types_path(&sol_attrs) {
+        let docs = crate::attr::docs(&attrs);
+        // errors in the layout check point to the item's name
+        let assert_layout = quote_spanned! {name.span()=>
+            #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+            const _: () = {
+                // the shared type must have the declared underlying type
+                fn _assert_layout<'a>(
+                    token: <#name as ::alloy_sol_types::SolType>::TokenType<'a>,
+                    rust: <#name as ::alloy_sol_types::SolType>::RustType,
+                ) -> (
+                    <#ty as ::alloy_sol_types::SolType>::TokenType<'a>,
+                    <#ty as ::alloy_sol_types::SolType>::RustType,
+                ) {
+                    (token, rust)
+                }
+            };
+        };
+        return Ok(quote! {
+            #(#docs)*
+            pub use #path::#name;
+
+            #assert_layout
+        })
+    }
+
+    // see `struct.rs`
+    let arbitrary = (cfg!(feature = "arbitrary") && cx.attrs.all_derives.is_some()).then(|| {
+        let arbitrary = quote!(::alloy_sol_types::arbitrary::ArbitrarySolType);
+        quote! {
+            #[automatically_derived]
+            impl #arbitrary for #name {
+                type Strategy = <#ty as #arbitrary>::Strategy;
+
+                #[inline]
+                fn strategy() -> Self::Strategy {
+                    <#ty as #arbitrary>::strategy()
+                }
+
+                #[inline]
+                fn arbitrary(
+                    u: &mut ::alloy_sol_types::private::arbitrary::Unstructured<'_>,
+                ) -> ::alloy_sol_types::private::arbitrary::Result<Self::RustType> {
+                    <#ty as #arbitrary>::arbitrary(u)
+                }
+            }
+        }
+    });
+
     let tokens = quote! {
         ::alloy_sol_types::define_udt! {
             #(#attrs)*
             #name,
             underlying: #ty,
         }
+
+        #arbitrary
     };
     Ok(tokens)
 }
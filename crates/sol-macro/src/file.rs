@@ -0,0 +1,59 @@
+//! Expansion of `sol!` inputs read from files, shared by the golden tests and
+//! `cargo xtask expand`.
+
+use crate::input::SolInput;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use std::{
+    io::{self, Write},
+    path::Path,
+    process::{Command, Stdio},
+};
+use syn::{LitStr, Result};
+
+/// Parses the contents of an input file.
+///
+/// `.json` files are parsed like `sol!(<file stem>, "<contents>")`, and
+/// require the `json` feature. Any other file is parsed like the contents of a
+/// `sol!` invocation.
+pub(crate) fn parse(path: &Path, src: &str) -> Result<SolInput> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let name = Ident::new(&name, Span::call_site());
+            let json = LitStr::new(src, Span::call_site());
+            syn::parse2(quote!(#name, #json))
+        }
+        _ => syn::parse_str(src),
+    }
+}
+
+/// Parses and expands the contents of an input file. See [`parse`].
+pub(crate) fn expand(path: &Path, src: &str) -> Result<TokenStream> {
+    parse(path, src)?.expand()
+}
+
+/// Formats Rust source code with `rustfmt`, using its default configuration.
+///
+/// The `RUSTFMT` environment variable overrides the `rustfmt` executable.
+pub(crate) fn rustfmt(src: &str) -> io::Result<String> {
+    let rustfmt = std::env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into());
+    let mut child = Command::new(rustfmt)
+        .args(["--edition", "2021", "--emit", "stdout"])
+        // don't pick up the repository's configuration
+        .current_dir(std::env::temp_dir())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(src.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!("rustfmt failed:\n{stderr}"),
+        ))
+    }
+    String::from_utf8(output.stdout).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
@@ -0,0 +1,254 @@
+//! Golden-file tests for the macro output.
+//!
+//! Every `tests/golden/<name>.sol` (and, with the `json` feature,
+//! `<name>.json`) file is expanded like the input of `sol!`, and compared
+//! against `tests/golden/<name>.expanded.rs`. This makes changes to the
+//! generated code show up in review, and doubles as a way to inspect exactly
+//! what the macro generates for a given interface.
+//!
+//! The expansion is compared token by token, ignoring formatting (see
+//! [`flatten`]), so the test does not need `rustfmt`, and the files do not
+//! depend on the `rustfmt` version they were written with. Run with
+//! `GOLDEN=overwrite` to write the expanded files, formatted with `rustfmt`,
+//! instead, then review the changes with `git diff`:
+//!
+//! ```sh
+//! GOLDEN=overwrite cargo test -p alloy-sol-macro --all-features golden
+//! ```
+//!
+//! To print the expansion of any other input, use `cargo xtask expand`.
+
+use crate::file;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const EXPANDED: &str = ".expanded.rs";
+
+#[test]
+fn golden() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+    let overwrite = std::env::var_os("GOLDEN").map_or(false, |v| v == "overwrite");
+
+    let mut inputs: Vec<PathBuf> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| !path.to_str().unwrap().ends_with(EXPANDED))
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty(), "no inputs in {}", dir.display());
+
+    let mut failures = String::new();
+    let mut expanded_files = Vec::new();
+    for input in &inputs {
+        let name = input.file_stem().unwrap().to_str().unwrap();
+        if input.extension().map_or(false, |ext| ext == "json") && !cfg!(feature = "json") {
+            continue
+        }
+        let src = fs::read_to_string(input).unwrap();
+        let tokens = file::expand(input, &src)
+            .unwrap_or_else(|e| panic!("failed to expand {}: {e}", input.display()));
+
+        let path = dir.join(format!("{name}{EXPANDED}"));
+        expanded_files.push(path.clone());
+        let expected = fs::read_to_string(&path).unwrap_or_default();
+        let actual = tokens.to_string();
+        let (expected_tokens, actual_tokens) = (flatten(&expected), flatten(&actual));
+        if expected_tokens == actual_tokens {
+            continue
+        }
+        if overwrite {
+            let formatted = file::rustfmt(&actual)
+                .unwrap_or_else(|e| panic!("failed to format {}: {e}", path.display()));
+            fs::write(&path, formatted).unwrap();
+        } else {
+            let _ = writeln!(
+                failures,
+                "{}:\n{}",
+                path.display(),
+                diff(&expected_tokens, &actual_tokens)
+            );
+        }
+    }
+
+    // expanded files whose input was removed
+    for entry in fs::read_dir(&dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.to_str().unwrap().ends_with(EXPANDED) && !expanded_files.contains(&path) {
+            let stem = path.file_name().unwrap().to_str().unwrap();
+            let stem = stem.strip_suffix(EXPANDED).unwrap();
+            if inputs
+                .iter()
+                .any(|input| input.file_stem().unwrap() == stem)
+            {
+                // input skipped because of a disabled feature
+                continue
+            }
+            if overwrite {
+                fs::remove_file(&path).unwrap();
+            } else {
+                let _ = writeln!(failures, "{}: no input file\n", path.display());
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "the macro output does not match the golden files; \
+         rerun with `GOLDEN=overwrite` to update them:\n\n{failures}"
+    );
+}
+
+/// Lexes Rust source code into a flat list of tokens, ignoring what depends
+/// on how the code is formatted: whitespace, the spacing of punctuation, the
+/// form of string literals, trailing commas, and the braces and commas that
+/// `rustfmt` adds or removes around match arm bodies.
+fn flatten(src: &str) -> Vec<String> {
+    fn flatten_into(tokens: TokenStream, out: &mut Vec<String>) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let is_punct = |i: usize, c: char| matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == c);
+        let is_arrow = |i: usize| i > 0 && is_punct(i - 1, '=') && is_punct(i, '>');
+
+        // the comma that ends a match arm is the last one before the next `=>`
+        let mut arm_ends = Vec::new();
+        let mut last_comma = None;
+        for i in 0..tokens.len() {
+            if is_punct(i, ',') {
+                last_comma = Some(i);
+            } else if is_arrow(i) {
+                arm_ends.extend(last_comma.take());
+            }
+        }
+
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                _ if arm_ends.contains(&i) => {}
+                // unwrap braced match arm bodies
+                TokenTree::Group(group)
+                    if i > 0 && is_arrow(i - 1) && group.delimiter() == Delimiter::Brace =>
+                {
+                    flatten_into(group.stream(), out);
+                    if is_punct(i + 1, ',') {
+                        i += 1;
+                    }
+                }
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+                    out.push(open.into());
+                    flatten_into(group.stream(), out);
+                    out.push(close.into());
+                }
+                TokenTree::Punct(punct) => out.push(punct.as_char().into()),
+                // doc comments are lexed as raw strings
+                TokenTree::Literal(lit) => match syn::parse_str::<syn::LitStr>(&lit.to_string()) {
+                    Ok(s) => out.push(format!("{:?}", s.value())),
+                    Err(_) => out.push(lit.to_string()),
+                },
+                token => out.push(token.to_string()),
+            }
+            i += 1;
+        }
+    }
+
+    let tokens: TokenStream = src.parse().unwrap();
+    let mut out = Vec::new();
+    flatten_into(tokens, &mut out);
+    out.retain(|s| !s.is_empty());
+    // trailing commas are added or removed by rustfmt
+    let mut i = 0;
+    while i < out.len() {
+        if out[i] == ","
+            && matches!(
+                out.get(i + 1).map(String::as_str),
+                Some(")" | "]" | "}" | ">")
+            )
+        {
+            out.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Returns a minimal diff of two token lists, one token per line.
+fn diff(a: &[String], b: &[String]) -> String {
+    // common prefix and suffix
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let (a, b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    // longest common subsequence of the rest
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = format!("@@ token {} @@\n", prefix + 1);
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            let _ = writeln!(out, " {}", a[i]);
+            i += 1;
+            j += 1;
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            let _ = writeln!(out, "+{}", b[j]);
+            j += 1;
+        } else {
+            let _ = writeln!(out, "-{}", a[i]);
+            i += 1;
+        }
+    }
+    out
+}
+
+#[test]
+fn flatten_formatting() {
+    assert_eq!(
+        flatten("fn f(a: &&u8) -> ::x::Y {}"),
+        flatten("fn f ( a : & & u8 ) - > :: x :: Y { }")
+    );
+    assert_ne!(flatten("fn f() {}"), flatten("fn g() {}"));
+    assert_eq!(flatten("f(a, b,)"), flatten("f(a, b)"));
+    assert_ne!(flatten("a(b)"), flatten("a[b]"));
+    assert_eq!(
+        flatten("/// a\nfn f() {}"),
+        flatten("#[doc = \" a\"] fn f() {}")
+    );
+    assert_eq!(
+        flatten("match x { A => f(a, b), B => { g() }, C => {} }"),
+        flatten("match x { A => { f(a, b) } B => g(), C => {} }")
+    );
+    assert_ne!(
+        flatten("match x { A => f(a), B => g() }"),
+        flatten("match x { A => f(a), B => h() }")
+    );
+}
+
+#[test]
+fn token_diff() {
+    let diff = |a: &str, b: &str| diff(&flatten(a), &flatten(b));
+    assert_eq!(diff("a b c", "a c d"), "@@ token 2 @@\n-b\n c\n+d\n");
+    assert_eq!(diff("", "a"), "@@ token 1 @@\n+a\n");
+    assert_eq!(diff("a", ""), "@@ token 1 @@\n-a\n");
+}
@@ -99,8 +99,11 @@ impl SolInput {
         {
             #[cfg(feature = "json")]
             {
-                let json = serde_json::from_str(s)
-                    .map_err(|e| Error::new(span, format!("invalid JSON: {e}")))?;
+                // artifacts are a superset of the other supported formats;
+                // bytecode that still has to be linked is ignored
+                let json = serde_json::from_str::<alloy_json_abi::ContractArtifact>(s)
+                    .map_err(|e| Error::new(span, format!("invalid JSON: {e}")))?
+                    .into();
                 let name = name.ok_or_else(|| Error::new(span, "need a name for JSON ABI"))?;
                 Ok(Self {
                     path,
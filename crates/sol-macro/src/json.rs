@@ -431,6 +431,12 @@ mod tests {
     fn expand_test(s: &str, path: &'static str) -> (ast::ItemContract, &'static str) {
         let abi: JsonAbi = serde_json::from_str(s).unwrap();
         let name = Path::new(path).file_stem().unwrap().to_str().unwrap();
+
+        // the rendered Solidity interface must also parse
+        let sol = abi.to_sol(name);
+        let ast: ast::File = syn::parse_str(&sol).unwrap_or_else(|e| panic!("{e}:\n{sol}"));
+        assert!(matches!(&ast.items[..], [Item::Contract(c)] if c.name == name));
+
         let tokens = expand_abi(&id(name), abi).expect("couldn't expand JSON ABI");
         let ast: ast::File = syn::parse2(tokens).expect("couldn't ABI parse back to AST");
         let mut items = ast.items.into_iter();
@@ -19,10 +19,15 @@
 extern crate syn_solidity as ast;
 
 use proc_macro::TokenStream;
-use syn::parse_macro_input;
+use syn::{parse_macro_input, DeriveInput};
 
 mod attr;
+mod derive;
 mod expand;
+#[cfg(test)]
+mod file;
+#[cfg(test)]
+mod golden;
 mod input;
 #[cfg(feature = "json")]
 mod json;
@@ -59,7 +64,8 @@ mod utils;
 /// - a current limitation for certain items is that custom types, like structs,
 ///   must be defined in the same macro scope, otherwise a signature cannot be
 ///   generated at compile time. You can bring them in scope with a [Solidity
-///   type alias](#udvt-and-type-aliases).
+///   type alias](#udvt-and-type-aliases), or share the Rust types between
+///   invocations with `#[sol(use_crate_types(...))]`.
 ///
 /// ## Solidity
 ///
@@ -85,17 +91,53 @@ mod utils;
 /// structs, enums, etc. These can be any Rust attribute, and they will be added
 /// to every Rust item generated from the Solidity item.
 ///
+/// Doc comments can also be written on struct fields, and on function, error
+/// and event parameters. Fields and enum variants without one are documented
+/// with their Solidity declaration. The generated code does not use `unsafe`,
+/// and compiles with `#![forbid(unsafe_code)]` and `#![deny(missing_docs)]`.
+/// It only refers to items from `core` and `alloc` through absolute paths, so
+/// it can also be used in `no_std` crates.
+///
 /// This macro provides the `sol` attribute, which can be used to customize the
 /// generated code. Note that unused attributes are currently silently ignored,
 /// but this may change in the future.
 ///
 /// List of all `#[sol(...)]` supported attributes:
-/// - `all_derives`: adds `#[derive(...)]` attributes to all generated types
+/// - `all_derives`: adds `#[derive(...)]` attributes to all generated types.
+///   With the `arbitrary` feature of `alloy-sol-types`, structs, enums and UDTs
+///   also implement `ArbitrarySolType`
 /// - `bytecode = <hex string literal>`: specifies the creation/init bytecode of
-///   a contract. This will emit a `static` item with the specified bytes.
+///   a contract. This will emit a `static` item with the specified bytes, and a
+///   `deploy_code` function that appends the ABI-encoded constructor arguments
+///   to it.
 /// - `deployed_bytecode = <hex string literal>`: specifies the deployed
 ///   bytecode of a contract. This will emit a `static` item with the specified
 ///   bytes.
+/// - `rename_type(<sol type> = <rust type>, ...)`: only as an inner attribute;
+///   substitutes the given Rust types for Solidity value types in the fields of
+///   generated structs, calls, returns and errors. The Rust type `T` must
+///   implement `Copy`, and convert to and from the Solidity type's Rust
+///   representation `R` through `TryFrom<R> for T` and `TryFrom<T> for R`,
+///   which are also implemented by `From` conversions. Decoded values which do
+///   not fit in `T` fail validation, and cause a panic when decoding without
+///   it. Example: `#![sol(rename_type(bytes32 = MyHash, uint256 = u64))]`
+/// - `use_crate_types(<path>)`: on structs and UDVTs, or as an inner attribute
+///   for all of them; re-exports the type with the same name from the module at
+///   `<path>` instead of generating a new one. This allows sharing types
+///   generated by another `sol!` invocation, or hand-written types implementing
+///   `SolStruct`. The type must still be declared, as its definition is needed
+///   to generate signatures, and the shared type is checked to match it at
+///   compile time. `<path>` should be absolute, e.g. `crate::types`, as it is
+///   also used inside of the modules generated for contracts. Example:
+///   `#![sol(use_crate_types(crate::types))]`
+/// - `bitflags(<name>)`: on contracts and libraries; generates a bitflags-style
+///   Rust type named `<name>` inside the contract's module, with an associated
+///   constant for every `constant` variable and the usual set operations
+///   (`contains`, `insert`, `remove`, `|`, `&`, `^`, `-`, `!`, ...). The
+///   constants must all have the same `uintN` type, and their values may use
+///   integer literals, previously declared flags, and the `**`, `<<`, `>>`,
+///   `&`, `^` and `|` operators. Example: `#[sol(bitflags(Roles))] library
+///   Access { uint8 constant ADMIN = 1 << 0; }`
 ///
 /// ### Structs and enums
 ///
@@ -151,6 +193,9 @@ mod utils;
 /// - functions: `<contract_name>Calls`
 /// - errors: `<contract_name>Errors`
 /// - events: `<contract_name>Events`
+///
+/// The events enum does not implement `SolInterface`, but instead provides a
+/// `decode_log` function which decodes a raw log into the matching event.
 /// ```ignore
 #[doc = include_str!("../doctests/contracts.rs")]
 /// ```
@@ -161,7 +206,11 @@ mod utils;
 /// the [ethers-rs `abigen!` macro](https://docs.rs/ethers/latest/ethers/contract/macro.abigen.html).
 ///
 /// JSON objects containing the `abi`, `evm`, `bytecode`, `deployedBytecode`,
-/// and similar keys are also supported.
+/// and similar keys are also supported, such as Foundry and Hardhat artifacts
+/// and the contracts of the solc standard JSON output. Their bytecode is used
+/// like the [`bytecode` and `deployed_bytecode` attributes](#attributes),
+/// unless it contains unlinked library references, in which case it is
+/// ignored.
 ///
 /// Note that only valid JSON is supported, and not the human-readable ABI
 /// format, also used by `abigen!`. This should instead be easily converted to
@@ -180,3 +229,30 @@ pub fn sol(input: TokenStream) -> TokenStream {
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+/// Derives the [`SolStruct`] and [`EventTopic`] traits for an existing Rust
+/// struct, as if it was declared as a Solidity struct in [`sol!`].
+///
+/// Every field must be annotated with its Solidity type as a string, with
+/// `#[sol(type = "...")]`. The Rust type of the field must be the
+/// [`SolType::RustType`] of that Solidity type, e.g. `U256` for `uint256` or
+/// `Vec<T>` for `T[]`. Custom types refer to other structs which implement
+/// [`SolStruct`], either through this derive or through [`sol!`].
+///
+/// The struct must also implement [`Clone`], and cannot be generic.
+///
+/// [`SolStruct`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolStruct.html
+/// [`EventTopic`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.EventTopic.html
+/// [`SolType::RustType`]: https://docs.rs/alloy-sol-types/latest/alloy_sol_types/trait.SolType.html#associatedtype.RustType
+///
+/// # Examples
+///
+/// ```ignore
+#[doc = include_str!("../doctests/derive.rs")]
+/// ```
+#[proc_macro_derive(SolStruct, attributes(sol))]
+pub fn derive_sol_struct(input: TokenStream) -> TokenStream {
+    derive::expand(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,111 @@
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+pub mod Counter {
+    #[doc = r" The creation / init code of the contract."]
+    pub static BYTECODE: ::alloy_sol_types::private::Bytes =
+        ::alloy_sol_types::private::bytes!("6080604052");
+    #[doc = r" The runtime bytecode of the contract."]
+    pub static DEPLOYED_BYTECODE: ::alloy_sol_types::private::Bytes =
+        ::alloy_sol_types::private::bytes!("6080");
+    #[doc = r" Returns the creation code of the contract followed by the"]
+    #[doc = r" ABI-encoded constructor arguments, which is the input data of the"]
+    #[doc = r" deployment transaction."]
+    pub fn deploy_code(
+        start: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    ) -> ::alloy_sol_types::private::Vec<u8> {
+        let args = < (:: alloy_sol_types :: sol_data :: Uint < 256 > ,) as :: alloy_sol_types :: SolType > :: encode_params (& (start ,)) ;
+        [&BYTECODE[..], &args[..]].concat()
+    }
+    #[derive(Default, Debug, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct incrementCall {}
+    #[derive(Default, Debug, PartialEq, Eq, Hash)]
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct incrementReturn {
+        #[doc = "`uint256`"]
+        pub _0: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = ();
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = ();
+            impl From<()> for incrementCall {
+                #[inline]
+                fn from((): ()) -> Self {
+                    Self {}
+                }
+            }
+            impl From<incrementCall> for () {
+                #[inline]
+                fn from(incrementCall {}: incrementCall) {}
+            }
+            impl ::alloy_sol_types::Encodable<()> for incrementCall {
+                #[inline]
+                fn to_tokens(&self) {}
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<incrementReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: incrementReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for incrementReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for incrementReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Uint<256>,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for incrementCall {
+            type Arguments<'a> = ();
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = incrementReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "increment()";
+            const SELECTOR: [u8; 4] = [208u8, 157u8, 224u8, 138u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                ()
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+}
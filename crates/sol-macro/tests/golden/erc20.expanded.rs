@@ -0,0 +1,1464 @@
+#[doc = " The ERC-20 token standard."]
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+pub mod IERC20 {
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    pub struct Transfer {
+        #[doc = "`address indexed from`"]
+        pub from: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`address indexed to`"]
+        pub to: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`uint256 value`"]
+        pub value: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        impl ::alloy_sol_types::SolEvent for Transfer {
+            type DataTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type DataToken<'a> = <Self::DataTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type TopicList = (
+                ::alloy_sol_types::sol_data::FixedBytes<32>,
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+            );
+            const SIGNATURE: &'static str = "Transfer(address,address,uint256)";
+            const SIGNATURE_HASH: ::alloy_sol_types::private::B256 =
+                ::alloy_sol_types::private::B256::new([
+                    221u8, 242u8, 82u8, 173u8, 27u8, 226u8, 200u8, 155u8, 105u8, 194u8, 176u8,
+                    104u8, 252u8, 55u8, 141u8, 170u8, 149u8, 43u8, 167u8, 241u8, 99u8, 196u8,
+                    161u8, 22u8, 40u8, 245u8, 90u8, 77u8, 245u8, 35u8, 179u8, 239u8,
+                ]);
+            const ANONYMOUS: bool = false;
+            #[allow(unused_variables)]
+            #[inline]
+            fn new(
+                topics: <Self::TopicList as ::alloy_sol_types::SolType>::RustType,
+                data: <Self::DataTuple<'_> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                Self {
+                    from: topics.1,
+                    to: topics.2,
+                    value: data.0,
+                }
+            }
+            #[inline]
+            fn tokenize_body(&self) -> Self::DataToken<'_> {
+                (< :: alloy_sol_types :: sol_data :: Uint < 256 > as :: alloy_sol_types :: SolType > :: tokenize (& self . value) ,)
+            }
+            #[inline]
+            fn topics(&self) -> <Self::TopicList as ::alloy_sol_types::SolType>::RustType {
+                (
+                    Self::SIGNATURE_HASH.into(),
+                    self.from.clone(),
+                    self.to.clone(),
+                )
+            }
+            #[inline]
+            fn check_signature(
+                topics: &<Self::TopicList as ::alloy_sol_types::SolType>::RustType,
+            ) -> ::alloy_sol_types::Result<()> {
+                if topics.0 != Self::SIGNATURE_HASH.0 {
+                    return Err(::alloy_sol_types::Error::EventSignatureMismatch {
+                        expected: Self::SIGNATURE_HASH,
+                        got: topics.0.into(),
+                    });
+                }
+                Ok(())
+            }
+            #[inline]
+            fn encode_topics_raw(
+                &self,
+                out: &mut [::alloy_sol_types::token::WordToken],
+            ) -> ::alloy_sol_types::Result<()> {
+                if out.len() < <Self::TopicList as ::alloy_sol_types::TopicList>::COUNT {
+                    return Err(::alloy_sol_types::Error::Overrun);
+                }
+                out[0usize] = ::alloy_sol_types::token::WordToken(Self::SIGNATURE_HASH);
+                out [1usize] = < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: encode_topic (& self . from) ;
+                out [2usize] = < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: encode_topic (& self . to) ;
+                Ok(())
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::LogData> for Transfer {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(
+                log: &::alloy_sol_types::private::LogData,
+            ) -> ::alloy_sol_types::Result<Self> {
+                <Self as ::alloy_sol_types::SolEvent>::decode_log_data(log, true)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::LogData> for Transfer {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(
+                log: ::alloy_sol_types::private::LogData,
+            ) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for Transfer {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::Log> for Transfer {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(log: ::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    pub struct Approval {
+        #[doc = "`address indexed owner`"]
+        pub owner: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`address indexed spender`"]
+        pub spender: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`uint256 value`"]
+        pub value: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        impl ::alloy_sol_types::SolEvent for Approval {
+            type DataTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type DataToken<'a> = <Self::DataTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type TopicList = (
+                ::alloy_sol_types::sol_data::FixedBytes<32>,
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+            );
+            const SIGNATURE: &'static str = "Approval(address,address,uint256)";
+            const SIGNATURE_HASH: ::alloy_sol_types::private::B256 =
+                ::alloy_sol_types::private::B256::new([
+                    140u8, 91u8, 225u8, 229u8, 235u8, 236u8, 125u8, 91u8, 209u8, 79u8, 113u8, 66u8,
+                    125u8, 30u8, 132u8, 243u8, 221u8, 3u8, 20u8, 192u8, 247u8, 178u8, 41u8, 30u8,
+                    91u8, 32u8, 10u8, 200u8, 199u8, 195u8, 185u8, 37u8,
+                ]);
+            const ANONYMOUS: bool = false;
+            #[allow(unused_variables)]
+            #[inline]
+            fn new(
+                topics: <Self::TopicList as ::alloy_sol_types::SolType>::RustType,
+                data: <Self::DataTuple<'_> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                Self {
+                    owner: topics.1,
+                    spender: topics.2,
+                    value: data.0,
+                }
+            }
+            #[inline]
+            fn tokenize_body(&self) -> Self::DataToken<'_> {
+                (< :: alloy_sol_types :: sol_data :: Uint < 256 > as :: alloy_sol_types :: SolType > :: tokenize (& self . value) ,)
+            }
+            #[inline]
+            fn topics(&self) -> <Self::TopicList as ::alloy_sol_types::SolType>::RustType {
+                (
+                    Self::SIGNATURE_HASH.into(),
+                    self.owner.clone(),
+                    self.spender.clone(),
+                )
+            }
+            #[inline]
+            fn check_signature(
+                topics: &<Self::TopicList as ::alloy_sol_types::SolType>::RustType,
+            ) -> ::alloy_sol_types::Result<()> {
+                if topics.0 != Self::SIGNATURE_HASH.0 {
+                    return Err(::alloy_sol_types::Error::EventSignatureMismatch {
+                        expected: Self::SIGNATURE_HASH,
+                        got: topics.0.into(),
+                    });
+                }
+                Ok(())
+            }
+            #[inline]
+            fn encode_topics_raw(
+                &self,
+                out: &mut [::alloy_sol_types::token::WordToken],
+            ) -> ::alloy_sol_types::Result<()> {
+                if out.len() < <Self::TopicList as ::alloy_sol_types::TopicList>::COUNT {
+                    return Err(::alloy_sol_types::Error::Overrun);
+                }
+                out[0usize] = ::alloy_sol_types::token::WordToken(Self::SIGNATURE_HASH);
+                out [1usize] = < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: encode_topic (& self . owner) ;
+                out [2usize] = < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: encode_topic (& self . spender) ;
+                Ok(())
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::LogData> for Approval {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(
+                log: &::alloy_sol_types::private::LogData,
+            ) -> ::alloy_sol_types::Result<Self> {
+                <Self as ::alloy_sol_types::SolEvent>::decode_log_data(log, true)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::LogData> for Approval {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(
+                log: ::alloy_sol_types::private::LogData,
+            ) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for Approval {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::alloy_sol_types::private::Log> for Approval {
+            type Error = ::alloy_sol_types::Error;
+            #[inline]
+            fn try_from(log: ::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+                Self::try_from(&log.data)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct totalSupplyCall {}
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct totalSupplyReturn {
+        #[doc = "`uint256`"]
+        pub _0: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = ();
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = ();
+            impl From<()> for totalSupplyCall {
+                #[inline]
+                fn from((): ()) -> Self {
+                    Self {}
+                }
+            }
+            impl From<totalSupplyCall> for () {
+                #[inline]
+                fn from(totalSupplyCall {}: totalSupplyCall) {}
+            }
+            impl ::alloy_sol_types::Encodable<()> for totalSupplyCall {
+                #[inline]
+                fn to_tokens(&self) {}
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<totalSupplyReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: totalSupplyReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for totalSupplyReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for totalSupplyReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Uint<256>,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for totalSupplyCall {
+            type Arguments<'a> = ();
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = totalSupplyReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "totalSupply()";
+            const SELECTOR: [u8; 4] = [24u8, 22u8, 13u8, 221u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                ()
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct balanceOfCall {
+        #[doc = "`address account`"]
+        pub account: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct balanceOfReturn {
+        #[doc = "`uint256`"]
+        pub _0: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Address,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> =
+                (<::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,);
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<balanceOfCall> for UnderlyingRustTuple<'_> {
+                fn from(value: balanceOfCall) -> Self {
+                    (value.account,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for balanceOfCall {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { account: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for balanceOfCall {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Address,
+                    >::to_tokens(&self.account),)
+                }
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<balanceOfReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: balanceOfReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for balanceOfReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for balanceOfReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Uint<256>,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for balanceOfCall {
+            type Arguments<'a> = (::alloy_sol_types::sol_data::Address,);
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = balanceOfReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "balanceOf(address)";
+            const SELECTOR: [u8; 4] = [112u8, 160u8, 130u8, 49u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                (
+                    <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::tokenize(
+                        &self.account,
+                    ),
+                )
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct transferCall {
+        #[doc = "`address to`"]
+        pub to: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`uint256 amount`"]
+        pub amount:
+            <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct transferReturn {
+        #[doc = "`bool`"]
+        pub _0: <::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<transferCall> for UnderlyingRustTuple<'_> {
+                fn from(value: transferCall) -> Self {
+                    (value.to, value.amount)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for transferCall {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self {
+                        to: tuple.0,
+                        amount: tuple.1,
+                    }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for transferCall {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (:: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . to) , :: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Uint < 256 > > :: to_tokens (& self . amount) ,)
+                }
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> =
+                (<::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,);
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<transferReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: transferReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for transferReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for transferReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Bool,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for transferCall {
+            type Arguments<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = transferReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "transfer(address,uint256)";
+            const SELECTOR: [u8; 4] = [169u8, 5u8, 156u8, 187u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                (< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: tokenize (& self . to) , < :: alloy_sol_types :: sol_data :: Uint < 256 > as :: alloy_sol_types :: SolType > :: tokenize (& self . amount) ,)
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct allowanceCall {
+        #[doc = "`address owner`"]
+        pub owner: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`address spender`"]
+        pub spender: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct allowanceReturn {
+        #[doc = "`uint256`"]
+        pub _0: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+            );
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<allowanceCall> for UnderlyingRustTuple<'_> {
+                fn from(value: allowanceCall) -> Self {
+                    (value.owner, value.spender)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for allowanceCall {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self {
+                        owner: tuple.0,
+                        spender: tuple.1,
+                    }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for allowanceCall {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (:: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . owner) , :: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . spender) ,)
+                }
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<allowanceReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: allowanceReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for allowanceReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for allowanceReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Uint<256>,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for allowanceCall {
+            type Arguments<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+            );
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = allowanceReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "allowance(address,address)";
+            const SELECTOR: [u8; 4] = [221u8, 98u8, 237u8, 62u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                (
+                    <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::tokenize(
+                        &self.owner,
+                    ),
+                    <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::tokenize(
+                        &self.spender,
+                    ),
+                )
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct approveCall {
+        #[doc = "`address spender`"]
+        pub spender: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`uint256 amount`"]
+        pub amount:
+            <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct approveReturn {
+        #[doc = "`bool`"]
+        pub _0: <::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<approveCall> for UnderlyingRustTuple<'_> {
+                fn from(value: approveCall) -> Self {
+                    (value.spender, value.amount)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for approveCall {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self {
+                        spender: tuple.0,
+                        amount: tuple.1,
+                    }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for approveCall {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (:: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . spender) , :: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Uint < 256 > > :: to_tokens (& self . amount) ,)
+                }
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> =
+                (<::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,);
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<approveReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: approveReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for approveReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for approveReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Bool,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for approveCall {
+            type Arguments<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = approveReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "approve(address,uint256)";
+            const SELECTOR: [u8; 4] = [9u8, 94u8, 167u8, 179u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                (< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: tokenize (& self . spender) , < :: alloy_sol_types :: sol_data :: Uint < 256 > as :: alloy_sol_types :: SolType > :: tokenize (& self . amount) ,)
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct transferFromCall {
+        #[doc = "`address from`"]
+        pub from: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`address to`"]
+        pub to: <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+        #[doc = "`uint256 amount`"]
+        pub amount:
+            <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case)]
+    #[derive(Clone)]
+    pub struct transferFromReturn {
+        #[doc = "`bool`"]
+        pub _0: <::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,
+    }
+    #[allow(non_camel_case_types, non_snake_case, clippy::style)]
+    const _: () = {
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> = (
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+                <::alloy_sol_types::sol_data::Address as ::alloy_sol_types::SolType>::RustType,
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+            );
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<transferFromCall> for UnderlyingRustTuple<'_> {
+                fn from(value: transferFromCall) -> Self {
+                    (value.from, value.to, value.amount)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for transferFromCall {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self {
+                        from: tuple.0,
+                        to: tuple.1,
+                        amount: tuple.2,
+                    }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for transferFromCall {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (:: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . from) , :: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Address > :: to_tokens (& self . to) , :: alloy_sol_types :: Encodable :: < :: alloy_sol_types :: sol_data :: Uint < 256 > > :: to_tokens (& self . amount) ,)
+                }
+            }
+        }
+        {
+            #[doc(hidden)]
+            type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            #[doc(hidden)]
+            type UnderlyingRustTuple<'a> =
+                (<::alloy_sol_types::sol_data::Bool as ::alloy_sol_types::SolType>::RustType,);
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<transferFromReturn> for UnderlyingRustTuple<'_> {
+                fn from(value: transferFromReturn) -> Self {
+                    (value._0,)
+                }
+            }
+            #[automatically_derived]
+            #[doc(hidden)]
+            impl ::core::convert::From<UnderlyingRustTuple<'_>> for transferFromReturn {
+                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                    Self { _0: tuple.0 }
+                }
+            }
+            #[automatically_derived]
+            impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for transferFromReturn {
+                fn to_tokens(
+                    &self,
+                ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_>
+                {
+                    (::alloy_sol_types::Encodable::<
+                        ::alloy_sol_types::sol_data::Bool,
+                    >::to_tokens(&self._0),)
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::SolCall for transferFromCall {
+            type Arguments<'a> = (
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Address,
+                ::alloy_sol_types::sol_data::Uint<256>,
+            );
+            type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            type Return = transferFromReturn;
+            type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Bool,);
+            type ReturnToken<'a> =
+                <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+            const SIGNATURE: &'static str = "transferFrom(address,address,uint256)";
+            const SELECTOR: [u8; 4] = [35u8, 184u8, 114u8, 221u8];
+            fn new<'a>(
+                tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType,
+            ) -> Self {
+                tuple.into()
+            }
+            fn tokenize(&self) -> Self::Token<'_> {
+                (< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: tokenize (& self . from) , < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: tokenize (& self . to) , < :: alloy_sol_types :: sol_data :: Uint < 256 > as :: alloy_sol_types :: SolType > :: tokenize (& self . amount) ,)
+            }
+            fn decode_returns(
+                data: &[u8],
+                validate: bool,
+            ) -> ::alloy_sol_types::Result<Self::Return> {
+                <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                    .map(Into::into)
+            }
+        }
+    };
+    #[doc = "Container for all the `IERC20` function calls."]
+    pub enum IERC20Calls {
+        #[doc = "[`totalSupplyCall`]"]
+        totalSupply(totalSupplyCall),
+        #[doc = "[`balanceOfCall`]"]
+        balanceOf(balanceOfCall),
+        #[doc = "[`transferCall`]"]
+        transfer(transferCall),
+        #[doc = "[`allowanceCall`]"]
+        allowance(allowanceCall),
+        #[doc = "[`approveCall`]"]
+        approve(approveCall),
+        #[doc = "[`transferFromCall`]"]
+        transferFrom(transferFromCall),
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<totalSupplyCall> for IERC20Calls {
+        #[inline]
+        fn from(value: totalSupplyCall) -> Self {
+            Self::totalSupply(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for totalSupplyCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::totalSupply(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<balanceOfCall> for IERC20Calls {
+        #[inline]
+        fn from(value: balanceOfCall) -> Self {
+            Self::balanceOf(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for balanceOfCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::balanceOf(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<transferCall> for IERC20Calls {
+        #[inline]
+        fn from(value: transferCall) -> Self {
+            Self::transfer(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for transferCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::transfer(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<allowanceCall> for IERC20Calls {
+        #[inline]
+        fn from(value: allowanceCall) -> Self {
+            Self::allowance(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for allowanceCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::allowance(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<approveCall> for IERC20Calls {
+        #[inline]
+        fn from(value: approveCall) -> Self {
+            Self::approve(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for approveCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::approve(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<transferFromCall> for IERC20Calls {
+        #[inline]
+        fn from(value: transferFromCall) -> Self {
+            Self::transferFrom(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Calls> for transferFromCall {
+        type Error = IERC20Calls;
+        #[inline]
+        fn try_from(value: IERC20Calls) -> ::core::result::Result<Self, IERC20Calls> {
+            match value {
+                IERC20Calls::transferFrom(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl IERC20Calls {
+        #[doc = r" All the selectors of this enum."]
+        #[doc = r""]
+        #[doc = r" Note that the selectors might not be in the same order as the"]
+        #[doc = r" variants, as they are sorted instead of ordered by definition."]
+        pub const SELECTORS: &'static [[u8; 4]] = &[
+            [9u8, 94u8, 167u8, 179u8],
+            [24u8, 22u8, 13u8, 221u8],
+            [35u8, 184u8, 114u8, 221u8],
+            [112u8, 160u8, 130u8, 49u8],
+            [169u8, 5u8, 156u8, 187u8],
+            [221u8, 98u8, 237u8, 62u8],
+        ];
+        #[doc = "Returns `true` if `self` matches [`totalSupply`](Self::totalSupply)."]
+        #[inline]
+        pub const fn is_total_supply(&self) -> bool {
+            ::core::matches!(self, Self::totalSupply(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`totalSupplyCall`] if `self` matches [`totalSupply`](Self::totalSupply)."]
+        #[inline]
+        pub const fn as_total_supply(&self) -> ::core::option::Option<&totalSupplyCall> {
+            match self {
+                Self::totalSupply(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`totalSupplyCall`] if `self` matches [`totalSupply`](Self::totalSupply)."]
+        #[inline]
+        pub fn as_total_supply_mut(&mut self) -> ::core::option::Option<&mut totalSupplyCall> {
+            match self {
+                Self::totalSupply(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`balanceOf`](Self::balanceOf)."]
+        #[inline]
+        pub const fn is_balance_of(&self) -> bool {
+            ::core::matches!(self, Self::balanceOf(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`balanceOfCall`] if `self` matches [`balanceOf`](Self::balanceOf)."]
+        #[inline]
+        pub const fn as_balance_of(&self) -> ::core::option::Option<&balanceOfCall> {
+            match self {
+                Self::balanceOf(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`balanceOfCall`] if `self` matches [`balanceOf`](Self::balanceOf)."]
+        #[inline]
+        pub fn as_balance_of_mut(&mut self) -> ::core::option::Option<&mut balanceOfCall> {
+            match self {
+                Self::balanceOf(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`transfer`](Self::transfer)."]
+        #[inline]
+        pub const fn is_transfer(&self) -> bool {
+            ::core::matches!(self, Self::transfer(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`transferCall`] if `self` matches [`transfer`](Self::transfer)."]
+        #[inline]
+        pub const fn as_transfer(&self) -> ::core::option::Option<&transferCall> {
+            match self {
+                Self::transfer(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`transferCall`] if `self` matches [`transfer`](Self::transfer)."]
+        #[inline]
+        pub fn as_transfer_mut(&mut self) -> ::core::option::Option<&mut transferCall> {
+            match self {
+                Self::transfer(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`allowance`](Self::allowance)."]
+        #[inline]
+        pub const fn is_allowance(&self) -> bool {
+            ::core::matches!(self, Self::allowance(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`allowanceCall`] if `self` matches [`allowance`](Self::allowance)."]
+        #[inline]
+        pub const fn as_allowance(&self) -> ::core::option::Option<&allowanceCall> {
+            match self {
+                Self::allowance(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`allowanceCall`] if `self` matches [`allowance`](Self::allowance)."]
+        #[inline]
+        pub fn as_allowance_mut(&mut self) -> ::core::option::Option<&mut allowanceCall> {
+            match self {
+                Self::allowance(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`approve`](Self::approve)."]
+        #[inline]
+        pub const fn is_approve(&self) -> bool {
+            ::core::matches!(self, Self::approve(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`approveCall`] if `self` matches [`approve`](Self::approve)."]
+        #[inline]
+        pub const fn as_approve(&self) -> ::core::option::Option<&approveCall> {
+            match self {
+                Self::approve(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`approveCall`] if `self` matches [`approve`](Self::approve)."]
+        #[inline]
+        pub fn as_approve_mut(&mut self) -> ::core::option::Option<&mut approveCall> {
+            match self {
+                Self::approve(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`transferFrom`](Self::transferFrom)."]
+        #[inline]
+        pub const fn is_transfer_from(&self) -> bool {
+            ::core::matches!(self, Self::transferFrom(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`transferFromCall`] if `self` matches [`transferFrom`](Self::transferFrom)."]
+        #[inline]
+        pub const fn as_transfer_from(&self) -> ::core::option::Option<&transferFromCall> {
+            match self {
+                Self::transferFrom(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`transferFromCall`] if `self` matches [`transferFrom`](Self::transferFrom)."]
+        #[inline]
+        pub fn as_transfer_from_mut(&mut self) -> ::core::option::Option<&mut transferFromCall> {
+            match self {
+                Self::transferFrom(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolInterface for IERC20Calls {
+        const NAME: &'static str = "IERC20Calls";
+        const MIN_DATA_LENGTH: usize = 0usize;
+        const COUNT: usize = 6usize;
+        #[inline]
+        fn selector(&self) -> [u8; 4] {
+            match self {
+                Self::totalSupply(_) => <totalSupplyCall as ::alloy_sol_types::SolCall>::SELECTOR,
+                Self::balanceOf(_) => <balanceOfCall as ::alloy_sol_types::SolCall>::SELECTOR,
+                Self::transfer(_) => <transferCall as ::alloy_sol_types::SolCall>::SELECTOR,
+                Self::allowance(_) => <allowanceCall as ::alloy_sol_types::SolCall>::SELECTOR,
+                Self::approve(_) => <approveCall as ::alloy_sol_types::SolCall>::SELECTOR,
+                Self::transferFrom(_) => <transferFromCall as ::alloy_sol_types::SolCall>::SELECTOR,
+            }
+        }
+        #[inline]
+        fn selector_at(i: usize) -> Option<[u8; 4]> {
+            Self::SELECTORS.get(i).copied()
+        }
+        #[inline]
+        fn type_check(selector: [u8; 4]) -> ::alloy_sol_types::Result<()> {
+            match selector {
+                <totalSupplyCall as ::alloy_sol_types::SolCall>::SELECTOR
+                | <balanceOfCall as ::alloy_sol_types::SolCall>::SELECTOR
+                | <transferCall as ::alloy_sol_types::SolCall>::SELECTOR
+                | <allowanceCall as ::alloy_sol_types::SolCall>::SELECTOR
+                | <approveCall as ::alloy_sol_types::SolCall>::SELECTOR
+                | <transferFromCall as ::alloy_sol_types::SolCall>::SELECTOR => Ok(()),
+                s => ::core::result::Result::Err(::alloy_sol_types::Error::unknown_selector(
+                    Self::NAME,
+                    s,
+                )),
+            }
+        }
+        #[inline]
+        fn decode_raw(
+            selector: [u8; 4],
+            data: &[u8],
+            validate: bool,
+        ) -> ::alloy_sol_types::Result<Self> {
+            match selector {
+                <totalSupplyCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <totalSupplyCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::totalSupply)
+                }
+                <balanceOfCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <balanceOfCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::balanceOf)
+                }
+                <transferCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <transferCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::transfer)
+                }
+                <allowanceCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <allowanceCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::allowance)
+                }
+                <approveCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <approveCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::approve)
+                }
+                <transferFromCall as ::alloy_sol_types::SolCall>::SELECTOR => {
+                    <transferFromCall as ::alloy_sol_types::SolCall>::decode_raw(data, validate)
+                        .map(Self::transferFrom)
+                }
+                s => ::core::result::Result::Err(::alloy_sol_types::Error::unknown_selector(
+                    Self::NAME,
+                    s,
+                )),
+            }
+        }
+        #[inline]
+        fn encoded_size(&self) -> usize {
+            match self {
+                Self::totalSupply(inner) => {
+                    <totalSupplyCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+                Self::balanceOf(inner) => {
+                    <balanceOfCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+                Self::transfer(inner) => {
+                    <transferCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+                Self::allowance(inner) => {
+                    <allowanceCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+                Self::approve(inner) => {
+                    <approveCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+                Self::transferFrom(inner) => {
+                    <transferFromCall as ::alloy_sol_types::SolCall>::encoded_size(inner)
+                }
+            }
+        }
+        #[inline]
+        fn encode_raw(&self, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+            match self {
+                Self::totalSupply(inner) => {
+                    <totalSupplyCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+                Self::balanceOf(inner) => {
+                    <balanceOfCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+                Self::transfer(inner) => {
+                    <transferCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+                Self::allowance(inner) => {
+                    <allowanceCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+                Self::approve(inner) => {
+                    <approveCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+                Self::transferFrom(inner) => {
+                    <transferFromCall as ::alloy_sol_types::SolCall>::encode_raw(inner, out)
+                }
+            }
+        }
+    }
+    #[doc = "Container for all the `IERC20` events."]
+    pub enum IERC20Events {
+        #[doc = "[`Transfer`]"]
+        Transfer(Transfer),
+        #[doc = "[`Approval`]"]
+        Approval(Approval),
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<Transfer> for IERC20Events {
+        #[inline]
+        fn from(value: Transfer) -> Self {
+            Self::Transfer(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Events> for Transfer {
+        type Error = IERC20Events;
+        #[inline]
+        fn try_from(value: IERC20Events) -> ::core::result::Result<Self, IERC20Events> {
+            match value {
+                IERC20Events::Transfer(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::From<Approval> for IERC20Events {
+        #[inline]
+        fn from(value: Approval) -> Self {
+            Self::Approval(value)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<IERC20Events> for Approval {
+        type Error = IERC20Events;
+        #[inline]
+        fn try_from(value: IERC20Events) -> ::core::result::Result<Self, IERC20Events> {
+            match value {
+                IERC20Events::Approval(value) => ::core::result::Result::Ok(value),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl IERC20Events {
+        #[doc = r" All the selectors of this enum."]
+        #[doc = r""]
+        #[doc = r" Note that the selectors might not be in the same order as the"]
+        #[doc = r" variants, as they are sorted instead of ordered by definition."]
+        pub const SELECTORS: &'static [[u8; 32]] = &[
+            [
+                140u8, 91u8, 225u8, 229u8, 235u8, 236u8, 125u8, 91u8, 209u8, 79u8, 113u8, 66u8,
+                125u8, 30u8, 132u8, 243u8, 221u8, 3u8, 20u8, 192u8, 247u8, 178u8, 41u8, 30u8, 91u8,
+                32u8, 10u8, 200u8, 199u8, 195u8, 185u8, 37u8,
+            ],
+            [
+                221u8, 242u8, 82u8, 173u8, 27u8, 226u8, 200u8, 155u8, 105u8, 194u8, 176u8, 104u8,
+                252u8, 55u8, 141u8, 170u8, 149u8, 43u8, 167u8, 241u8, 99u8, 196u8, 161u8, 22u8,
+                40u8, 245u8, 90u8, 77u8, 245u8, 35u8, 179u8, 239u8,
+            ],
+        ];
+        #[doc = "Returns `true` if `self` matches [`Transfer`](Self::Transfer)."]
+        #[inline]
+        pub const fn is_transfer(&self) -> bool {
+            ::core::matches!(self, Self::Transfer(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`Transfer`] if `self` matches [`Transfer`](Self::Transfer)."]
+        #[inline]
+        pub const fn as_transfer(&self) -> ::core::option::Option<&Transfer> {
+            match self {
+                Self::Transfer(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`Transfer`] if `self` matches [`Transfer`](Self::Transfer)."]
+        #[inline]
+        pub fn as_transfer_mut(&mut self) -> ::core::option::Option<&mut Transfer> {
+            match self {
+                Self::Transfer(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns `true` if `self` matches [`Approval`](Self::Approval)."]
+        #[inline]
+        pub const fn is_approval(&self) -> bool {
+            ::core::matches!(self, Self::Approval(_))
+        }
+        #[doc = "Returns an immutable reference to the inner [`Approval`] if `self` matches [`Approval`](Self::Approval)."]
+        #[inline]
+        pub const fn as_approval(&self) -> ::core::option::Option<&Approval> {
+            match self {
+                Self::Approval(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+        #[doc = "Returns a mutable reference to the inner [`Approval`] if `self` matches [`Approval`](Self::Approval)."]
+        #[inline]
+        pub fn as_approval_mut(&mut self) -> ::core::option::Option<&mut Approval> {
+            match self {
+                Self::Approval(inner) => ::core::option::Option::Some(inner),
+                _ => ::core::option::Option::None,
+            }
+        }
+    }
+    #[automatically_derived]
+    impl IERC20Events {
+        #[doc = r" Decodes a raw log into the event whose signature hash matches the"]
+        #[doc = r" first topic."]
+        #[doc = r""]
+        #[doc = r" If no signature hash matches, the anonymous events are tried in"]
+        #[doc = r" definition order."]
+        pub fn decode_log(
+            topics: &[::alloy_sol_types::private::B256],
+            data: &[u8],
+            validate: bool,
+        ) -> ::alloy_sol_types::Result<Self> {
+            if let Some(&topic0) = topics.first() {
+                if topic0 == <Transfer as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH {
+                    return <Transfer as ::alloy_sol_types::SolEvent>::decode_raw_log(
+                        topics, data, validate,
+                    )
+                    .map(Self::Transfer);
+                }
+                if topic0 == <Approval as ::alloy_sol_types::SolEvent>::SIGNATURE_HASH {
+                    return <Approval as ::alloy_sol_types::SolEvent>::decode_raw_log(
+                        topics, data, validate,
+                    )
+                    .map(Self::Approval);
+                }
+            }
+            Err(::alloy_sol_types::Error::InvalidLog {
+                name: "IERC20Events",
+            })
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<&::alloy_sol_types::private::LogData> for IERC20Events {
+        type Error = ::alloy_sol_types::Error;
+        #[inline]
+        fn try_from(log: &::alloy_sol_types::private::LogData) -> ::alloy_sol_types::Result<Self> {
+            Self::decode_log(log.topics(), &log.data, true)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<::alloy_sol_types::private::LogData> for IERC20Events {
+        type Error = ::alloy_sol_types::Error;
+        #[inline]
+        fn try_from(log: ::alloy_sol_types::private::LogData) -> ::alloy_sol_types::Result<Self> {
+            Self::try_from(&log)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<&::alloy_sol_types::private::Log> for IERC20Events {
+        type Error = ::alloy_sol_types::Error;
+        #[inline]
+        fn try_from(log: &::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+            Self::try_from(&log.data)
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<::alloy_sol_types::private::Log> for IERC20Events {
+        type Error = ::alloy_sol_types::Error;
+        #[inline]
+        fn try_from(log: ::alloy_sol_types::private::Log) -> ::alloy_sol_types::Result<Self> {
+            Self::try_from(&log.data)
+        }
+    }
+}
@@ -0,0 +1,375 @@
+::alloy_sol_types::define_udt! { Price , underlying : :: alloy_sol_types :: sol_data :: Uint < 128 > , }
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+#[derive(Clone, Copy)]
+#[repr(u8)]
+pub enum Side {
+    #[doc = "`Side.Buy`"]
+    Buy,
+    #[doc = "`Side.Sell`"]
+    Sell,
+    #[doc = r" Invalid variant."]
+    #[doc = r""]
+    #[doc = r" This is only used when decoding an out-of-range `u8` value."]
+    #[doc(hidden)]
+    __Invalid = u8::MAX,
+}
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+const _: () = {
+    #[automatically_derived]
+    impl ::core::convert::From<Side> for u8 {
+        #[inline]
+        fn from(v: Side) -> Self {
+            v as u8
+        }
+    }
+    #[automatically_derived]
+    impl ::core::convert::TryFrom<u8> for Side {
+        type Error = ::alloy_sol_types::Error;
+        #[inline]
+        fn try_from(v: u8) -> ::alloy_sol_types::Result<Self> {
+            match v {
+                0u8 => ::core::result::Result::Ok(Self::Buy),
+                1u8 => ::core::result::Result::Ok(Self::Sell),
+                _ => ::core::result::Result::Err(::alloy_sol_types::Error::InvalidEnumValue {
+                    name: "Side",
+                    value: v,
+                    max: 1u8,
+                }),
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::Encodable<Side> for Side {
+        #[inline]
+        fn to_tokens(
+            &self,
+        ) -> <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::TokenType<'_>
+        {
+            ::alloy_sol_types::Word::with_last_byte(*self as u8).into()
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolType for Side {
+        type RustType = Side;
+        type TokenType<'a> =
+            <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        const ENCODED_SIZE: ::core::option::Option<usize> =
+            <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::ENCODED_SIZE;
+        #[inline]
+        fn sol_type_name() -> ::alloy_sol_types::private::Cow<'static, str> {
+            <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::sol_type_name()
+        }
+        #[inline]
+        fn type_check(token: &Self::TokenType<'_>) -> ::alloy_sol_types::Result<()> {
+            <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::type_check(
+                token,
+            )?;
+            <Self as ::core::convert::TryFrom<u8>>::try_from(
+                <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::detokenize(
+                    *token,
+                ),
+            )
+            .map(::core::mem::drop)
+        }
+        #[inline]
+        fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+            <Self as ::core::convert::TryFrom<u8>>::try_from(
+                <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::detokenize(
+                    token,
+                ),
+            )
+            .unwrap_or(Self::__Invalid)
+        }
+        #[inline]
+        fn eip712_data_word(rust: &Self::RustType) -> ::alloy_sol_types::Word {
+            <::alloy_sol_types::sol_data::Uint<8> as ::alloy_sol_types::SolType>::eip712_data_word(
+                &(*rust as u8),
+            )
+        }
+        #[inline]
+        fn encode_packed_to(rust: &Self::RustType, out: &mut ::alloy_sol_types::private::Vec<u8>) {
+            out.push(*rust as u8);
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolEnum for Side {
+        const COUNT: usize = 2usize;
+    }
+};
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Clone)]
+pub struct Order { # [doc = "`address maker`"] pub maker : < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: RustType , # [doc = "`Side side`"] pub side : < Side as :: alloy_sol_types :: SolType > :: RustType , # [doc = "`Price price`"] pub price : < Price as :: alloy_sol_types :: SolType > :: RustType , # [doc = "`uint64[] amounts`"] pub amounts : < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: SolType > :: RustType }
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+const _: () = {
+    #[doc(hidden)]
+    type UnderlyingSolTuple<'a> = (
+        ::alloy_sol_types::sol_data::Address,
+        Side,
+        Price,
+        ::alloy_sol_types::sol_data::Array<::alloy_sol_types::sol_data::Uint<64>>,
+    );
+    #[doc(hidden)]
+    type UnderlyingRustTuple < 'a > = (< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: RustType , < Side as :: alloy_sol_types :: SolType > :: RustType , < Price as :: alloy_sol_types :: SolType > :: RustType , < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: SolType > :: RustType ,) ;
+    #[automatically_derived]
+    #[doc(hidden)]
+    impl ::core::convert::From<Order> for UnderlyingRustTuple<'_> {
+        fn from(value: Order) -> Self {
+            (value.maker, value.side, value.price, value.amounts)
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    impl ::core::convert::From<UnderlyingRustTuple<'_>> for Order {
+        fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+            Self {
+                maker: tuple.0,
+                side: tuple.1,
+                price: tuple.2,
+                amounts: tuple.3,
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for Order {
+        fn to_tokens(
+            &self,
+        ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_> {
+            (
+                ::alloy_sol_types::Encodable::<::alloy_sol_types::sol_data::Address>::to_tokens(
+                    &self.maker,
+                ),
+                ::alloy_sol_types::Encodable::<Side>::to_tokens(&self.side),
+                ::alloy_sol_types::Encodable::<Price>::to_tokens(&self.price),
+                ::alloy_sol_types::Encodable::<
+                    ::alloy_sol_types::sol_data::Array<::alloy_sol_types::sol_data::Uint<64>>,
+                >::to_tokens(&self.amounts),
+            )
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolStruct for Order {
+        type Tuple<'a> = UnderlyingSolTuple<'a>;
+        type Token<'a> = <Self::Tuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        const NAME: &'static str = "Order";
+        fn to_rust<'a>(&self) -> UnderlyingRustTuple<'a> {
+            self.clone().into()
+        }
+        fn new<'a>(tuple: UnderlyingRustTuple<'a>) -> Self {
+            tuple.into()
+        }
+        fn tokenize<'a>(&'a self) -> Self::Token<'a> {
+            (< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: tokenize (& self . maker) , < Side as :: alloy_sol_types :: SolType > :: tokenize (& self . side) , < Price as :: alloy_sol_types :: SolType > :: tokenize (& self . price) , < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: SolType > :: tokenize (& self . amounts) ,)
+        }
+        #[inline]
+        fn eip712_root_type() -> ::alloy_sol_types::private::Cow<'static, str> {
+            ::alloy_sol_types::private::Cow::Borrowed(
+                "Order(address maker,uint8 side,uint128 price,uint64[] amounts)",
+            )
+        }
+        fn eip712_components(
+        ) -> ::alloy_sol_types::private::Vec<::alloy_sol_types::private::Cow<'static, str>>
+        {
+            ::alloy_sol_types::private::Vec::new()
+        }
+        #[inline]
+        fn eip712_encode_type() -> ::alloy_sol_types::private::Cow<'static, str> {
+            <Self as ::alloy_sol_types::SolStruct>::eip712_root_type()
+        }
+        fn eip712_encode_data(&self) -> ::alloy_sol_types::private::Vec<u8> {
+            [< :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: SolType > :: eip712_data_word (& self . maker) . 0 , < Side as :: alloy_sol_types :: SolType > :: eip712_data_word (& self . side) . 0 , < Price as :: alloy_sol_types :: SolType > :: eip712_data_word (& self . price) . 0 , < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: SolType > :: eip712_data_word (& self . amounts) . 0 ,] . concat ()
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::EventTopic for Order {
+        #[inline]
+        fn topic_preimage_length(rust: &Self::RustType) -> usize {
+            0usize + < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: topic_preimage_length (& rust . maker) + < Side as :: alloy_sol_types :: EventTopic > :: topic_preimage_length (& rust . side) + < Price as :: alloy_sol_types :: EventTopic > :: topic_preimage_length (& rust . price) + < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: EventTopic > :: topic_preimage_length (& rust . amounts)
+        }
+        #[inline]
+        fn encode_topic_preimage(
+            rust: &Self::RustType,
+            out: &mut ::alloy_sol_types::private::Vec<u8>,
+        ) {
+            out.reserve(<Self as ::alloy_sol_types::EventTopic>::topic_preimage_length(rust));
+            < :: alloy_sol_types :: sol_data :: Address as :: alloy_sol_types :: EventTopic > :: encode_topic_preimage (& rust . maker , out) ;
+            <Side as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.side, out);
+            <Price as ::alloy_sol_types::EventTopic>::encode_topic_preimage(&rust.price, out);
+            < :: alloy_sol_types :: sol_data :: Array < :: alloy_sol_types :: sol_data :: Uint < 64 > > as :: alloy_sol_types :: EventTopic > :: encode_topic_preimage (& rust . amounts , out) ;
+        }
+        #[inline]
+        fn encode_topic(rust: &Self::RustType) -> ::alloy_sol_types::token::WordToken {
+            let mut out = ::alloy_sol_types::private::Vec::new();
+            <Self as ::alloy_sol_types::EventTopic>::encode_topic_preimage(rust, &mut out);
+            ::alloy_sol_types::token::WordToken(::alloy_sol_types::private::keccak256(out))
+        }
+    }
+};
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Clone)]
+pub struct Expired {
+    #[doc = "`uint256 deadline`"]
+    pub deadline: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+}
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+const _: () = {
+    #[doc(hidden)]
+    type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+    #[doc(hidden)]
+    type UnderlyingRustTuple<'a> =
+        (<::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,);
+    #[automatically_derived]
+    #[doc(hidden)]
+    impl ::core::convert::From<Expired> for UnderlyingRustTuple<'_> {
+        fn from(value: Expired) -> Self {
+            (value.deadline,)
+        }
+    }
+    #[automatically_derived]
+    #[doc(hidden)]
+    impl ::core::convert::From<UnderlyingRustTuple<'_>> for Expired {
+        fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+            Self { deadline: tuple.0 }
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for Expired {
+        fn to_tokens(
+            &self,
+        ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_> {
+            (::alloy_sol_types::Encodable::<
+                ::alloy_sol_types::sol_data::Uint<256>,
+            >::to_tokens(&self.deadline),)
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolError for Expired {
+        type Parameters<'a> = UnderlyingSolTuple<'a>;
+        type Token<'a> = <Self::Parameters<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        const SIGNATURE: &'static str = "Expired(uint256)";
+        const SELECTOR: [u8; 4] = [248u8, 13u8, 186u8, 234u8];
+        #[inline]
+        fn new<'a>(tuple: <Self::Parameters<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
+            tuple.into()
+        }
+        #[inline]
+        fn tokenize(&self) -> Self::Token<'_> {
+            (
+                <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::tokenize(
+                    &self.deadline,
+                ),
+            )
+        }
+    }
+};
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Clone)]
+pub struct fillCall {
+    #[doc = "`Order memory order`"]
+    pub order: <Order as ::alloy_sol_types::SolType>::RustType,
+    #[doc = "`bytes calldata signature`"]
+    pub signature: <::alloy_sol_types::sol_data::Bytes as ::alloy_sol_types::SolType>::RustType,
+}
+#[allow(non_camel_case_types, non_snake_case)]
+#[derive(Clone)]
+pub struct fillReturn {
+    #[doc = "`uint256 filled`"]
+    pub filled: <::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,
+}
+#[allow(non_camel_case_types, non_snake_case, clippy::style)]
+const _: () = {
+    {
+        #[doc(hidden)]
+        type UnderlyingSolTuple<'a> = (Order, ::alloy_sol_types::sol_data::Bytes);
+        #[doc(hidden)]
+        type UnderlyingRustTuple<'a> = (
+            <Order as ::alloy_sol_types::SolType>::RustType,
+            <::alloy_sol_types::sol_data::Bytes as ::alloy_sol_types::SolType>::RustType,
+        );
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<fillCall> for UnderlyingRustTuple<'_> {
+            fn from(value: fillCall) -> Self {
+                (value.order, value.signature)
+            }
+        }
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<UnderlyingRustTuple<'_>> for fillCall {
+            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                Self {
+                    order: tuple.0,
+                    signature: tuple.1,
+                }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for fillCall {
+            fn to_tokens(
+                &self,
+            ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_> {
+                (
+                    ::alloy_sol_types::Encodable::<Order>::to_tokens(&self.order),
+                    ::alloy_sol_types::Encodable::<::alloy_sol_types::sol_data::Bytes>::to_tokens(
+                        &self.signature,
+                    ),
+                )
+            }
+        }
+    }
+    {
+        #[doc(hidden)]
+        type UnderlyingSolTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+        #[doc(hidden)]
+        type UnderlyingRustTuple<'a> =
+            (<::alloy_sol_types::sol_data::Uint<256> as ::alloy_sol_types::SolType>::RustType,);
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<fillReturn> for UnderlyingRustTuple<'_> {
+            fn from(value: fillReturn) -> Self {
+                (value.filled,)
+            }
+        }
+        #[automatically_derived]
+        #[doc(hidden)]
+        impl ::core::convert::From<UnderlyingRustTuple<'_>> for fillReturn {
+            fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
+                Self { filled: tuple.0 }
+            }
+        }
+        #[automatically_derived]
+        impl ::alloy_sol_types::Encodable<UnderlyingSolTuple<'_>> for fillReturn {
+            fn to_tokens(
+                &self,
+            ) -> <UnderlyingSolTuple<'_> as ::alloy_sol_types::SolType>::TokenType<'_> {
+                (::alloy_sol_types::Encodable::<
+                    ::alloy_sol_types::sol_data::Uint<256>,
+                >::to_tokens(&self.filled),)
+            }
+        }
+    }
+    #[automatically_derived]
+    impl ::alloy_sol_types::SolCall for fillCall {
+        type Arguments<'a> = (Order, ::alloy_sol_types::sol_data::Bytes);
+        type Token<'a> = <Self::Arguments<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        type Return = fillReturn;
+        type ReturnTuple<'a> = (::alloy_sol_types::sol_data::Uint<256>,);
+        type ReturnToken<'a> = <Self::ReturnTuple<'a> as ::alloy_sol_types::SolType>::TokenType<'a>;
+        const SIGNATURE: &'static str = "fill((address,uint8,uint128,uint64[]),bytes)";
+        const SELECTOR: [u8; 4] = [200u8, 26u8, 56u8, 93u8];
+        fn new<'a>(tuple: <Self::Arguments<'a> as ::alloy_sol_types::SolType>::RustType) -> Self {
+            tuple.into()
+        }
+        fn tokenize(&self) -> Self::Token<'_> {
+            (
+                <Order as ::alloy_sol_types::SolType>::tokenize(&self.order),
+                <::alloy_sol_types::sol_data::Bytes as ::alloy_sol_types::SolType>::tokenize(
+                    &self.signature,
+                ),
+            )
+        }
+        fn decode_returns(data: &[u8], validate: bool) -> ::alloy_sol_types::Result<Self::Return> {
+            <Self::ReturnTuple<'_> as ::alloy_sol_types::SolType>::decode(data, validate)
+                .map(Into::into)
+        }
+    }
+};
@@ -0,0 +1,17 @@
+type Price is uint128;
+
+enum Side {
+    Buy,
+    Sell
+}
+
+struct Order {
+    address maker;
+    Side side;
+    Price price;
+    uint64[] amounts;
+}
+
+error Expired(uint256 deadline);
+
+function fill(Order memory order, bytes calldata signature) returns (uint256 filled);
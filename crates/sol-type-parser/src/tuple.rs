@@ -74,11 +74,14 @@ impl<'a> TupleSpecifier<'a> {
                     // handle extra closing paren
                     depth = depth
                         .checked_sub(1)
-                        .ok_or_else(|| Error::invalid_type_string(value))?;
+                        .ok_or_else(|| Error::invalid_type_string(span))?;
                 }
                 ',' if depth == 0 => {
                     // SAFETY: `char_indices` always returns a valid char boundary
                     let v = unsafe { value.get_unchecked(start..i) };
+                    if v.trim().is_empty() {
+                        return Err(Error::invalid_type_string(span))
+                    }
                     types.push(v.try_into()?);
                     start = i + 1;
                 }
@@ -88,7 +91,7 @@ impl<'a> TupleSpecifier<'a> {
 
         // handle extra open paren
         if depth != 0 {
-            return Err(Error::invalid_type_string(value))
+            return Err(Error::invalid_type_string(span))
         }
 
         // handle trailing commas in tuples
@@ -80,34 +80,25 @@ impl<'a> TypeSpecifier<'a> {
     pub fn parse(span: &'a str) -> Result<Self> {
         let span = span.trim();
 
+        // strip array suffixes from the end, e.g. `(uint256,bool[])[2][]`
         let mut root = span;
         let mut sizes = vec![];
-
-        // an iterator of string slices split by `[`
-        for s in root.rsplit('[') {
-            // we've reached a root tuple so we need to include the closing
-            // paren
-            if s.contains(')') {
-                let idx = span.rfind(')').unwrap();
-                root = &span[..=idx];
-                break
-            }
-            // we've reached a root type that is not a tuple or array
-            if !s.contains(']') {
-                root = s;
-                break
-            }
-
-            let s = s
-                .trim()
-                .strip_suffix(']')
+        while let Some(rest) = root.strip_suffix(']') {
+            let (stem, size) = rest
+                .rsplit_once('[')
+                .filter(|(_, size)| !size.contains(']'))
                 .ok_or_else(|| Error::invalid_type_string(span))?;
-            let size = if s.is_empty() {
+            let size = size.trim();
+            let size = if size.is_empty() {
                 None
             } else {
-                Some(s.parse().map_err(|_| Error::invalid_type_string(span))?)
+                Some(size.parse().map_err(|_| Error::invalid_size(span))?)
             };
             sizes.push(size);
+            root = stem.trim_end();
+        }
+        if root.is_empty() {
+            return Err(Error::invalid_type_string(span))
         }
 
         sizes.reverse();
@@ -208,6 +199,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn nested_arrays() {
+        let spec = TypeSpecifier::try_from("(uint256,bool[])[2][]").unwrap();
+        assert_eq!(spec.stem.span(), "(uint256,bool[])");
+        assert_eq!(spec.sizes, [NonZeroUsize::new(2), None]);
+
+        let spec = TypeSpecifier::try_from("(uint256[3])").unwrap();
+        assert_eq!(spec.stem.span(), "(uint256[3])");
+        assert!(spec.sizes.is_empty());
+    }
+
+    #[test]
+    fn invalid_arrays() {
+        for s in ["uint256[2", "uint256]", "uint256[2]]", "[]"] {
+            assert_eq!(
+                TypeSpecifier::try_from(s),
+                Err(Error::invalid_type_string(s)),
+                "{s}"
+            );
+        }
+        for s in ["uint256[0]", "uint256[-1]", "uint256[a][]"] {
+            assert_eq!(
+                TypeSpecifier::try_from(s),
+                Err(Error::invalid_size(s)),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            TypeSpecifier::try_from("(bool,,uint256)[]"),
+            Err(Error::invalid_type_string("(bool,,uint256)"))
+        );
+    }
+
     #[test]
     fn a_type_named_tuple() {
         TypeSpecifier::try_from("tuple").unwrap();
@@ -0,0 +1,380 @@
+//! [`arbitrary`] and [`proptest`] support for Solidity types.
+//!
+//! [`ArbitrarySolType`] generates valid Rust values for a [`SolType`]: integers
+//! are within the bounds of their bit size, and fixed-size byte and array
+//! types always have the correct length. It is implemented for all the
+//! [`sol_data`](crate::sol_data) types, and for tuples of up to 12 elements.
+//!
+//! Structs, enums and user-defined value types generated by [`sol!`] implement
+//! it too, if they are declared with `#[sol(all_derives)]`.
+//!
+//! [`check_roundtrip`] can be used to test that values of a type survive an
+//! encoding round-trip.
+//!
+//! # Examples
+//!
+//! ```
+//! use alloy_sol_types::{arbitrary::check_roundtrip, sol_data::*};
+//!
+//! check_roundtrip::<(Address, Uint<24>, Array<FixedBytes<4>>, String)>();
+//! ```
+//!
+//! [`sol!`]: crate::sol
+
+use crate::{sol_data::*, token::WordToken, types::Renamed, SolType};
+use alloc::string::String as RustString;
+use alloy_primitives::B256;
+use arbitrary::Unstructured;
+use core::fmt;
+use proptest::{
+    array::UniformArrayStrategy,
+    collection::{vec as vec_strategy, SizeRange, VecStrategy},
+    prelude::*,
+    strategy::{FilterMap, Map},
+    test_runner::{TestCaseResult, TestRunner},
+};
+
+/// The maximum length of generated dynamic arrays and byte strings.
+const MAX_LEN: usize = 8;
+
+/// A [`SolType`] whose Rust values can be randomly generated.
+///
+/// See the [module-level documentation](self) for more details.
+pub trait ArbitrarySolType: SolType {
+    /// The [`proptest`] strategy for this type.
+    type Strategy: Strategy<Value = Self::RustType>;
+
+    /// Returns a [`proptest`] strategy which generates valid values.
+    fn strategy() -> Self::Strategy;
+
+    /// Generates a valid value from unstructured data, like
+    /// [`arbitrary::Arbitrary`].
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType>;
+}
+
+/// The strategy for types which are encoded in a single word.
+type WordStrategy<T> =
+    Map<UniformArrayStrategy<proptest::num::u8::Any, [u8; 32]>, fn([u8; 32]) -> T>;
+
+macro_rules! word_impls {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bounds:tt)*])?;)+) => {$(
+        impl<$($gen)*> ArbitrarySolType for $ty $(where $($bounds)*)? {
+            type Strategy = WordStrategy<Self::RustType>;
+
+            #[inline]
+            fn strategy() -> Self::Strategy {
+                any::<[u8; 32]>().prop_map(from_word::<Self>)
+            }
+
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+                u.arbitrary().map(from_word::<Self>)
+            }
+        }
+    )+};
+}
+
+/// Detokenizes a random word, which truncates or sign-extends it to the
+/// bounds of the type.
+#[inline]
+fn from_word<T: for<'a> SolType<TokenType<'a> = WordToken>>(word: [u8; 32]) -> T::RustType {
+    T::detokenize(WordToken(B256::new(word)))
+}
+
+word_impls! {
+    [] Address;
+    [const N: usize] FixedBytes<N> where [ByteCount<N>: SupportedFixedBytes];
+    [const BITS: usize] Int<BITS> where [IntBitCount<BITS>: SupportedInt];
+    [const BITS: usize] Uint<BITS> where [IntBitCount<BITS>: SupportedInt];
+}
+
+impl ArbitrarySolType for Bool {
+    type Strategy = proptest::bool::Any;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        any::<bool>()
+    }
+
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        u.arbitrary()
+    }
+}
+
+impl ArbitrarySolType for Bytes {
+    type Strategy = VecStrategy<proptest::num::u8::Any>;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        vec_strategy(any::<u8>(), 0..=MAX_LEN * 32)
+    }
+
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        u.arbitrary()
+    }
+}
+
+impl ArbitrarySolType for String {
+    type Strategy = <RustString as Arbitrary>::Strategy;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        any::<RustString>()
+    }
+
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        u.arbitrary()
+    }
+}
+
+impl<T: ArbitrarySolType> ArbitrarySolType for Array<T> {
+    type Strategy = VecStrategy<T::Strategy>;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        vec_strategy(T::strategy(), SizeRange::from(0..=MAX_LEN))
+    }
+
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        let len = u.int_in_range(0..=MAX_LEN)?;
+        (0..len).map(|_| T::arbitrary(u)).collect()
+    }
+}
+
+impl<T: ArbitrarySolType, const N: usize> ArbitrarySolType for FixedArray<T, N>
+where
+    T::RustType: fmt::Debug,
+{
+    type Strategy = UniformArrayStrategy<T::Strategy, [T::RustType; N]>;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        proptest::array::uniform(T::strategy())
+    }
+
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        crate::impl_core::try_from_fn(|_| T::arbitrary(u))
+    }
+}
+
+/// Values of `S` which do not fit in `T` are skipped, so most of them should.
+impl<S, T> ArbitrarySolType for Renamed<S, T>
+where
+    S: ArbitrarySolType + for<'a> SolType<TokenType<'a> = WordToken>,
+    T: Copy + TryFrom<S::RustType> + fmt::Debug + 'static,
+    S::RustType: TryFrom<T>,
+{
+    type Strategy = FilterMap<S::Strategy, fn(S::RustType) -> Option<T>>;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        let try_from: fn(_) -> _ = |rust| T::try_from(rust).ok();
+        S::strategy().prop_filter_map("value does not fit in the Rust type", try_from)
+    }
+
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        T::try_from(S::arbitrary(u)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
+impl ArbitrarySolType for () {
+    type Strategy = Just<()>;
+
+    #[inline]
+    fn strategy() -> Self::Strategy {
+        Just(())
+    }
+
+    #[inline]
+    fn arbitrary(_u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+        Ok(())
+    }
+}
+
+macro_rules! tuple_impls {
+    ($($ty:ident),+) => {
+        impl<$($ty: ArbitrarySolType,)+> ArbitrarySolType for ($($ty,)+) {
+            type Strategy = ($($ty::Strategy,)+);
+
+            #[inline]
+            fn strategy() -> Self::Strategy {
+                ($($ty::strategy(),)+)
+            }
+
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self::RustType> {
+                Ok(($($ty::arbitrary(u)?,)+))
+            }
+        }
+    };
+}
+
+// proptest only implements `Strategy` for tuples of up to 12 elements
+tuple_impls!(T1);
+tuple_impls!(T1, T2);
+tuple_impls!(T1, T2, T3);
+tuple_impls!(T1, T2, T3, T4);
+tuple_impls!(T1, T2, T3, T4, T5);
+tuple_impls!(T1, T2, T3, T4, T5, T6);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7, T8);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7, T8, T9);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
+tuple_impls!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Checks that `value` is unchanged after being encoded and decoded as `T`.
+///
+/// This can be used as a [`proptest`] property, see [`check_roundtrip`].
+///
+/// # Errors
+///
+/// Returns a test case failure if decoding fails, or if the decoded value is
+/// different from `value`.
+pub fn roundtrip<T>(value: &T::RustType) -> TestCaseResult
+where
+    T: SolType,
+    T::RustType: PartialEq + fmt::Debug,
+{
+    let encoded = T::encode_single(value);
+    prop_assert_eq!(
+        encoded.len(),
+        32 * T::DYNAMIC as usize + T::encoded_size(value)
+    );
+    let decoded = T::decode_single(&encoded, true)
+        .map_err(|e| TestCaseError::fail(alloc::format!("failed to decode: {e}")))?;
+    prop_assert_eq!(&decoded, value);
+    Ok(())
+}
+
+/// Checks the [`roundtrip`] property for values generated by
+/// [`ArbitrarySolType::strategy`], using the default [`TestRunner`].
+///
+/// # Panics
+///
+/// Panics with the minimal failing input if the property does not hold.
+#[track_caller]
+pub fn check_roundtrip<T>()
+where
+    T: ArbitrarySolType,
+    T::RustType: PartialEq + fmt::Debug,
+{
+    let mut runner = TestRunner::default();
+    if let Err(e) = runner.run(&T::strategy(), |value| roundtrip::<T>(&value)) {
+        panic!("{} roundtrip failed: {e}", T::sol_type_name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use alloy_primitives::{I256, U256};
+
+    #[test]
+    fn bounded_ints() {
+        let mut runner = TestRunner::default();
+        runner
+            .run(&Uint::<24>::strategy(), |x| {
+                prop_assert!(x < 1 << 24);
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&Int::<24>::strategy(), |x| {
+                prop_assert!((-(1 << 23)..1 << 23).contains(&x));
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&Uint::<160>::strategy(), |x| {
+                prop_assert!(x < U256::from(1) << 160);
+                Ok(())
+            })
+            .unwrap();
+        runner
+            .run(&Int::<136>::strategy(), |x| {
+                let bound: I256 = I256::ONE << 135;
+                prop_assert!(x >= -bound && x < bound);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn unstructured() {
+        let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&data);
+        type T = (Int<40>, FixedArray<Array<Bytes>, 2>, String);
+        for _ in 0..8 {
+            let value = T::arbitrary(&mut u).unwrap();
+            assert!((-(1 << 39)..1 << 39).contains(&value.0));
+            roundtrip::<T>(&value).unwrap();
+        }
+    }
+
+    crate::sol! {
+        #![sol(all_derives)]
+
+        type Price is uint128;
+
+        enum Side {
+            Buy,
+            Sell
+        }
+
+        struct Order {
+            address maker;
+            Price price;
+            uint64[] amounts;
+        }
+
+        struct Book {
+            Order[2] best;
+            int24 tick;
+        }
+    }
+
+    #[test]
+    fn sol_items() {
+        check_roundtrip::<Price>();
+        check_roundtrip::<Side>();
+        check_roundtrip::<Order>();
+        check_roundtrip::<Book>();
+
+        let data = (0..=255).cycle().take(4096).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..8 {
+            let book = Book::arbitrary(&mut u).unwrap();
+            assert!((-(1 << 23)..1 << 23).contains(&book.tick));
+            roundtrip::<Book>(&book).unwrap();
+            assert!(!matches!(Side::arbitrary(&mut u).unwrap(), Side::__Invalid));
+        }
+    }
+
+    #[test]
+    fn roundtrips() {
+        check_roundtrip::<Bool>();
+        check_roundtrip::<Address>();
+        check_roundtrip::<FixedBytes<7>>();
+        check_roundtrip::<Int<8>>();
+        check_roundtrip::<Int<72>>();
+        check_roundtrip::<Uint<256>>();
+        check_roundtrip::<Bytes>();
+        check_roundtrip::<String>();
+        check_roundtrip::<Array<Uint<16>>>();
+        check_roundtrip::<FixedArray<String, 3>>();
+        check_roundtrip::<()>();
+        check_roundtrip::<(Int<256>, (Bytes, Array<FixedArray<Bool, 2>>))>();
+        check_roundtrip::<Renamed<Uint<8>, u16>>();
+        check_roundtrip::<(
+            Renamed<FixedBytes<4>, [u8; 4]>,
+            Array<Renamed<Int<32>, i64>>,
+        )>();
+    }
+}
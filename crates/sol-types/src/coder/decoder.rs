@@ -8,8 +8,9 @@
 // except according to those terms.
 //
 
+use super::ser;
 use crate::{encode, token::TokenSeq, utils, Error, Result, TokenType, Word};
-use alloc::{borrow::Cow, vec::Vec};
+use alloc::vec::Vec;
 use core::{fmt, slice::SliceIndex};
 
 /// The [`Decoder`] wraps a byte slice with necessary info to progressively
@@ -72,7 +73,8 @@ impl<'de> Decoder<'de> {
     ///
     /// If `validate` is true, the decoder will check that the bytes conform to
     /// expected type limitations, and that the decoded values can be re-encoded
-    /// to an identical bytestring.
+    /// to an identical bytestring. See the [crate-level
+    /// documentation](crate#decoding-modes) for more details.
     #[inline]
     pub const fn new(buf: &'de [u8], validate: bool) -> Self {
         Self {
@@ -132,9 +134,7 @@ impl<'de> Decoder<'de> {
     /// offset.
     #[inline]
     pub fn peek_word_at(&self, offset: usize) -> Result<Word, Error> {
-        Ok(Word::from_slice(
-            self.peek_len_at(offset, Word::len_bytes())?,
-        ))
+        ser::read_word(self.buf, offset)
     }
 
     /// Peek the next word from the buffer without advancing the offset.
@@ -145,15 +145,18 @@ impl<'de> Decoder<'de> {
 
     /// Peek a u32 from the buffer at a specific offset, without advancing the
     /// offset.
+    ///
+    /// If validating, the word must not have any bits set above the low 32.
+    /// Otherwise, they are ignored.
     #[inline]
     pub fn peek_u32_at(&self, offset: usize) -> Result<u32> {
-        utils::as_u32(self.peek_word_at(offset)?, true)
+        utils::as_u32(self.peek_word_at(offset)?, self.validate)
     }
 
-    /// Peek the next word as a u32.
+    /// Peek the next word as a u32. See [`peek_u32_at`](Self::peek_u32_at).
     #[inline]
     pub fn peek_u32(&self) -> Result<u32> {
-        utils::as_u32(self.peek_word()?, true)
+        utils::as_u32(self.peek_word()?, self.validate)
     }
 
     /// Take a word from the buffer, advancing the offset.
@@ -168,30 +171,24 @@ impl<'de> Decoder<'de> {
     /// pointer, and following it.
     #[inline]
     pub fn take_indirection(&mut self) -> Result<Decoder<'de>, Error> {
-        let ptr = self.take_u32()? as usize;
+        let ptr = ser::read_offset(self.buf, self.offset, self.validate)?;
+        self.increase_offset(Word::len_bytes());
         self.child(ptr)
     }
 
-    /// Take a u32 from the buffer by consuming a word.
+    /// Take a u32 from the buffer by consuming a word. See
+    /// [`peek_u32_at`](Self::peek_u32_at).
     #[inline]
     pub fn take_u32(&mut self) -> Result<u32> {
         let word = self.take_word()?;
-        utils::as_u32(word, true)
+        utils::as_u32(word, self.validate)
     }
 
     /// Takes a slice of bytes of the given length by consuming up to the next
     /// word boundary.
     pub fn take_slice(&mut self, len: usize) -> Result<&[u8], Error> {
         if self.validate {
-            let padded_len = utils::next_multiple_of_32(len);
-            if self.offset + padded_len > self.buf.len() {
-                return Err(Error::Overrun)
-            }
-            if !utils::check_zeroes(self.peek(self.offset + len..self.offset + padded_len)?) {
-                return Err(Error::Other(Cow::Borrowed(
-                    "Non-empty bytes after packed array",
-                )))
-            }
+            ser::check_padding(self.buf, self.offset, len)?;
         }
         let res = self.peek_len(len)?;
         self.increase_offset(len);
@@ -659,4 +656,38 @@ mod tests {
             "did not match error"
         );
     }
+
+    #[test]
+    fn decode_static_fixed_array_in_dynamic_array() {
+        type MyTy = sol_data::Array<sol_data::FixedArray<sol_data::Bool, 2>>;
+        let input = hex!(
+            "
+        0000000000000000000000000000000000000000000000000000000000000020
+        0000000000000000000000000000000000000000000000000000000000000002
+        0000000000000000000000000000000000000000000000000000000000000000
+        0000000000000000000000000000000000000000000000000000000000000001
+        0000000000000000000000000000000000000000000000000000000000000001
+        0000000000000000000000000000000000000000000000000000000000000000
+        "
+        );
+        let decoded = MyTy::decode_single(&input, true).unwrap();
+        assert_eq!(decoded, [[false, true], [true, false]]);
+    }
+
+    #[test]
+    fn dirty_offsets_and_lengths() {
+        type MyTy = sol_data::Bytes;
+        let input = hex!(
+            "
+        ff00000000000000000000000000000000000000000000000000000000000020
+        0100000000000000000000000000000000000000000000000000000000000002
+        1234000000000000000000000000000000000000000000000000000000000000
+        "
+        );
+        assert_eq!(MyTy::decode_single(&input, false).unwrap(), [0x12, 0x34]);
+        assert!(matches!(
+            MyTy::decode_single(&input, true),
+            Err(crate::Error::TypeCheckFail { .. })
+        ));
+    }
 }
@@ -1111,4 +1111,35 @@ mod tests {
         assert_eq!(encoded_params.len() + 32, encoded.len());
         assert_eq!(encoded_params.len(), MyTy::encoded_size(&data));
     }
+
+    #[test]
+    fn encode_dynamic_fixed_array_followed_by_dynamic() {
+        type MyTy = (
+            sol_data::FixedArray<sol_data::String, 2>,
+            sol_data::Array<sol_data::Bytes>,
+        );
+        let data = (["a".to_owned(), "b".to_owned()], vec![vec![0x04]]);
+
+        let encoded = MyTy::encode_params(&data);
+        let expected = hex!(
+            "
+    		0000000000000000000000000000000000000000000000000000000000000040
+    		0000000000000000000000000000000000000000000000000000000000000100
+    		0000000000000000000000000000000000000000000000000000000000000040
+    		0000000000000000000000000000000000000000000000000000000000000080
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6100000000000000000000000000000000000000000000000000000000000000
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		6200000000000000000000000000000000000000000000000000000000000000
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		0000000000000000000000000000000000000000000000000000000000000020
+    		0000000000000000000000000000000000000000000000000000000000000001
+    		0400000000000000000000000000000000000000000000000000000000000000
+    	"
+        )
+        .to_vec();
+        assert_eq!(encoded, expected);
+        assert_eq!(encoded.len(), MyTy::encoded_size(&data));
+        assert_eq!(MyTy::decode_params(&encoded, true).unwrap(), data);
+    }
 }
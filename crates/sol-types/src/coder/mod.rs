@@ -33,4 +33,6 @@ pub use encoder::{encode, encode_params, encode_single, Encoder};
 mod decoder;
 pub use decoder::{decode, decode_params, decode_single, Decoder};
 
+pub mod ser;
+
 pub mod token;
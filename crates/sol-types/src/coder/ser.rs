@@ -0,0 +1,181 @@
+//! Low-level ABI word reading and writing.
+//!
+//! The [`Decoder`](crate::Decoder) reads words, offsets and padding with
+//! these helpers, and integer tokens are encoded with [`encode_uint_to_word`].
+//! They are exposed for implementors of custom
+//! [`SolType`](crate::SolType)s whose Rust representation is not covered by
+//! the types in [`sol_data`](crate::sol_data), such as a fixed-point decimal
+//! type that is stored on-chain as a `uint256`.
+//!
+//! All readers operate on a raw ABI blob and a byte offset into it. They never
+//! panic: out-of-bounds reads are reported as [`Error::Overrun`]. Mirroring
+//! the [`Decoder`](crate::Decoder), the `validate` flag enables the additional
+//! checks described in the [crate-level documentation](crate#decoding-modes).
+
+use crate::{utils, Error, Result, Word};
+use alloc::borrow::Cow;
+use alloy_primitives::U256;
+
+/// Encodes an unsigned integer as a big-endian, left-padded ABI word.
+///
+/// This is the encoding of every `uintN` type, regardless of `N`. It is the
+/// caller's responsibility to ensure that `value` fits in `N` bits, as a
+/// validating decoder will reject words with non-zero high bits.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_primitives::{b256, U256};
+/// use alloy_sol_types::abi::ser::encode_uint_to_word;
+///
+/// assert_eq!(
+///     encode_uint_to_word(U256::from(0x1234)),
+///     b256!("0000000000000000000000000000000000000000000000000000000000001234")
+/// );
+/// ```
+#[inline]
+pub fn encode_uint_to_word(value: U256) -> Word {
+    Word::from(value.to_be_bytes::<32>())
+}
+
+/// Reads the word at byte offset `at` of `data`.
+///
+/// # Errors
+///
+/// Returns [`Error::Overrun`] if `data` does not contain 32 bytes starting at
+/// `at`.
+#[inline]
+pub fn read_word(data: &[u8], at: usize) -> Result<Word> {
+    at.checked_add(Word::len_bytes())
+        .and_then(|end| data.get(at..end))
+        .map(Word::from_slice)
+        .ok_or(Error::Overrun)
+}
+
+/// Reads the word at byte offset `at` of `data` and interprets it as an offset
+/// to the tail of a dynamic value.
+///
+/// Offsets are relative to the start of the enclosing tuple, so `data` must
+/// begin at that tuple, which is also how the [`Decoder`](crate::Decoder)
+/// tracks them. The returned offset is always within the bounds of `data`, so
+/// it can be passed to [`read_len_prefixed`] or used to slice `data` directly.
+///
+/// # Errors
+///
+/// Returns [`Error::Overrun`] if the word cannot be read or if it points past
+/// the end of `data`. If `validate` is true, also returns an error if the word
+/// does not fit in a `uint32`; otherwise its high bytes are ignored.
+#[inline]
+pub fn read_offset(data: &[u8], at: usize, validate: bool) -> Result<usize> {
+    let offset = utils::as_u32(read_word(data, at)?, validate)? as usize;
+    if offset > data.len() {
+        return Err(Error::Overrun)
+    }
+    Ok(offset)
+}
+
+/// Reads a length-prefixed byte sequence starting at byte offset `offset` of
+/// `data`, as used by `bytes` and `string`.
+///
+/// The sequence is one word holding its length `len`, followed by `len` bytes
+/// that are right-padded with zeroes to a multiple of 32 bytes. This is
+/// typically called with the result of [`read_offset`].
+///
+/// # Errors
+///
+/// Returns [`Error::Overrun`] if `data` is too short to contain the length
+/// word or the `len` bytes. If `validate` is true, also returns an error if
+/// the length does not fit in a `uint32`, if the padding is missing, or if it
+/// is not all zeroes.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{abi::ser, sol_data, SolType};
+///
+/// let data = sol_data::Bytes::encode_single(&vec![1, 2, 3]);
+/// let offset = ser::read_offset(&data, 0, true)?;
+/// assert_eq!(ser::read_len_prefixed(&data, offset, true)?, [1, 2, 3]);
+/// # Ok::<_, alloy_sol_types::Error>(())
+/// ```
+pub fn read_len_prefixed(data: &[u8], offset: usize, validate: bool) -> Result<&[u8]> {
+    let len = utils::as_u32(read_word(data, offset)?, validate)? as usize;
+    let start = offset + Word::len_bytes();
+    let end = start.checked_add(len).ok_or(Error::Overrun)?;
+    let bytes = data.get(start..end).ok_or(Error::Overrun)?;
+    if validate {
+        check_padding(data, start, len)?;
+    }
+    Ok(bytes)
+}
+
+/// Checks that the `len` bytes starting at byte offset `start` of `data` are
+/// right-padded with zeroes to a multiple of 32 bytes.
+pub(crate) fn check_padding(data: &[u8], start: usize, len: usize) -> Result<()> {
+    let padding = start
+        .checked_add(len)
+        .zip(start.checked_add(utils::next_multiple_of_32(len)))
+        .and_then(|(end, padded_end)| data.get(end..padded_end))
+        .ok_or(Error::Overrun)?;
+    if !utils::check_zeroes(padding) {
+        return Err(Error::Other(Cow::Borrowed(
+            "Non-empty bytes after packed array",
+        )))
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sol_data, SolType};
+    use alloc::string::ToString;
+    use alloy_primitives::hex;
+
+    #[test]
+    fn uint_word() {
+        let value = U256::from(0xdeadbeefu64);
+        let word = encode_uint_to_word(value);
+        assert_eq!(word, sol_data::Uint::<256>::encode_single(&value)[..]);
+        assert_eq!(U256::from_be_bytes(word.0), value);
+    }
+
+    #[test]
+    fn read_words() {
+        let data = sol_data::String::encode_single(&"hello".to_string());
+        assert_eq!(read_offset(&data, 0, true), Ok(32));
+        assert_eq!(read_len_prefixed(&data, 32, true), Ok(&b"hello"[..]));
+
+        assert_eq!(read_word(&data, data.len() - 31), Err(Error::Overrun));
+        assert_eq!(read_word(&data, usize::MAX), Err(Error::Overrun));
+        assert_eq!(
+            read_len_prefixed(&data[..64], 32, true),
+            Err(Error::Overrun)
+        );
+    }
+
+    #[test]
+    fn read_invalid() {
+        // offset past the end
+        let data = hex!("0000000000000000000000000000000000000000000000000000000000000040");
+        assert_eq!(read_offset(&data, 0, false), Err(Error::Overrun));
+
+        // dirty high bytes are only accepted in lenient mode
+        let data = hex!(
+            "0100000000000000000000000000000000000000000000000000000000000003"
+            "6162630000000000000000000000000000000000000000000000000000000000"
+        );
+        assert!(read_len_prefixed(&data, 0, true).is_err());
+        assert_eq!(read_len_prefixed(&data, 0, false), Ok(&b"abc"[..]));
+
+        // missing or dirty padding
+        let data = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000003"
+            "6162630000000000000000000000000000000000000000000000000000000001"
+        );
+        assert!(read_len_prefixed(&data, 0, true).is_err());
+        assert_eq!(read_len_prefixed(&data, 0, false), Ok(&b"abc"[..]));
+        assert_eq!(read_len_prefixed(&data[..35], 0, true), Err(Error::Overrun));
+        assert_eq!(read_len_prefixed(&data[..35], 0, false), Ok(&b"abc"[..]));
+    }
+}
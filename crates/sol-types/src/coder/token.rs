@@ -16,6 +16,7 @@
 //! - Tuples (T, U, V, ...)
 //! - Dynamic-length byte arrays `u8[]`
 
+use super::ser;
 use crate::{Decoder, Encoder, Result, Word};
 use alloc::vec::Vec;
 use alloy_primitives::{FixedBytes, I256, U256};
@@ -108,7 +109,7 @@ impl From<bool> for WordToken {
 impl From<U256> for WordToken {
     #[inline]
     fn from(value: U256) -> Self {
-        Self(value.into())
+        Self(ser::encode_uint_to_word(value))
     }
 }
 
@@ -225,7 +226,13 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
             dec.raw_child()
         };
 
-        Self::decode_sequence(&mut child)
+        let res = Self::decode_sequence(&mut child)?;
+
+        if !Self::DYNAMIC {
+            dec.take_offset(child);
+        }
+
+        Ok(res)
     }
 
     #[inline]
@@ -240,7 +247,7 @@ impl<'de, T: TokenType<'de>, const N: usize> TokenType<'de> for FixedSeqToken<T,
     #[inline]
     fn tail_words(&self) -> usize {
         if Self::DYNAMIC {
-            N
+            self.0.iter().map(TokenType::total_words).sum()
         } else {
             0
         }
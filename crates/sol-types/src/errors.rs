@@ -8,13 +8,14 @@
 // except according to those terms.
 
 use alloc::{borrow::Cow, string::String};
+use alloy_primitives::B256;
 use core::fmt;
 
 /// ABI result type.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// ABI Encoding and Decoding errors.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Error {
     /// A typecheck detected a word that does not match the data type.
     TypeCheckFail {
@@ -48,6 +49,28 @@ pub enum Error {
         selector: alloy_primitives::FixedBytes<4>,
     },
 
+    /// The number of topics in a log does not match the event.
+    TopicLengthMismatch {
+        /// The expected number of topics.
+        expected: usize,
+        /// The actual number of topics.
+        actual: usize,
+    },
+
+    /// The first topic of a log does not match the event's signature hash.
+    EventSignatureMismatch {
+        /// The event's signature hash.
+        expected: B256,
+        /// The log's first topic.
+        got: B256,
+    },
+
+    /// A log could not be decoded as any of the events in a collection.
+    InvalidLog {
+        /// The collection's name.
+        name: &'static str,
+    },
+
     /// Hex error.
     FromHexError(hex::FromHexError),
 
@@ -84,6 +107,16 @@ impl fmt::Display for Error {
             Self::UnknownSelector { name, selector } => {
                 write!(f, "Unknown selector `{selector}` for {name}")
             }
+            Self::TopicLengthMismatch { expected, actual } => {
+                write!(f, "Invalid topic count: expected {expected}, got {actual}")
+            }
+            Self::EventSignatureMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Invalid event signature: expected `{expected}`, got `{got}`"
+                )
+            }
+            Self::InvalidLog { name } => write!(f, "Could not decode log as any {name} variant"),
             Self::FromHexError(e) => e.fmt(f),
             Self::Other(e) => f.write_str(e),
         }
@@ -43,6 +43,35 @@
 //! # }
 //! ```
 //!
+//! ## Decoding modes
+//!
+//! All decoding functions take a `validate` flag, which selects between two
+//! modes:
+//!
+//! - strict (`true`): the input must be exactly the canonical encoding of the
+//!   decoded values. Trailing bytes, non-zero padding, values outside the range
+//!   of their type, and offsets or lengths with bits set above the low 32 are
+//!   rejected. Use this when the input must be well-formed, e.g. when
+//!   validating data which will be hashed or compared byte-for-byte.
+//! - lenient (`false`): anything that can be decoded is accepted. Trailing
+//!   bytes and padding are ignored, values are truncated to the range of their
+//!   type, and only the low 32 bits of offsets and lengths are used. Use this
+//!   to decode data from untrusted or misbehaving contracts, e.g. when
+//!   inspecting transaction traces.
+//!
+//! ```
+//! use alloy_sol_types::{sol_data::*, SolType};
+//!
+//! let mut encoded = Uint::<8>::encode_single(&42);
+//! encoded.extend_from_slice(&[0; 32]);
+//! assert_eq!(Uint::<8>::decode_single(&encoded, false), Ok(42));
+//! assert!(Uint::<8>::decode_single(&encoded, true).is_err());
+//!
+//! encoded[0] = 1;
+//! assert_eq!(Uint::<8>::decode_single(&encoded, false), Ok(42));
+//! assert!(Uint::<8>::decode_single(&encoded[..32], true).is_err());
+//! ```
+//!
 //! ## [`sol!`]
 //!
 //! The [`sol!`] procedural macro provides a convenient way to define
@@ -175,6 +204,11 @@ pub use coder::{
 #[doc(hidden)]
 pub use coder::{Decoder, Encoder};
 
+/// Low-level ABI coding primitives.
+pub mod abi {
+    pub use crate::coder::ser;
+}
+
 mod errors;
 pub use errors::{Error, Result};
 
@@ -182,12 +216,16 @@ mod impl_core;
 
 mod types;
 pub use types::{
-    data_type as sol_data, ContractError, Encodable, EventTopic, Panic, PanicKind, Revert,
-    Selectors, SolCall, SolEnum, SolError, SolEvent, SolInterface, SolStruct, SolType, TopicList,
+    data_type as sol_data, decode_revert, BorrowedSolType, ContractError, Encodable, EventTopic,
+    GenericContractError, Panic, PanicKind, Revert, Selectors, SolCall, SolEnum, SolError,
+    SolEvent, SolInterface, SolStruct, SolType, TopicList,
 };
 
 pub mod utils;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
 mod eip712;
 pub use eip712::Eip712Domain;
 
@@ -195,20 +233,26 @@ pub use eip712::Eip712Domain;
 pub type Word = alloy_primitives::B256;
 
 #[doc(no_inline)]
-pub use alloy_sol_macro::sol;
+pub use alloy_sol_macro::{sol, SolStruct};
 
 // Not public API.
 #[doc(hidden)]
 pub mod private {
-    pub use super::utils::{just_ok, next_multiple_of_32, words_for, words_for_len};
+    pub use super::{
+        types::Renamed,
+        utils::{just_ok, next_multiple_of_32, words_for, words_for_len},
+    };
     pub use alloc::{
         borrow::{Borrow, Cow, ToOwned},
         string::{String, ToString},
         vec::Vec,
     };
-    pub use alloy_primitives::{bytes, keccak256, Bytes, FixedBytes, B256, U256};
+    pub use alloy_primitives::{bytes, keccak256, Bytes, FixedBytes, Log, LogData, B256, U256};
     pub use core::{convert::From, default::Default, option::Option, result::Result};
 
+    #[cfg(feature = "arbitrary")]
+    pub use {arbitrary, proptest};
+
     pub use Option::{None, Some};
     pub use Result::{Err, Ok};
 
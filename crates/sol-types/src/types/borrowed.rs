@@ -0,0 +1,234 @@
+use crate::{sol_data::*, token::*, Result, SolType};
+use alloc::vec::Vec;
+
+/// A [`SolType`] which can be decoded into a view borrowing from the input
+/// buffer.
+///
+/// `bytes` and `string` decode into `&[u8]` and `&str`, and arrays and tuples
+/// of these decode into collections of borrowed views, which avoids copying
+/// their contents. All other types decode into their owned
+/// [`RustType`](SolType::RustType).
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{sol_data::*, BorrowedSolType, SolType};
+///
+/// type MyTuple = (Uint<64>, String, Array<Bytes>);
+///
+/// let data = MyTuple::encode(&(
+///     42,
+///     "hello".to_string(),
+///     vec![vec![1, 2, 3], vec![]],
+/// ));
+///
+/// let (a, b, c) = MyTuple::decode_borrowed(&data, true)?;
+/// assert_eq!(a, 42);
+/// assert_eq!(b, "hello");
+/// assert_eq!(c, [&[1, 2, 3][..], &[]]);
+/// # Ok::<_, alloy_sol_types::Error>(())
+/// ```
+pub trait BorrowedSolType: SolType {
+    /// The borrowed Rust type.
+    type Borrowed<'a>;
+
+    /// Detokenize into the borrowed Rust type.
+    ///
+    /// Unlike [`SolType::detokenize`], this fails if a `string` is not valid
+    /// UTF-8, as it cannot be lossily converted without allocating.
+    fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>>;
+
+    /// Decode a borrowed Rust type from an ABI blob.
+    #[inline]
+    fn decode_borrowed<'de>(data: &'de [u8], validate: bool) -> Result<Self::Borrowed<'de>>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let decoded = crate::decode::<Self::TokenType<'_>>(data, validate)?;
+        if validate {
+            Self::type_check(&decoded)?;
+        }
+        Self::detokenize_borrowed(decoded)
+    }
+
+    /// Decode a borrowed Rust type from an ABI blob.
+    #[inline]
+    fn decode_params_borrowed<'de>(data: &'de [u8], validate: bool) -> Result<Self::Borrowed<'de>>
+    where
+        Self::TokenType<'de>: TokenSeq<'de>,
+    {
+        let decoded = crate::decode_params::<Self::TokenType<'_>>(data, validate)?;
+        if validate {
+            Self::type_check(&decoded)?;
+        }
+        Self::detokenize_borrowed(decoded)
+    }
+
+    /// Decode a borrowed Rust type from an ABI blob.
+    #[inline]
+    fn decode_single_borrowed(data: &[u8], validate: bool) -> Result<Self::Borrowed<'_>> {
+        let decoded = crate::decode_single::<Self::TokenType<'_>>(data, validate)?;
+        if validate {
+            Self::type_check(&decoded)?;
+        }
+        Self::detokenize_borrowed(decoded)
+    }
+}
+
+macro_rules! owned_impls {
+    ($([$($gen:tt)*] $ty:ty $(where [$($bounds:tt)*])?;)+) => {$(
+        impl<$($gen)*> BorrowedSolType for $ty $(where $($bounds)*)? {
+            type Borrowed<'a> = <Self as SolType>::RustType;
+
+            #[inline]
+            fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+                Ok(<Self as SolType>::detokenize(token))
+            }
+        }
+    )+};
+}
+
+owned_impls! {
+    [] Bool;
+    [] Address;
+    [const N: usize] FixedBytes<N> where [ByteCount<N>: SupportedFixedBytes];
+    [const BITS: usize] Int<BITS> where [IntBitCount<BITS>: SupportedInt];
+    [const BITS: usize] Uint<BITS> where [IntBitCount<BITS>: SupportedInt];
+}
+
+impl BorrowedSolType for Bytes {
+    type Borrowed<'a> = &'a [u8];
+
+    #[inline]
+    fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+        Ok(token.0)
+    }
+}
+
+impl BorrowedSolType for String {
+    type Borrowed<'a> = &'a str;
+
+    #[inline]
+    fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+        core::str::from_utf8(token.0).map_err(|_| Self::type_check_fail(token.0))
+    }
+}
+
+impl<T: BorrowedSolType> BorrowedSolType for Array<T> {
+    type Borrowed<'a> = Vec<T::Borrowed<'a>>;
+
+    #[inline]
+    fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+        token.0.into_iter().map(T::detokenize_borrowed).collect()
+    }
+}
+
+impl<T: BorrowedSolType, const N: usize> BorrowedSolType for FixedArray<T, N> {
+    type Borrowed<'a> = [T::Borrowed<'a>; N];
+
+    #[inline]
+    fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+        let mut err = None;
+        let items = token.0.map(|t| match T::detokenize_borrowed(t) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                err.get_or_insert(e);
+                None
+            }
+        });
+        match err {
+            // all items are `Some` if no error occurred
+            None => Ok(items.map(Option::unwrap)),
+            Some(e) => Err(e),
+        }
+    }
+}
+
+impl BorrowedSolType for () {
+    type Borrowed<'a> = ();
+
+    #[inline]
+    fn detokenize_borrowed(_token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+        Ok(())
+    }
+}
+
+macro_rules! tuple_impls {
+    ($($ty:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($ty: BorrowedSolType,)+> BorrowedSolType for ($($ty,)+) {
+            type Borrowed<'a> = ($( $ty::Borrowed<'a>, )+);
+
+            #[inline]
+            fn detokenize_borrowed(token: Self::TokenType<'_>) -> Result<Self::Borrowed<'_>> {
+                let ($($ty,)+) = token;
+                Ok(($(
+                    <$ty as BorrowedSolType>::detokenize_borrowed($ty)?,
+                )+))
+            }
+        }
+    };
+}
+
+all_the_tuples!(tuple_impls);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use alloc::{borrow::ToOwned, string::ToString, vec};
+    use alloy_primitives::U256;
+
+    #[test]
+    fn borrowed_views() {
+        type T = (Bytes, String, FixedArray<String, 2>, Array<Bytes>);
+        let data = T::encode(&(
+            vec![1, 2, 3],
+            "hello".to_owned(),
+            ["a".to_owned(), "b".to_owned()],
+            vec![vec![4], vec![]],
+        ));
+
+        let (bytes, string, fixed, array) = T::decode_borrowed(&data, true).unwrap();
+        assert_eq!(bytes, [1, 2, 3]);
+        assert_eq!(string, "hello");
+        assert_eq!(fixed, ["a", "b"]);
+        assert_eq!(array, [&[4][..], &[]]);
+
+        // the views point into the input buffer
+        let range = data.as_ptr_range();
+        assert!(range.contains(&bytes.as_ptr()));
+        assert!(range.contains(&string.as_ptr()));
+        assert!(range.contains(&fixed[1].as_ptr()));
+    }
+
+    #[test]
+    fn decode_variants() {
+        let data = String::encode_single(&"hello".to_string());
+        assert_eq!(
+            String::decode_single_borrowed(&data, true).unwrap(),
+            "hello"
+        );
+
+        type P = (Uint<256>, String);
+        let params = P::encode_params(&(U256::from(7), "world".to_string()));
+        assert_eq!(
+            P::decode_params_borrowed(&params, true).unwrap(),
+            (U256::from(7), "world")
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let data = Bytes::encode_single(&vec![0xff, 0xfe]);
+        assert!(matches!(
+            String::decode_single_borrowed(&data, false),
+            Err(Error::TypeCheckFail { .. })
+        ));
+        let data = FixedArray::<Bytes, 1>::encode_single(&[vec![0xff]]);
+        assert!(matches!(
+            FixedArray::<String, 1>::decode_single_borrowed(&data, false),
+            Err(Error::TypeCheckFail { .. })
+        ));
+    }
+}
@@ -325,11 +325,24 @@ impl<T: SolType> SolType for Array<T> {
     #[inline]
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         for item in rust {
-            T::encode_packed_to(item, out);
+            encode_packed_array_element::<T>(item, out);
         }
     }
 }
 
+/// Packed-encodes an array element.
+///
+/// Unlike top-level values, static array elements are padded to 32 bytes, so
+/// their packed encoding is the same as their standard ABI encoding.
+#[inline]
+fn encode_packed_array_element<T: SolType>(item: &T::RustType, out: &mut Vec<u8>) {
+    if T::DYNAMIC {
+        T::encode_packed_to(item, out);
+    } else {
+        out.extend_from_slice(&T::encode_single(item));
+    }
+}
+
 /// String - `string`
 pub struct String;
 
@@ -508,7 +521,7 @@ impl<T: SolType, const N: usize> SolType for FixedArray<T, N> {
     #[inline]
     fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
         for item in rust {
-            T::encode_packed_to(item, out);
+            encode_packed_array_element::<T>(item, out);
         }
     }
 }
@@ -1040,8 +1053,10 @@ mod tests {
         let token = WordToken::new(word);
         macro_rules! test {
             ($($n:literal => $x:expr),+ $(,)?) => {$(
-                assert_eq!(<Uint<$n>>::detokenize(token), $x);
-                assert_eq!(<Int<$n>>::detokenize(token), $x);
+                let uint: <Uint<$n> as SolType>::RustType = $x;
+                assert_eq!(<Uint<$n>>::detokenize(token), uint);
+                let int: <Int<$n> as SolType>::RustType = $x;
+                assert_eq!(<Int<$n>>::detokenize(token), int);
             )+};
         }
         #[rustfmt::skip]
@@ -1166,4 +1181,38 @@ mod tests {
         assert_eq!(<Int<248>>::detokenize(token), "0xff82038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
         assert_eq!(<Int<256>>::detokenize(token), "0x0182038405860788098a0b8c0d8e0f901192139415961798199a1b9c1d9e1fa0".as_u256_as_i256());
     }
+
+    #[test]
+    fn encode_packed() {
+        use alloy_primitives::hex;
+
+        // https://docs.soliditylang.org/en/latest/abi-spec.html#non-standard-packed-mode
+        type MyTy = (Int<16>, FixedBytes<1>, Uint<16>, String);
+        let value = (-1, [0x42], 0x03, "Hello, world!".into());
+        assert_eq!(
+            MyTy::encode_packed(&value),
+            hex!("ffff42000348656c6c6f2c20776f726c6421")
+        );
+
+        // array elements are padded
+        assert_eq!(
+            <Array<Uint<8>>>::encode_packed(&vec![1, 2]),
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+            )
+        );
+        assert_eq!(<FixedArray<Int<8>, 1>>::encode_packed(&[-1]), [0xff; 32]);
+        assert_eq!(
+            <Array<FixedBytes<2>>>::encode_packed(&vec![[0x12, 0x34]]),
+            hex!("1234000000000000000000000000000000000000000000000000000000000000")
+        );
+        assert_eq!(
+            <Array<FixedArray<Bool, 2>>>::encode_packed(&vec![[true, false]]),
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000000"
+            )
+        );
+    }
 }
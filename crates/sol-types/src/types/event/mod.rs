@@ -1,9 +1,9 @@
 use crate::{
     token::{TokenSeq, WordToken},
-    Result, SolType, TokenType, Word,
+    Error, Result, SolType, TokenType, Word,
 };
 use alloc::vec::Vec;
-use alloy_primitives::{FixedBytes, B256};
+use alloy_primitives::{FixedBytes, LogData, B256};
 
 mod topic;
 pub use topic::EventTopic;
@@ -141,14 +141,56 @@ pub trait SolEvent: Sized {
         <Self::DataTuple<'a> as SolType>::decode(data, validate)
     }
 
+    /// Check that the decoded topics match this event's signature hash.
+    ///
+    /// This is a no-op for anonymous events, which do not include their
+    /// signature hash in the topics.
+    #[inline]
+    fn check_signature(_topics: &<Self::TopicList as SolType>::RustType) -> Result<()> {
+        Ok(())
+    }
+
     /// Decode the event from the given log info.
+    ///
+    /// The first topic is checked against
+    /// [`SIGNATURE_HASH`][Self::SIGNATURE_HASH] for non-anonymous events.
     fn decode_log<I, D>(topics: I, data: &[u8], validate: bool) -> Result<Self>
     where
         I: IntoIterator<Item = D>,
         D: Into<WordToken>,
     {
         let topics = Self::decode_topics(topics)?;
+        Self::check_signature(&topics)?;
         let body = Self::decode_data(data, validate)?;
         Ok(Self::new(topics, body))
     }
+
+    /// Decode the event from a raw log's topics and data.
+    ///
+    /// Unlike [`decode_log`][Self::decode_log], this also checks that the
+    /// number of topics matches the event's exactly.
+    ///
+    /// Indexed parameters of dynamic types are not recoverable from the log,
+    /// so they are decoded as the keccak256 hash of their encoding.
+    #[inline]
+    fn decode_raw_log(topics: &[B256], data: &[u8], validate: bool) -> Result<Self> {
+        let expected = Self::TopicList::COUNT;
+        if topics.len() != expected {
+            return Err(Error::TopicLengthMismatch {
+                expected,
+                actual: topics.len(),
+            })
+        }
+        Self::decode_log(topics.iter().copied(), data, validate)
+    }
+
+    /// Decode the event from a [`LogData`], like
+    /// [`decode_raw_log`][Self::decode_raw_log].
+    ///
+    /// Generated events also implement `TryFrom` for [`LogData`] and
+    /// [`Log`](alloy_primitives::Log), which decode in strict mode.
+    #[inline]
+    fn decode_log_data(log: &LogData, validate: bool) -> Result<Self> {
+        Self::decode_raw_log(log.topics(), &log.data, validate)
+    }
 }
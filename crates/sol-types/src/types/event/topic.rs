@@ -1,4 +1,4 @@
-use crate::{sol_data::*, token::WordToken, SolType};
+use crate::{sol_data::*, token::WordToken, types::Renamed, SolType};
 use alloc::vec::Vec;
 use alloy_primitives::keccak256;
 
@@ -87,6 +87,15 @@ where
     word_impl!(FixedBytes<N>);
 }
 
+impl<S, T> EventTopic for Renamed<S, T>
+where
+    S: for<'a> SolType<TokenType<'a> = WordToken>,
+    T: Copy + TryFrom<S::RustType> + 'static,
+    S::RustType: TryFrom<T>,
+{
+    word_impl!(Renamed<S, T>);
+}
+
 // Bytes-like types - preimage encoding: bytes padded to 32; hash: the bytes
 macro_rules! bytes_impl {
     ($t:ty) => {
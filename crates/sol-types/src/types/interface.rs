@@ -1,6 +1,6 @@
 use crate::{Panic, Result, Revert, SolError};
 use alloc::vec::Vec;
-use core::{fmt, iter::FusedIterator, marker::PhantomData};
+use core::{convert::Infallible, fmt, iter::FusedIterator, marker::PhantomData};
 
 /// A collection of ABI-encoded call-like types. This currently includes
 /// [`SolCall`] and [`SolError`].
@@ -77,9 +77,96 @@ pub trait SolInterface: Sized {
     }
 }
 
+/// An empty [`SolInterface`] with no variants.
+///
+/// Used as [`ContractError<Infallible>`](GenericContractError) when there are
+/// no custom errors to decode.
+impl SolInterface for Infallible {
+    const NAME: &'static str = "GenericContractError";
+
+    const MIN_DATA_LENGTH: usize = 0;
+    const COUNT: usize = 0;
+
+    #[inline]
+    fn selector(&self) -> [u8; 4] {
+        match *self {}
+    }
+
+    #[inline]
+    fn selector_at(_i: usize) -> Option<[u8; 4]> {
+        None
+    }
+
+    #[inline]
+    fn type_check(selector: [u8; 4]) -> Result<()> {
+        Err(crate::Error::unknown_selector(Self::NAME, selector))
+    }
+
+    #[inline]
+    fn decode_raw(selector: [u8; 4], _data: &[u8], _validate: bool) -> Result<Self> {
+        Self::type_check(selector).map(|()| unreachable!())
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        match *self {}
+    }
+
+    #[inline]
+    fn encode_raw(&self, _out: &mut Vec<u8>) {
+        match *self {}
+    }
+}
+
+/// A [`ContractError`] which can only be a [`Revert`] or a [`Panic`].
+pub type GenericContractError = ContractError<Infallible>;
+
+/// Decodes revert data into a [`Revert`], a [`Panic`], or one of the custom
+/// errors in `T`.
+///
+/// Use [`GenericContractError`] (`T = Infallible`) if there are no custom
+/// errors to decode.
+///
+/// # Errors
+///
+/// Returns an error if the selector is unknown, or if the data could not be
+/// decoded as the error it selects.
+///
+/// # Examples
+///
+/// ```
+/// use alloy_sol_types::{decode_revert, sol, ContractError, PanicKind, SolError};
+/// use core::convert::Infallible;
+///
+/// sol! {
+///     contract Vault {
+///         error InsufficientBalance(uint256 available, uint256 required);
+///         error Unauthorized();
+///     }
+/// }
+///
+/// let data = alloy_sol_types::Revert::from("not enough").encode();
+/// let err = decode_revert::<Infallible>(&data, true).unwrap();
+/// assert_eq!(err.to_string(), "Revert: not enough");
+///
+/// let data = alloy_sol_types::Panic::from(PanicKind::DivisionByZero).encode();
+/// let err = decode_revert::<Vault::VaultErrors>(&data, true).unwrap();
+/// assert_eq!(err.as_panic().unwrap().kind(), Some(PanicKind::DivisionByZero));
+///
+/// let data = Vault::Unauthorized {}.encode();
+/// let err = decode_revert::<Vault::VaultErrors>(&data, true).unwrap();
+/// assert!(matches!(err, ContractError::CustomError(Vault::VaultErrors::Unauthorized(_))));
+/// ```
+#[inline]
+pub fn decode_revert<T: SolInterface>(data: &[u8], validate: bool) -> Result<ContractError<T>> {
+    ContractError::decode(data, validate)
+}
+
 /// A generic contract error.
 ///
 /// Contains a [`Revert`] or [`Panic`] error, or a custom error.
+///
+/// See [`decode_revert`] for decoding revert data into this type.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ContractError<T> {
     /// A contract's custom error.
@@ -137,7 +224,7 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
         match selector {
             Revert::SELECTOR => Revert::decode_raw(data, validate).map(Self::Revert),
             Panic::SELECTOR => Panic::decode_raw(data, validate).map(Self::Panic),
-            _ => T::decode(data, validate).map(Self::CustomError),
+            _ => T::decode_raw(selector, data, validate).map(Self::CustomError),
         }
     }
 
@@ -160,6 +247,16 @@ impl<T: SolInterface> SolInterface for ContractError<T> {
     }
 }
 
+impl<T: fmt::Debug> fmt::Display for ContractError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CustomError(error) => write!(f, "Custom error: {error:?}"),
+            Self::Revert(revert) => revert.fmt(f),
+            Self::Panic(panic) => panic.fmt(f),
+        }
+    }
+}
+
 impl<T: SolInterface> From<T> for ContractError<T> {
     #[inline]
     fn from(value: T) -> Self {
@@ -357,7 +454,8 @@ impl<T: SolInterface> FusedIterator for Selectors<T> {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::keccak256;
+    use alloc::string::ToString;
+    use alloy_primitives::{keccak256, U256};
 
     fn sel(s: &str) -> [u8; 4] {
         keccak256(s)[..4].try_into().unwrap()
@@ -389,4 +487,48 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn decode_revert() {
+        crate::sol! {
+            #[derive(Debug, PartialEq)]
+            contract C {
+                error Err1();
+                error Err2(uint256);
+            }
+        }
+        use C::{CErrors, Err1, Err2};
+
+        let data = Revert::from("message").encode();
+        let err = super::decode_revert::<CErrors>(&data, true).unwrap();
+        assert_eq!(err, ContractError::Revert("message".into()));
+        assert_eq!(err.to_string(), "Revert: message");
+
+        let data = Panic::from(crate::PanicKind::UnderOverflow).encode();
+        let err = super::decode_revert::<CErrors>(&data, true).unwrap();
+        assert_eq!(err.to_string(), "Panic: arithmetic underflow or overflow");
+        let err = GenericContractError::decode(&data, true).unwrap();
+        assert!(err.is_panic());
+
+        let data = Err2 { _0: U256::from(1) }.encode();
+        let err = super::decode_revert::<CErrors>(&data, true).unwrap();
+        assert_eq!(
+            err,
+            ContractError::CustomError(CErrors::Err2(Err2 { _0: U256::from(1) }))
+        );
+        assert_eq!(
+            GenericContractError::decode(&data, true),
+            Err(crate::Error::unknown_selector(
+                "GenericContractError",
+                sel("Err2(uint256)")
+            ))
+        );
+
+        let data = Err1 {}.encode();
+        let err = super::decode_revert::<CErrors>(&data, true).unwrap();
+        assert_eq!(err.to_string(), "Custom error: Err1(Err1)");
+
+        assert!(super::decode_revert::<CErrors>(&[], true).is_err());
+        assert!(super::decode_revert::<CErrors>(&[1, 2, 3, 4], true).is_err());
+    }
 }
@@ -1,5 +1,8 @@
 pub mod data_type;
 
+mod borrowed;
+pub use borrowed::BorrowedSolType;
+
 mod r#enum;
 pub use r#enum::SolEnum;
 
@@ -13,7 +16,10 @@ mod function;
 pub use function::SolCall;
 
 mod interface;
-pub use interface::{ContractError, Selectors, SolInterface};
+pub use interface::{decode_revert, ContractError, GenericContractError, Selectors, SolInterface};
+
+mod renamed;
+pub use renamed::Renamed;
 
 mod r#struct;
 pub use r#struct::SolStruct;
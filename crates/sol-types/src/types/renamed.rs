@@ -0,0 +1,86 @@
+#![allow(missing_copy_implementations, missing_debug_implementations)]
+
+use crate::{token::WordToken, Encodable, Result, SolType, Word};
+use alloc::{borrow::Cow, vec::Vec};
+use core::marker::PhantomData;
+
+/// A Solidity value type `S` represented by the Rust type `T` instead of
+/// `S::RustType`.
+///
+/// This is used by [`sol!`](crate::sol) to implement
+/// `#[sol(rename_type(...))]`. `T` is converted to and from `S::RustType` with
+/// `TryFrom`, which is also implemented by all `From` conversions.
+///
+/// Decoded values which do not fit in `T` fail the type check, so they are
+/// rejected when decoding with validation, and cause a panic otherwise.
+/// Encoding a value which does not fit in `S::RustType` panics.
+pub struct Renamed<S, T>(PhantomData<(S, T)>);
+
+impl<S, T> Encodable<Renamed<S, T>> for T
+where
+    S: for<'a> SolType<TokenType<'a> = WordToken>,
+    T: Copy + TryFrom<S::RustType> + 'static,
+    S::RustType: TryFrom<T>,
+{
+    #[inline]
+    fn to_tokens(&self) -> WordToken {
+        Encodable::<S>::to_tokens(&to_sol::<S, T>(*self))
+    }
+}
+
+impl<S, T> SolType for Renamed<S, T>
+where
+    S: for<'a> SolType<TokenType<'a> = WordToken>,
+    T: Copy + TryFrom<S::RustType> + 'static,
+    S::RustType: TryFrom<T>,
+{
+    type RustType = T;
+    type TokenType<'a> = WordToken;
+
+    #[inline]
+    fn sol_type_name() -> Cow<'static, str> {
+        S::sol_type_name()
+    }
+
+    #[inline]
+    fn type_check(token: &Self::TokenType<'_>) -> Result<()> {
+        S::type_check(token)?;
+        match T::try_from(S::detokenize(*token)) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(Self::type_check_fail(token.as_slice())),
+        }
+    }
+
+    #[inline]
+    fn detokenize(token: Self::TokenType<'_>) -> Self::RustType {
+        match T::try_from(S::detokenize(token)) {
+            Ok(rust) => rust,
+            Err(_) => panic!(
+                "`{}` value does not fit in the Rust type; decode with validation to get an error instead",
+                S::sol_type_name()
+            ),
+        }
+    }
+
+    #[inline]
+    fn eip712_data_word(rust: &Self::RustType) -> Word {
+        S::eip712_data_word(&to_sol::<S, T>(*rust))
+    }
+
+    #[inline]
+    fn encode_packed_to(rust: &Self::RustType, out: &mut Vec<u8>) {
+        S::encode_packed_to(&to_sol::<S, T>(*rust), out)
+    }
+}
+
+/// Converts a Rust value to the Rust representation of `S`.
+#[inline]
+fn to_sol<S: SolType, T>(rust: T) -> S::RustType
+where
+    S::RustType: TryFrom<T>,
+{
+    match S::RustType::try_from(rust) {
+        Ok(rust) => rust,
+        Err(_) => panic!("Rust value does not fit in `{}`", S::sol_type_name()),
+    }
+}
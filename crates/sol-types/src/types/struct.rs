@@ -2,7 +2,7 @@
 //! Solidity structs logic, particularly for EIP-712 encoding/decoding.
 
 use super::{Encodable, SolType};
-use crate::{token::TokenSeq, Eip712Domain, TokenType, Word};
+use crate::{token::TokenSeq, Eip712Domain, Word};
 use alloc::{borrow::Cow, vec::Vec};
 use alloy_primitives::{keccak256, B256};
 
@@ -60,7 +60,7 @@ pub trait SolStruct: 'static {
             return size
         }
 
-        self.tokenize().total_words() * Word::len_bytes()
+        <Self::Tuple<'_> as SolType>::encoded_size(&self.to_rust())
     }
 
     /// Returns component EIP-712 types. These types are used to construct
@@ -136,6 +136,7 @@ impl<T: SolStruct> SolType for T {
     type RustType = T;
     type TokenType<'a> = TupleTokenTypeFor<'a, T>;
 
+    const ENCODED_SIZE: Option<usize> = TupleFor::<T>::ENCODED_SIZE;
     const DYNAMIC: bool = TupleFor::<T>::DYNAMIC;
 
     #[inline]
@@ -166,7 +167,7 @@ impl<T: SolStruct> SolType for T {
 
     #[inline]
     fn eip712_data_word<'a>(rust: &Self::RustType) -> Word {
-        keccak256(rust.eip712_hash_struct())
+        rust.eip712_hash_struct()
     }
 
     #[inline]
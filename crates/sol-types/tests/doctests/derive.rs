@@ -0,0 +1,60 @@
+use alloy_primitives::{hex, Address, U256};
+use alloy_sol_types::{SolStruct, SolType};
+
+/// An existing domain type, which is encoded as if it was declared in Solidity.
+#[derive(Clone, Debug, PartialEq, SolStruct)]
+struct Order {
+    #[sol(type = "address")]
+    maker: Address,
+    #[sol(type = "uint256")]
+    amount: U256,
+    #[sol(type = "bytes32[]")]
+    tags: Vec<[u8; 32]>,
+}
+
+/// Structs deriving `SolStruct` can be nested.
+#[derive(Clone, Debug, PartialEq, SolStruct)]
+struct Batch {
+    #[sol(type = "Order[]")]
+    orders: Vec<Order>,
+    #[sol(type = "uint64")]
+    deadline: u64,
+}
+
+#[test]
+fn derive() {
+    let order = Order {
+        maker: Address::repeat_byte(0x11),
+        amount: U256::from(42),
+        tags: vec![[0x22; 32]],
+    };
+    assert_eq!(Order::sol_type_name(), "Order");
+    assert_eq!(
+        <Order as SolStruct>::eip712_encode_type(),
+        "Order(address maker,uint256 amount,bytes32[] tags)"
+    );
+
+    let encoded = Order::encode(&order);
+    assert_eq!(
+        encoded,
+        hex! {
+            "0000000000000000000000001111111111111111111111111111111111111111" // maker
+            "000000000000000000000000000000000000000000000000000000000000002a" // amount
+            "0000000000000000000000000000000000000000000000000000000000000060" // tags offset
+            "0000000000000000000000000000000000000000000000000000000000000001" // tags length
+            "2222222222222222222222222222222222222222222222222222222222222222" // tags[0]
+        }
+    );
+    assert_eq!(Order::decode(&encoded, true), Ok(order.clone()));
+
+    let batch = Batch {
+        orders: vec![order],
+        deadline: 1,
+    };
+    assert_eq!(
+        <Batch as SolStruct>::eip712_encode_type(),
+        "Batch(Order[] orders,uint64 deadline)\
+         Order(address maker,uint256 amount,bytes32[] tags)"
+    );
+    assert_eq!(Batch::decode(&Batch::encode(&batch), true), Ok(batch));
+}
@@ -0,0 +1,74 @@
+//! Checks that the code generated by [`sol!`] compiles under strict lints.
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+use alloy_sol_types::sol;
+
+sol! {
+    /// A struct.
+    struct MyStruct {
+        /// A documented field.
+        uint256 a;
+        bytes b;
+    }
+
+    /// An enum.
+    enum MyEnum {
+        A,
+        B,
+    }
+
+    /// A user-defined value type.
+    type MyUdt is uint256;
+
+    /// An error.
+    error MyError(uint256 a, string b);
+
+    /// An event.
+    event MyEvent(address indexed a, uint256 b);
+
+    /// A function.
+    function myFunction(uint256 a, MyStruct b) returns (bool c, bytes d);
+
+    /// A contract.
+    contract MyContract {
+        /// A contract struct.
+        struct ContractStruct {
+            address a;
+        }
+
+        /// A contract user-defined value type.
+        type ContractUdt is bytes32;
+
+        /// A contract enum.
+        enum ContractEnum {
+            X,
+        }
+
+        /// A contract error.
+        error ContractError(uint256 a);
+
+        /// A contract event.
+        event ContractEvent(address indexed a, uint256 b) anonymous;
+
+        /// Another contract error.
+        error OtherError();
+
+        /// Another contract event.
+        event OtherEvent(string indexed a, bytes b);
+
+        /// A contract function.
+        function contractFunction(ContractStruct a, ContractEnum b) external returns (uint256);
+
+        /// Another contract function.
+        function otherFunction(
+            /// A documented argument.
+            uint256 a,
+            bytes memory
+        ) external returns (ContractUdt b);
+    }
+}
+
+#[test]
+fn lints() {}
@@ -0,0 +1,87 @@
+//! Checks that the code generated by [`sol!`] compiles without the standard
+//! library prelude.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec};
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{sol, SolCall, SolError, SolEvent, SolInterface, SolStruct};
+
+sol! {
+    struct MyStruct {
+        uint256 a;
+        bytes b;
+        string[] c;
+    }
+
+    type MyUdt is uint256;
+
+    #[derive(Debug, PartialEq)]
+    contract MyContract {
+        enum MyEnum {
+            A,
+            B,
+        }
+
+        struct ContractStruct {
+            address a;
+            bytes32 b;
+        }
+
+        error MyError(uint256 a, string b);
+        error OtherError();
+
+        event MyEvent(address indexed a, string indexed b, uint256 c);
+        event OtherEvent(bytes b) anonymous;
+
+        function myFunction(uint256 a, ContractStruct b, MyEnum c) external returns (bool d, bytes e);
+        function otherFunction() external;
+    }
+}
+
+#[test]
+fn no_std() {
+    let s = MyStruct {
+        a: U256::from(1),
+        b: vec![2],
+        c: vec![String::from("3")],
+    };
+    let _ = s.eip712_hash_struct();
+
+    let call = MyContract::myFunctionCall {
+        a: U256::from(1),
+        b: MyContract::ContractStruct {
+            a: Address::ZERO,
+            b: [0; 32],
+        },
+        c: MyContract::MyEnum::B,
+    };
+    let data: alloc::vec::Vec<u8> = call.encode();
+    let decoded = MyContract::MyContractCalls::decode(&data, true).unwrap();
+    assert_eq!(decoded, MyContract::MyContractCalls::myFunction(call));
+
+    let error = MyContract::MyError {
+        a: U256::from(1),
+        b: String::from("error"),
+    };
+    let data = error.encode();
+    assert_eq!(
+        MyContract::MyContractErrors::decode(&data, true).unwrap(),
+        MyContract::MyContractErrors::MyError(error)
+    );
+
+    let event = MyContract::MyEvent {
+        a: Address::ZERO,
+        b: Default::default(),
+        c: U256::from(1),
+    };
+    let topics = event.encode_topics_array::<3>();
+    let data = event.encode_data();
+    let topics = topics.map(|t| t.0);
+    assert_eq!(
+        MyContract::MyContractEvents::decode_log(&topics, &data, true).unwrap(),
+        MyContract::MyContractEvents::MyEvent(event)
+    );
+}
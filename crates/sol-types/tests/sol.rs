@@ -1,4 +1,4 @@
-use alloy_primitives::{keccak256, Address, U256};
+use alloy_primitives::{keccak256, Address, I256, U256};
 use alloy_sol_types::{sol, SolCall, SolError, SolType};
 
 #[test]
@@ -128,6 +128,41 @@ fn function() {
     );
 }
 
+#[test]
+fn signed_integers() {
+    sol! {
+        function setPrices(int256 big, int128 medium, int24 small) returns (int256 sum);
+    }
+
+    let call = setPricesCall {
+        big: I256::MIN,
+        medium: -1i128,
+        small: -8_388_608i32,
+    };
+    let encoded = call.encode();
+    assert_eq!(encoded.len(), 4 + 32 * 3);
+    // sign-extended to full words
+    assert_eq!(encoded[4..36], I256::MIN.to_be_bytes::<32>());
+    assert_eq!(encoded[36..68], [0xff; 32]);
+    assert_eq!(
+        encoded[68..100],
+        I256::try_from(-8_388_608i32).unwrap().to_be_bytes::<32>()
+    );
+    let decoded = setPricesCall::decode(&encoded, true).unwrap();
+    assert_eq!(
+        (decoded.big, decoded.medium, decoded.small),
+        (call.big, call.medium, call.small)
+    );
+
+    // values that do not fit in the type are rejected when validating
+    let mut invalid = encoded;
+    invalid[68] = 0x7f;
+    assert!(setPricesCall::decode(&invalid, true).is_err());
+
+    let ret = setPricesCall::decode_returns(&I256::MINUS_ONE.to_be_bytes::<32>(), true);
+    assert_eq!(ret.unwrap().sum, I256::MINUS_ONE);
+}
+
 #[test]
 fn error() {
     sol! {
@@ -162,6 +197,126 @@ fn empty_call() {
     let depositCall {} = depositCall::decode_raw(&[], true).unwrap();
 }
 
+#[test]
+fn degenerate_contracts() {
+    sol! {
+        contract Empty {}
+
+        interface IEmpty {}
+
+        library LEmpty {}
+
+        abstract contract AEmpty {}
+
+        contract OnlyReceive {
+            receive() external payable;
+        }
+
+        contract OnlyFallback {
+            fallback() external payable {}
+        }
+
+        contract OnlyConstructor {
+            constructor(uint256 a) {}
+        }
+
+        #[derive(Debug, PartialEq)]
+        contract OnlyVars {
+            uint256 internal a;
+            address private b;
+            uint256 constant C = 1;
+        }
+    }
+}
+
+#[test]
+fn public_variable_getters() {
+    sol! {
+        contract Getters {
+            uint256 public a;
+            mapping(address owner => mapping(address => uint256)) public allowance;
+            bytes32[][2] public arr;
+        }
+    }
+    use Getters::*;
+
+    assert_eq!(aCall::SIGNATURE, "a()");
+    assert_eq!(allowanceCall::SIGNATURE, "allowance(address,address)");
+    assert_eq!(arrCall::SIGNATURE, "arr(uint256,uint256)");
+
+    let call = allowanceCall {
+        owner: Address::repeat_byte(1),
+        _1: Address::repeat_byte(2),
+    };
+    assert_eq!(call.encoded_size(), 64);
+    let ret = allowanceReturn {
+        allowance: U256::from(3),
+    };
+    let data = allowanceCall::encode_returns(&ret);
+    assert_eq!(data, U256::from(3).to_be_bytes::<32>());
+    let decoded = allowanceCall::decode_returns(&data, true).unwrap();
+    assert_eq!(decoded.allowance, ret.allowance);
+}
+
+mod shared {
+    alloy_sol_types::sol! {
+        struct Order {
+            address maker;
+            uint256 amount;
+        }
+
+        type Price is uint128;
+    }
+}
+
+#[test]
+fn use_crate_types() {
+    sol! {
+        #![sol(use_crate_types(crate::shared))]
+
+        contract Exchange {
+            struct Order {
+                address maker;
+                uint256 amount;
+            }
+
+            type Price is uint128;
+
+            function fill(Order order, Price price) external returns (Order);
+        }
+    }
+
+    // the types are shared, not duplicated
+    let order = shared::Order {
+        maker: Address::repeat_byte(1),
+        amount: U256::from(2),
+    };
+    let _: Exchange::Order = order.clone();
+
+    assert_eq!(
+        Exchange::fillCall::SIGNATURE,
+        "fill((address,uint256),uint128)"
+    );
+    let call = Exchange::fillCall { order, price: 3 };
+    let decoded = Exchange::fillCall::decode(&call.encode(), true).unwrap();
+    assert_eq!(decoded.order.maker, call.order.maker);
+    assert_eq!(decoded.price, 3);
+
+    // the attribute can also be used on individual items
+    sol! {
+        #[sol(use_crate_types(crate::shared))]
+        struct Order {
+            address maker;
+            uint256 amount;
+        }
+
+        struct Local {
+            Order order;
+        }
+    }
+    let _ = Local { order: call.order };
+}
+
 #[test]
 fn function_names() {
     sol! {
@@ -328,6 +483,67 @@ fn abigen_json_seaport() {
     );
 }
 
+#[test]
+#[cfg(feature = "json")]
+fn abigen_json_artifact() {
+    // Hardhat artifact layout
+    sol!(
+        Counter,
+        r#"{
+            "_format": "hh-sol-artifact-1",
+            "contractName": "Counter",
+            "abi": [
+                {
+                    "type": "constructor",
+                    "inputs": [{ "name": "start", "type": "uint256" }],
+                    "stateMutability": "nonpayable"
+                }
+            ],
+            "bytecode": "0x6080",
+            "deployedBytecode": "0x6001",
+            "linkReferences": {},
+            "deployedLinkReferences": {}
+        }"#
+    );
+
+    assert_eq!(Counter::BYTECODE[..], [0x60, 0x80]);
+    assert_eq!(Counter::DEPLOYED_BYTECODE[..], [0x60, 0x01]);
+    let code = Counter::deploy_code(U256::from(1));
+    assert_eq!(code[..2], [0x60, 0x80]);
+    assert_eq!(code[2..], U256::from(1).to_be_bytes::<32>());
+}
+
+#[test]
+fn deploy_code() {
+    sol! {
+        #[sol(bytecode = "0x6080")]
+        contract Token {
+            struct Config {
+                address owner;
+                string name;
+            }
+
+            constructor(Config config, uint8 decimals) {}
+        }
+
+        #[sol(bytecode = "0x60806040")]
+        contract Empty {}
+    }
+
+    let config = Token::Config {
+        owner: Address::repeat_byte(0x11),
+        name: "Token".into(),
+    };
+    let args =
+        <(Token::Config, alloy_sol_types::sol_data::Uint<8>)>::encode_params(&(config.clone(), 18));
+    assert_eq!(
+        Token::deploy_code(config, 18),
+        [&Token::BYTECODE[..], &args[..]].concat()
+    );
+
+    assert_eq!(Empty::deploy_code(), Empty::BYTECODE[..]);
+}
+
 #[test]
 fn eip712_encode_type_nesting() {
     sol! {
@@ -362,3 +578,389 @@ fn eip712_encode_type_nesting() {
         "D(C c,A a,B b)A(uint256 a)B(bytes32 b)C(A a,B b)"
     );
 }
+
+#[test]
+fn rename_type() {
+    use alloy_sol_types::SolStruct;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct MyHash([u8; 32]);
+
+    impl From<[u8; 32]> for MyHash {
+        fn from(value: [u8; 32]) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<MyHash> for [u8; 32] {
+        fn from(value: MyHash) -> Self {
+            value.0
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Amount(U256);
+
+    impl From<U256> for Amount {
+        fn from(value: U256) -> Self {
+            Self(value)
+        }
+    }
+
+    impl From<Amount> for U256 {
+        fn from(value: Amount) -> Self {
+            value.0
+        }
+    }
+
+    sol! {
+        #![sol(rename_type(bytes32 = MyHash, uint = Amount))]
+
+        struct Order {
+            bytes32 hash;
+            uint amount;
+            address maker;
+        }
+
+        function fill(bytes32 hash, uint256 amount) returns (bytes32);
+    }
+
+    let order = Order {
+        hash: MyHash([1; 32]),
+        amount: Amount(U256::from(42)),
+        maker: Address::ZERO,
+    };
+    let encoded = Order::encode_single(&order);
+    assert_eq!(
+        encoded,
+        <(
+            alloy_sol_types::sol_data::FixedBytes<32>,
+            alloy_sol_types::sol_data::Uint<256>,
+            alloy_sol_types::sol_data::Address,
+        )>::encode(&([1; 32], U256::from(42), Address::ZERO))
+    );
+    let decoded = Order::decode_single(&encoded, true).unwrap();
+    assert_eq!(decoded.hash, order.hash);
+    assert_eq!(decoded.amount, order.amount);
+    assert_eq!(
+        SolStruct::eip712_hash_struct(&order),
+        keccak256(
+            [
+                &SolStruct::eip712_type_hash(&order)[..],
+                &[1; 32],
+                &U256::from(42).to_be_bytes::<32>(),
+                &[0; 32],
+            ]
+            .concat()
+        )
+    );
+
+    let call = fillCall {
+        hash: MyHash([2; 32]),
+        amount: Amount(U256::from(1)),
+    };
+    assert_eq!(fillCall::SIGNATURE, "fill(bytes32,uint256)");
+    let decoded = fillCall::decode(&call.encode(), true).unwrap();
+    assert_eq!(decoded.hash, call.hash);
+    assert_eq!(decoded.amount, call.amount);
+
+    let ret = fillCall::decode_returns(&[3; 32], true).unwrap();
+    assert_eq!(ret._0, MyHash([3; 32]));
+}
+
+#[test]
+fn rename_type_narrowing() {
+    use alloy_sol_types::{SolError, SolStruct};
+
+    sol! {
+        #![sol(rename_type(uint = u64))]
+
+        struct Deposit {
+            uint amount;
+            address from;
+        }
+
+        error TooLarge(uint256 amount);
+    }
+
+    let deposit = Deposit {
+        amount: 7,
+        from: Address::ZERO,
+    };
+    let encoded = Deposit::encode_single(&deposit);
+    assert_eq!(encoded[..32], U256::from(7).to_be_bytes::<32>());
+    assert_eq!(Deposit::decode_single(&encoded, true).unwrap().amount, 7);
+    assert_eq!(
+        SolStruct::eip712_encode_data(&deposit)[..32],
+        U256::from(7).to_be_bytes::<32>()
+    );
+
+    // values which do not fit in `u64` fail validation
+    let too_large = (U256::from(u64::MAX) + U256::from(1)).to_be_bytes::<32>();
+    let encoded = [&too_large[..], &[0; 32]].concat();
+    assert!(Deposit::decode_single(&encoded, true).is_err());
+    assert!(TooLarge::decode_raw(&too_large, true).is_err());
+    assert_eq!(
+        TooLarge::decode_raw(&U256::from(1).to_be_bytes::<32>(), true)
+            .unwrap()
+            .amount,
+        1
+    );
+}
+
+#[test]
+fn decode_raw_log() {
+    use alloy_primitives::B256;
+    use alloy_sol_types::{Error, SolEvent};
+
+    sol! {
+        #[derive(Debug, PartialEq)]
+        contract Token {
+            event Transfer(address indexed from, address indexed to, uint256 value);
+            event Named(string indexed name, bytes data);
+            event Note(bytes4 indexed sig, uint256 wad) anonymous;
+        }
+    }
+    use Token::*;
+
+    let from = Address::repeat_byte(1);
+    let to = Address::repeat_byte(2);
+    let transfer = Transfer {
+        from,
+        to,
+        value: U256::from(3),
+    };
+    let topics: Vec<B256> = transfer.encode_topics().into_iter().map(|t| t.0).collect();
+    let data = transfer.encode_data();
+
+    let decoded = Transfer::decode_raw_log(&topics, &data, true).unwrap();
+    assert_eq!(
+        (decoded.from, decoded.to, decoded.value),
+        (from, to, U256::from(3))
+    );
+    assert_eq!(
+        Transfer::decode_raw_log(&topics[..2], &data, true),
+        Err(Error::TopicLengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
+
+    let mut bad_topics = topics.clone();
+    bad_topics[0] = B256::ZERO;
+    assert_eq!(
+        Transfer::decode_raw_log(&bad_topics, &data, true),
+        Err(Error::EventSignatureMismatch {
+            expected: Transfer::SIGNATURE_HASH,
+            got: B256::ZERO
+        })
+    );
+
+    // indexed dynamic values are surfaced as their hash
+    let named_topics = [Named::SIGNATURE_HASH, keccak256("alice")];
+    let named_data = <(alloy_sol_types::sol_data::Bytes,)>::encode(&(vec![4, 5],));
+    let named = Named::decode_raw_log(&named_topics, &named_data, true).unwrap();
+    assert_eq!(named.name, keccak256("alice").0);
+    assert_eq!(named.data, [4, 5]);
+
+    // decode over all the contract's events
+    let event = TokenEvents::decode_log(&topics, &data, true).unwrap();
+    assert_eq!(event.as_transfer().unwrap().value, U256::from(3));
+    let event = TokenEvents::decode_log(&named_topics, &named_data, true).unwrap();
+    assert!(event.is_named());
+
+    let mut sig = B256::ZERO;
+    sig[..4].copy_from_slice(&[0xaa; 4]);
+    let note_topics = [sig];
+    let note_data = U256::from(6).to_be_bytes::<32>();
+    let event = TokenEvents::decode_log(&note_topics, &note_data, true).unwrap();
+    assert_eq!(event.as_note().unwrap().wad, U256::from(6));
+
+    assert_eq!(
+        TokenEvents::decode_log(&bad_topics, &data, true),
+        Err(Error::InvalidLog {
+            name: "TokenEvents"
+        })
+    );
+}
+
+#[test]
+fn try_from_log() {
+    use alloy_primitives::{Log, LogData};
+    use alloy_sol_types::{Error, SolEvent};
+
+    sol! {
+        #[derive(Clone, Debug, PartialEq)]
+        contract Vault {
+            event Deposit(address indexed owner, uint256 amount);
+            event Withdraw(address indexed owner, uint256 amount);
+        }
+    }
+    use Vault::*;
+
+    let deposit = Deposit {
+        owner: Address::repeat_byte(1),
+        amount: U256::from(2),
+    };
+    let data = LogData::new(
+        deposit.encode_topics().into_iter().map(|t| t.0).collect(),
+        deposit.encode_data().into(),
+    );
+    let log = Log::new(Address::repeat_byte(0xaa), data.clone());
+
+    assert_eq!(Deposit::try_from(&data), Ok(deposit.clone()));
+    assert_eq!(Deposit::try_from(&log), Ok(deposit.clone()));
+    assert_eq!(Deposit::try_from(log.clone()), Ok(deposit.clone()));
+    let converted: Deposit = data.clone().try_into().unwrap();
+    assert_eq!(converted, deposit);
+    assert!(matches!(
+        Withdraw::try_from(&data),
+        Err(Error::EventSignatureMismatch { .. })
+    ));
+
+    // composes with `?`
+    fn amounts(logs: &[Log]) -> alloy_sol_types::Result<Vec<U256>> {
+        let mut amounts = Vec::new();
+        for log in logs {
+            match VaultEvents::try_from(log)? {
+                VaultEvents::Deposit(e) => amounts.push(e.amount),
+                VaultEvents::Withdraw(e) => amounts.push(e.amount),
+            }
+        }
+        Ok(amounts)
+    }
+    assert_eq!(amounts(&[log.clone(), log]), Ok(vec![U256::from(2); 2]));
+
+    let mut bad = data;
+    bad.topics[0] = Default::default();
+    assert_eq!(
+        VaultEvents::try_from(bad),
+        Err(Error::InvalidLog {
+            name: "VaultEvents"
+        })
+    );
+}
+
+#[test]
+fn bitflags() {
+    sol! {
+        #[sol(bitflags(Roles))]
+        library Access {
+            uint8 constant ADMIN = 1 << 0;
+            uint8 constant MINTER = 1 << 1;
+            uint8 constant BURNER = 2 ** 2;
+            uint8 constant OPERATOR = MINTER | BURNER;
+        }
+
+        #[sol(bitflags(Permissions))]
+        library Wide {
+            uint256 constant LOW = 0x1;
+            uint256 constant HIGH = 1 << 255;
+        }
+    }
+
+    use Access::Roles;
+
+    assert_eq!(Roles::ADMIN.bits(), 1);
+    assert_eq!(Roles::BURNER.bits(), 4);
+    assert_eq!(Roles::OPERATOR, Roles::MINTER | Roles::BURNER);
+    assert_eq!(Roles::all().bits(), 0b111);
+    assert!(Roles::empty().is_empty());
+
+    let mut roles = Roles::ADMIN | Roles::MINTER;
+    assert!(roles.contains(Roles::ADMIN));
+    assert!(!roles.contains(Roles::OPERATOR));
+    assert!(roles.intersects(Roles::OPERATOR));
+    roles.insert(Roles::BURNER);
+    assert!(roles.is_all());
+    roles.remove(Roles::MINTER);
+    assert_eq!(roles, Roles::ADMIN | Roles::BURNER);
+    roles.toggle(Roles::ADMIN);
+    assert_eq!(roles, Roles::BURNER);
+    assert_eq!(!roles, Roles::ADMIN | Roles::MINTER);
+    assert_eq!(Roles::all() - Roles::OPERATOR, Roles::ADMIN);
+    assert_eq!(Roles::all() & Roles::OPERATOR, Roles::OPERATOR);
+    assert_eq!(Roles::ADMIN ^ Roles::ADMIN, Roles::empty());
+
+    assert_eq!(Roles::from_bits(0b101), Some(Roles::ADMIN | Roles::BURNER));
+    assert_eq!(Roles::from_bits(0b1000), None);
+    assert_eq!(Roles::from_bits_truncate(0b1001), Roles::ADMIN);
+    assert_eq!(Roles::from_bits_retain(0b1000).bits(), 0b1000);
+    assert_eq!(u8::from(Roles::MINTER), 2);
+
+    use Wide::Permissions;
+
+    assert_eq!(Permissions::LOW.bits(), U256::from(1));
+    assert_eq!(Permissions::HIGH.bits(), U256::from(1) << 255);
+    assert_eq!(
+        Permissions::from(U256::MAX),
+        Permissions::from_bits_retain(U256::MAX)
+    );
+    assert_eq!(
+        Permissions::from_bits_truncate(U256::MAX),
+        Permissions::all()
+    );
+}
+
+// https://github.com/ethereum/EIPs/blob/master/assets/eip-712/Example.js
+#[test]
+fn eip712_spec_example() {
+    use alloy_primitives::b256;
+    use alloy_sol_types::{eip712_domain, SolStruct};
+
+    sol! {
+        struct Person {
+            string name;
+            address wallet;
+        }
+
+        struct Mail {
+            Person from;
+            Person to;
+            string contents;
+        }
+    }
+
+    let domain = eip712_domain! {
+        name: "Ether Mail",
+        version: "1",
+        chain_id: 1,
+        verifying_contract: "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".parse().unwrap(),
+    };
+    let mail = Mail {
+        from: Person {
+            name: "Cow".into(),
+            wallet: "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+                .parse()
+                .unwrap(),
+        },
+        to: Person {
+            name: "Bob".into(),
+            wallet: "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"
+                .parse()
+                .unwrap(),
+        },
+        contents: "Hello, Bob!".into(),
+    };
+
+    assert_eq!(
+        <Mail as SolStruct>::eip712_encode_type(),
+        "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+    );
+    assert_eq!(
+        mail.eip712_type_hash(),
+        b256!("a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2")
+    );
+    assert_eq!(
+        mail.eip712_hash_struct(),
+        b256!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+    );
+    assert_eq!(
+        domain.separator(),
+        b256!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+    );
+    assert_eq!(
+        mail.eip712_signing_hash(&domain),
+        b256!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+    );
+}
@@ -0,0 +1,60 @@
+use alloy_sol_types::sol;
+
+sol! {
+    #[sol(bitflags(Empty))]
+    library NoConstants {
+        uint256 immutable NOT_CONSTANT;
+    }
+}
+
+sol! {
+    #[sol(bitflags(Signed))]
+    library SignedFlags {
+        int8 constant A = 1;
+    }
+}
+
+sol! {
+    #[sol(bitflags(Mixed))]
+    library MixedTypes {
+        uint8 constant A = 1;
+        uint16 constant B = 2;
+    }
+}
+
+sol! {
+    #[sol(bitflags(TooBig))]
+    library Overflowing {
+        uint8 constant A = 1 << 8;
+    }
+}
+
+sol! {
+    #[sol(bitflags(TooBig))]
+    library Overflowing256 {
+        uint256 constant A = 1 << 256;
+    }
+}
+
+sol! {
+    #[sol(bitflags(Unknown))]
+    library UnknownFlag {
+        uint8 constant A = B | 1;
+    }
+}
+
+sol! {
+    #[sol(bitflags(Unsupported))]
+    library UnsupportedExpr {
+        uint8 constant A = 3 ** 2;
+    }
+}
+
+sol! {
+    #[sol(bitflags(Unsupported))]
+    library UnsupportedOp {
+        uint8 constant A = 1 + 2;
+    }
+}
+
+fn main() {}
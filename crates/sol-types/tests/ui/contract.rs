@@ -34,4 +34,22 @@ sol! {
     }
 }
 
+sol! {
+    interface C {
+        function f() external {}
+    }
+}
+
+sol! {
+    interface C {
+        function f() private;
+    }
+}
+
+sol! {
+    library C {
+        function f() external payable;
+    }
+}
+
 fn main() {}
@@ -0,0 +1,51 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolStruct;
+
+#[derive(Clone, SolStruct)]
+struct MissingType {
+    #[sol(type = "address")]
+    a: Address,
+    b: U256,
+}
+
+#[derive(Clone, SolStruct)]
+struct InvalidType {
+    #[sol(type = "uint7")]
+    a: U256,
+    #[sol(type = "mapping(uint => uint)")]
+    b: U256,
+}
+
+#[derive(Clone, SolStruct)]
+struct UnknownAttribute {
+    #[sol(ty = "uint256")]
+    a: U256,
+    #[sol(type = "uint256", type = "uint256")]
+    b: U256,
+}
+
+#[derive(Clone, SolStruct)]
+#[sol(type = "uint256")]
+struct ContainerAttribute {
+    #[sol(type = "uint256")]
+    a: U256,
+}
+
+#[derive(Clone, SolStruct)]
+struct Tuple(#[sol(type = "uint256")] U256);
+
+#[derive(Clone, SolStruct)]
+struct Empty {}
+
+#[derive(Clone, SolStruct)]
+struct Generic<T> {
+    #[sol(type = "uint256")]
+    a: T,
+}
+
+#[derive(Clone, SolStruct)]
+enum Enum {
+    A,
+}
+
+fn main() {}
@@ -17,4 +17,12 @@ sol! {
     error Valid(uint256);
 }
 
+sol! {
+    error DataLocation(bytes memory a);
+}
+
+sol! {
+    error DataLocationNoName(string calldata);
+}
+
 fn main() {}
@@ -46,6 +46,14 @@ sol! {
     event Valid(uint256);
 }
 
+sol! {
+    event DataLocation(bytes memory a);
+}
+
+sol! {
+    event DataLocationIndexed(string indexed storage a);
+}
+
 fn main() {}
 
 struct A {}
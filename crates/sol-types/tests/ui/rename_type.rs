@@ -0,0 +1,17 @@
+use alloy_sol_types::sol;
+
+sol! {
+    #[sol(rename_type(uint256 = u64))]
+    struct Deposit {
+        uint256 amount;
+    }
+}
+
+sol! {
+    contract C {
+        #[sol(rename_type(bytes32 = [u8; 32]))]
+        function f(bytes32 hash);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+use alloy_sol_types::sol;
+
+mod shared {
+    alloy_sol_types::sol! {
+        struct Order {
+            address maker;
+            uint256 amount;
+        }
+
+        type Price is uint128;
+    }
+}
+
+sol! {
+    #![sol(use_crate_types(crate::shared))]
+
+    struct Order {
+        address maker;
+        uint128 amount;
+    }
+
+    type Price is uint64;
+}
+
+fn main() {}
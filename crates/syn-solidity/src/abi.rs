@@ -0,0 +1,192 @@
+//! Canonical ABI signatures and selectors of items.
+
+use crate::{Item, ItemContract, SolIdent, SolPath, Type};
+use std::fmt::Write;
+use syn::{Error, Result};
+use tiny_keccak::{Hasher, Keccak};
+
+/// The maximum number of nested custom types to resolve, to detect recursive
+/// type definitions.
+const RESOLVE_LIMIT: usize = 32;
+
+/// Returns the canonical signature `name(type1,type2,...)` of an item with
+/// the given parameter types.
+///
+/// Custom types are resolved in `scope`: structs are flattened into tuples,
+/// enums are `uint8`, contracts are `address`, and user-defined value types
+/// are their underlying type. If `name` belongs to an item in the body of a
+/// contract in `scope`, types are looked up in that contract first.
+pub(crate) fn signature<'a>(
+    name: &SolIdent,
+    types: impl IntoIterator<Item = &'a Type>,
+    scope: &[Item],
+) -> Result<String> {
+    let scope = Scope {
+        file: scope,
+        contract: enclosing_contract(scope, name),
+    };
+    let mut s = name.as_string();
+    s.push('(');
+    for (i, ty) in types.into_iter().enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        write_type(&mut s, ty, scope, 0)?;
+    }
+    s.push(')');
+    Ok(s)
+}
+
+pub(crate) fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(bytes);
+    hasher.finalize(&mut output);
+    output
+}
+
+pub(crate) fn selector(signature: &str) -> [u8; 4] {
+    keccak256(signature.as_bytes())[..4].try_into().unwrap()
+}
+
+fn write_type(s: &mut String, ty: &Type, scope: Scope<'_>, depth: usize) -> Result<()> {
+    match ty {
+        Type::Int(_, None) => s.push_str("int256"),
+        Type::Uint(_, None) => s.push_str("uint256"),
+        Type::Array(array) => {
+            write_type(s, &array.ty, scope, depth)?;
+            match array.size() {
+                Some(size) => write!(s, "[{size}]").unwrap(),
+                None => s.push_str("[]"),
+            }
+        }
+        Type::Tuple(tuple) => write_tuple(s, tuple.types.iter(), scope, depth)?,
+        Type::Mapping(mapping) => {
+            return Err(Error::new(
+                mapping.span(),
+                "mappings are not allowed in ABI signatures",
+            ))
+        }
+        Type::Custom(path) => {
+            if depth >= RESOLVE_LIMIT {
+                return Err(Error::new(
+                    path.span(),
+                    "failed to resolve a recursive type definition",
+                ))
+            }
+            // the definition's own types are resolved where it is declared
+            let (item, scope) = scope.resolve(path)?;
+            match item {
+                Item::Struct(strukt) => write_tuple(s, strukt.fields.types(), scope, depth + 1)?,
+                Item::Enum(_) => s.push_str("uint8"),
+                Item::Contract(_) => s.push_str("address"),
+                Item::Udt(udt) => write_type(s, &udt.ty, scope, depth + 1)?,
+                _ => unreachable!("not a type definition"),
+            }
+        }
+        ty => write!(s, "{ty}").unwrap(),
+    }
+    Ok(())
+}
+
+fn write_tuple<'a>(
+    s: &mut String,
+    types: impl Iterator<Item = &'a Type>,
+    scope: Scope<'_>,
+    depth: usize,
+) -> Result<()> {
+    s.push('(');
+    for (i, ty) in types.enumerate() {
+        if i > 0 {
+            s.push(',');
+        }
+        write_type(s, ty, scope, depth)?;
+    }
+    s.push(')');
+    Ok(())
+}
+
+/// The items that custom types are resolved in.
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    /// The items of the file.
+    file: &'a [Item],
+    /// The contract whose body is searched before the file, if any.
+    contract: Option<&'a ItemContract>,
+}
+
+impl<'a> Scope<'a> {
+    /// Resolves a type path, returning its definition and the scope it is
+    /// declared in.
+    ///
+    /// The first segment is searched for in the body of the current contract,
+    /// then in the items of the file. The following segments are searched for
+    /// in the body of the contract named by the previous segment.
+    fn resolve(self, path: &SolPath) -> Result<(&'a Item, Self)> {
+        let mut segments = path.iter();
+        let first = segments.next().unwrap();
+        let local = match self.contract {
+            Some(contract) => find_type(&contract.body, first, path)?,
+            None => None,
+        };
+        let (mut item, mut scope) = match local {
+            Some(item) => (item, self),
+            None => {
+                let item = find_type(self.file, first, path)?
+                    .ok_or_else(|| Error::new(path.span(), "unresolved type"))?;
+                (
+                    item,
+                    Self {
+                        contract: None,
+                        ..self
+                    },
+                )
+            }
+        };
+        for name in segments {
+            let Item::Contract(contract) = item else {
+                return Err(Error::new(path.span(), "unresolved type"))
+            };
+            item = find_type(&contract.body, name, path)?
+                .ok_or_else(|| Error::new(path.span(), "unresolved type"))?;
+            scope.contract = Some(contract);
+        }
+        Ok((item, scope))
+    }
+}
+
+/// Finds the type definition named `name` in `items`. Returns an error if
+/// there is more than one.
+fn find_type<'a>(items: &'a [Item], name: &SolIdent, path: &SolPath) -> Result<Option<&'a Item>> {
+    let mut matches = items
+        .iter()
+        .filter(|item| is_type(item) && item.name() == Some(name));
+    let item = matches.next();
+    if matches.next().is_some() {
+        return Err(Error::new(path.span(), "ambiguous type"))
+    }
+    Ok(item)
+}
+
+fn is_type(item: &Item) -> bool {
+    matches!(
+        item,
+        Item::Contract(_) | Item::Enum(_) | Item::Struct(_) | Item::Udt(_)
+    )
+}
+
+/// Returns the contract in `items` whose body declares the item named by the
+/// `name` reference.
+fn enclosing_contract<'a>(items: &'a [Item], name: &SolIdent) -> Option<&'a ItemContract> {
+    items.iter().find_map(|item| match item {
+        Item::Contract(contract)
+            if contract
+                .body
+                .iter()
+                .any(|item| item.name().map_or(false, |n| std::ptr::eq(n, name))) =>
+        {
+            Some(contract)
+        }
+        _ => None,
+    })
+}
@@ -281,6 +281,11 @@ impl FunctionAttribute {
         matches!(self, Self::Visibility(Visibility::Private(_)))
     }
 
+    #[inline]
+    pub const fn is_payable(&self) -> bool {
+        matches!(self, Self::Mutability(Mutability::Payable(_)))
+    }
+
     #[inline]
     pub const fn is_virtual(&self) -> bool {
         matches!(self, Self::Virtual(_))
@@ -1,5 +1,5 @@
-use super::Item;
-use crate::{kw, utils::DebugPunctuated, Modifier, SolIdent};
+use super::{function::FunctionBody, Item};
+use crate::{kw, utils::DebugPunctuated, ItemFunction, Modifier, SolIdent};
 use proc_macro2::Span;
 use std::{cmp::Ordering, fmt};
 use syn::{
@@ -66,6 +66,9 @@ impl Parse for ItemContract {
                     if matches!(item, Item::Contract(_)) {
                         return Err(Error::new(item.span(), "cannot declare nested contracts"))
                     }
+                    if let Item::Function(function) = &item {
+                        check_function(kind, function)?;
+                    }
                     body.push(item);
                 }
                 body
@@ -74,6 +77,38 @@ impl Parse for ItemContract {
     }
 }
 
+/// Checks that a function's body and attributes are allowed in a contract of
+/// the given kind.
+fn check_function(kind: ContractKind, function: &ItemFunction) -> Result<()> {
+    let (span, msg) = match kind {
+        ContractKind::Interface(_) => {
+            if let FunctionBody::Block(block) = &function.body {
+                (
+                    block.brace_token.span.join(),
+                    "functions in interfaces cannot have an implementation",
+                )
+            } else if let Some(attr) = function.attributes.iter().find(|attr| attr.is_private()) {
+                (attr.span(), "functions in interfaces cannot be private")
+            } else {
+                return Ok(())
+            }
+        }
+        ContractKind::Library(_) => {
+            match function.attributes.iter().find(|attr| attr.is_payable()) {
+                Some(attr) => (attr.span(), "library functions cannot be payable"),
+                None => return Ok(()),
+            }
+        }
+        _ => return Ok(()),
+    };
+    let mut e = Error::new(span, msg);
+    e.combine(Error::new(
+        kind.span(),
+        format!("{} declared here", kind.as_str()),
+    ));
+    Err(e)
+}
+
 impl ItemContract {
     pub fn span(&self) -> Span {
         self.name.span()
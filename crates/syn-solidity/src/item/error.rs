@@ -1,11 +1,11 @@
-use crate::{kw, ParameterList, SolIdent, Type};
+use crate::{abi, kw, Item, ParameterList, SolIdent, Type};
 use proc_macro2::Span;
 use std::fmt;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
     token::Paren,
-    Attribute, Result, Token,
+    Attribute, Error, Result, Token,
 };
 
 /// An error definition: `error Foo(uint256 a, uint256 b);`
@@ -35,14 +35,21 @@ impl fmt::Debug for ItemError {
 impl Parse for ItemError {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         let content;
-        Ok(Self {
+        let this = Self {
             attrs: input.call(Attribute::parse_outer)?,
             error_token: input.parse()?,
             name: input.parse()?,
             paren_token: parenthesized!(content in input),
             parameters: content.parse()?,
             semi_token: input.parse()?,
-        })
+        };
+        if let Some(storage) = this.parameters.iter().find_map(|p| p.storage) {
+            return Err(Error::new(
+                storage.span(),
+                "data locations are not allowed in error parameters",
+            ))
+        }
+        Ok(this)
     }
 }
 
@@ -60,4 +67,21 @@ impl ItemError {
         ty.set_span(self.span());
         ty
     }
+
+    /// Returns the canonical ABI signature of the error, e.g.
+    /// `InsufficientBalance(uint256,uint256)`.
+    ///
+    /// Custom types are resolved in `scope`, usually the items of the
+    /// surrounding [`File`](crate::File): first in the body of the contract
+    /// that declares this item, if it is in `scope`, then at the file level.
+    /// Structs are flattened into tuples.
+    pub fn signature(&self, scope: &[Item]) -> Result<String> {
+        abi::signature(&self.name, self.parameters.types(), scope)
+    }
+
+    /// Returns the selector of the error: the first 4 bytes of the Keccak-256
+    /// hash of its [signature](Self::signature).
+    pub fn selector(&self, scope: &[Item]) -> Result<[u8; 4]> {
+        self.signature(scope).map(|sig| abi::selector(&sig))
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{kw, utils::DebugPunctuated, ParameterList, SolIdent, Type, VariableDeclaration};
+use crate::{
+    abi, kw, utils::DebugPunctuated, Item, ParameterList, SolIdent, Storage, Type,
+    VariableDeclaration,
+};
 use proc_macro2::Span;
 use std::fmt;
 use syn::{
@@ -120,6 +123,25 @@ impl ItemEvent {
         ty.set_span(self.span());
         ty
     }
+
+    /// Returns the canonical ABI signature of the event, e.g.
+    /// `Transfer(address,address,uint256)`.
+    ///
+    /// Custom types are resolved in `scope`, usually the items of the
+    /// surrounding [`File`](crate::File): first in the body of the contract
+    /// that declares this item, if it is in `scope`, then at the file level.
+    /// Structs are flattened into tuples.
+    pub fn signature(&self, scope: &[Item]) -> Result<String> {
+        abi::signature(&self.name, self.parameters.iter().map(|p| &p.ty), scope)
+    }
+
+    /// Returns the selector of the event: the Keccak-256 hash of its
+    /// [signature](Self::signature), which is emitted as the first topic of
+    /// non-anonymous events.
+    pub fn selector(&self, scope: &[Item]) -> Result<[u8; 32]> {
+        self.signature(scope)
+            .map(|sig| abi::keccak256(sig.as_bytes()))
+    }
 }
 
 /// An event parameter.
@@ -146,10 +168,15 @@ impl fmt::Debug for EventParameter {
 
 impl Parse for EventParameter {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let ty = input.parse()?;
+        reject_storage(input)?;
+        let indexed = input.parse()?;
+        reject_storage(input)?;
         Ok(Self {
-            attrs: input.call(Attribute::parse_outer)?,
-            ty: input.parse()?,
-            indexed: input.parse()?,
+            attrs,
+            ty,
+            indexed,
             name: if SolIdent::peek_any(input) {
                 Some(input.parse()?)
             } else {
@@ -159,6 +186,17 @@ impl Parse for EventParameter {
     }
 }
 
+/// Data locations would otherwise be parsed as the parameter name.
+fn reject_storage(input: ParseStream<'_>) -> Result<()> {
+    match input.call(Storage::parse_opt)? {
+        Some(storage) => Err(Error::new(
+            storage.span(),
+            "data locations are not allowed in event parameters",
+        )),
+        None => Ok(()),
+    }
+}
+
 impl EventParameter {
     /// Get the span of the event parameter
     pub fn span(&self) -> Span {
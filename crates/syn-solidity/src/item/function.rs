@@ -1,5 +1,6 @@
 use crate::{
-    kw, Block, FunctionAttributes, ParameterList, Parameters, SolIdent, Type, VariableDefinition,
+    abi, kw, Block, FunctionAttributes, Item, ParameterList, Parameters, SolIdent, Type,
+    VariableDeclaration, VariableDefinition,
 };
 use proc_macro2::Span;
 use std::{
@@ -81,6 +82,10 @@ impl ItemFunction {
 
     /// Creates a new function from a variable definition. The function will
     /// have the same name and the variable type's will be the return type.
+    ///
+    /// Like the getters generated by the Solidity compiler, mappings and arrays
+    /// are unwrapped: their keys and indices become the function's arguments,
+    /// and the innermost value type becomes the return type.
     pub fn from_variable_definition(var: &VariableDefinition) -> Self {
         let name = var.name.clone();
         let span = name.span();
@@ -88,8 +93,28 @@ impl ItemFunction {
 
         let mut function = ItemFunction::new(kind, Some(name));
 
+        let mut ty = &var.ty;
+        loop {
+            match ty {
+                Type::Mapping(map) => {
+                    let mut key = VariableDeclaration::new(map.key.as_ref().clone());
+                    key.name = map.key_name.clone();
+                    function.arguments.push(key);
+                    ty = &map.value;
+                }
+                Type::Array(array) => {
+                    let index = Type::Uint(array.span(), None);
+                    function.arguments.push(VariableDeclaration::new(index));
+                    ty = &array.ty;
+                }
+                _ => break,
+            }
+        }
+
         let mut returns = ParameterList::new();
-        returns.push(var.as_declaration());
+        let mut declaration = var.as_declaration();
+        declaration.ty = ty.clone();
+        returns.push(declaration);
         let returns = Returns::new(span, returns);
         function.returns = Some(returns);
 
@@ -159,6 +184,30 @@ impl ItemFunction {
             )
         })
     }
+
+    /// Returns the canonical ABI signature of the function, e.g.
+    /// `transfer(address,uint256)`.
+    ///
+    /// Custom types are resolved in `scope`, usually the items of the
+    /// surrounding [`File`](crate::File): first in the body of the contract
+    /// that declares this item, if it is in `scope`, then at the file level.
+    /// Structs are flattened into tuples.
+    ///
+    /// Returns an error if the function has no name, or if a type cannot be
+    /// resolved.
+    pub fn signature(&self, scope: &[Item]) -> Result<String> {
+        let name = self
+            .name
+            .as_ref()
+            .ok_or_else(|| Error::new(self.span(), "only named functions have a signature"))?;
+        abi::signature(name, self.arguments.types(), scope)
+    }
+
+    /// Returns the selector of the function: the first 4 bytes of the
+    /// Keccak-256 hash of its [signature](Self::signature).
+    pub fn selector(&self, scope: &[Item]) -> Result<[u8; 4]> {
+        self.signature(scope).map(|sig| abi::selector(&sig))
+    }
 }
 
 kw_enum! {
@@ -56,7 +56,7 @@ impl Parse for PragmaTokens {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
         if input.peek(kw::solidity) {
             let solidity = input.parse()?;
-            let version = tts_until_semi(input);
+            let version = tts_until_semi(input)?;
             Ok(Self::Version(solidity, version))
         } else if input.peek(kw::abicoder) {
             let abicoder = input.parse()?;
@@ -67,7 +67,7 @@ impl Parse for PragmaTokens {
             let ident = input.parse()?;
             Ok(Self::Experimental(experimental, ident))
         } else {
-            Ok(Self::Verbatim(tts_until_semi(input)))
+            tts_until_semi(input).map(Self::Verbatim)
         }
     }
 }
@@ -20,6 +20,8 @@ use syn::Result;
 #[macro_use]
 mod macros;
 
+mod abi;
+
 mod attribute;
 pub use attribute::{
     FunctionAttribute, FunctionAttributes, Modifier, Mutability, Override, Storage,
@@ -40,6 +42,9 @@ pub use item::{
     UsingDirective, UsingList, UsingListItem, UsingType,
 };
 
+mod limits;
+pub use limits::ParseLimits;
+
 mod lit;
 pub use lit::LitStr;
 
@@ -0,0 +1,114 @@
+use std::cell::Cell;
+use syn::{parse::ParseStream, Result};
+
+/// Limits on the nesting depth and size of parsed input.
+///
+/// Inputs exceeding these limits, such as deeply nested tuple types or very
+/// long runs of unparsed tokens, are rejected with a parse error instead of
+/// overflowing the stack or stalling macro expansion. This matters when the
+/// parsed input is machine-generated or untrusted.
+///
+/// The limits are applied to all the parsing done on the current thread; use
+/// [`ParseLimits::with`] to override the [defaults](ParseLimits::DEFAULT) for
+/// a specific parse.
+///
+/// # Examples
+///
+/// ```
+/// use syn_solidity::{ParseLimits, Type};
+///
+/// let limits = ParseLimits {
+///     max_depth: 2,
+///     ..Default::default()
+/// };
+/// let ty = limits.with(|| syn::parse_str::<Type>("((uint256,),)"));
+/// assert!(ty.is_ok());
+/// let ty = limits.with(|| syn::parse_str::<Type>("(((uint256,),),)"));
+/// assert!(ty.is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseLimits {
+    /// The maximum nesting depth of types. Every tuple, array dimension,
+    /// mapping and function type adds a level.
+    pub max_depth: usize,
+    /// The maximum number of token trees in a run of tokens that is not
+    /// parsed further, like a variable initializer or a pragma.
+    pub max_tokens: usize,
+}
+
+impl Default for ParseLimits {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+thread_local! {
+    static LIMITS: Cell<ParseLimits> = const { Cell::new(ParseLimits::DEFAULT) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+impl ParseLimits {
+    /// The default limits.
+    pub const DEFAULT: Self = Self {
+        max_depth: 128,
+        max_tokens: 65536,
+    };
+
+    /// Returns the limits that are currently in effect on this thread.
+    #[inline]
+    pub fn current() -> Self {
+        LIMITS.with(Cell::get)
+    }
+
+    /// Runs `f` with these limits in effect on this thread, restoring the
+    /// previous limits afterwards.
+    pub fn with<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(ParseLimits);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                LIMITS.with(|limits| limits.set(self.0));
+            }
+        }
+
+        let _restore = Restore(LIMITS.with(|limits| limits.replace(self)));
+        f()
+    }
+}
+
+/// Tracks the current nesting depth. The levels entered through a guard are
+/// left when it is dropped.
+#[derive(Default)]
+pub(crate) struct DepthGuard(usize);
+
+impl DepthGuard {
+    /// Enters a nesting level, returning an error if this exceeds the maximum
+    /// depth.
+    pub(crate) fn enter(input: ParseStream<'_>) -> Result<Self> {
+        let mut guard = Self::default();
+        guard.nest(input)?;
+        Ok(guard)
+    }
+
+    /// Enters one more nesting level, which is left when the guard is dropped.
+    pub(crate) fn nest(&mut self, input: ParseStream<'_>) -> Result<()> {
+        self.0 += 1;
+        let depth = DEPTH.with(|depth| {
+            let new = depth.get() + 1;
+            depth.set(new);
+            new
+        });
+        let max = ParseLimits::current().max_depth;
+        if depth > max {
+            return Err(input.error(format!("exceeded the maximum nesting depth of {max}")))
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - self.0));
+    }
+}
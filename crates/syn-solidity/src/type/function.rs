@@ -1,4 +1,4 @@
-use crate::{kw, FunctionAttributes, ParameterList, Returns};
+use crate::{kw, limits::DepthGuard, FunctionAttributes, ParameterList, Returns};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -73,6 +73,7 @@ impl fmt::Debug for TypeFunction {
 
 impl Parse for TypeFunction {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let _guard = DepthGuard::enter(input)?;
         let content;
         Ok(Self {
             function_token: input.parse()?,
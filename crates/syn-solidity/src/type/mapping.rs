@@ -1,4 +1,4 @@
-use crate::{kw, SolIdent, Type};
+use crate::{kw, limits::DepthGuard, SolIdent, Type};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -57,6 +57,7 @@ impl fmt::Display for TypeMapping {
 
 impl Parse for TypeMapping {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let _guard = DepthGuard::enter(input)?;
         let content;
         Ok(Self {
             mapping_token: input.parse()?,
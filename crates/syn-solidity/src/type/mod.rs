@@ -1,4 +1,4 @@
-use crate::{kw, sol_path, SolPath};
+use crate::{kw, limits::DepthGuard, sol_path, SolPath};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -154,7 +154,9 @@ impl Parse for Type {
 
         // while the next token is a bracket, parse an array size and nest the
         // candidate into an array
+        let mut guard = DepthGuard::default();
         while input.peek(Bracket) {
+            guard.nest(input)?;
             candidate = Self::Array(TypeArray::wrap(input, candidate)?);
         }
 
@@ -1,4 +1,4 @@
-use crate::{kw, utils::DebugPunctuated, Type};
+use crate::{kw, limits::DepthGuard, utils::DebugPunctuated, Type};
 use proc_macro2::Span;
 use std::{
     fmt,
@@ -57,6 +57,7 @@ impl fmt::Display for TypeTuple {
 
 impl Parse for TypeTuple {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let _guard = DepthGuard::enter(input)?;
         let content;
         let this = Self {
             tuple_token: input.parse()?,
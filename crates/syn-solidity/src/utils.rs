@@ -1,6 +1,7 @@
+use crate::ParseLimits;
 use proc_macro2::{TokenStream, TokenTree};
 use std::fmt;
-use syn::{parse::ParseStream, punctuated::Punctuated, Token};
+use syn::{parse::ParseStream, punctuated::Punctuated, Result, Token};
 
 #[repr(transparent)]
 pub(crate) struct DebugPunctuated<T, P>(Punctuated<T, P>);
@@ -18,11 +19,17 @@ impl<T: fmt::Debug, P> fmt::Debug for DebugPunctuated<T, P> {
     }
 }
 
-pub(crate) fn tts_until_semi(input: ParseStream<'_>) -> TokenStream {
+pub(crate) fn tts_until_semi(input: ParseStream<'_>) -> Result<TokenStream> {
+    let max = ParseLimits::current().max_tokens;
     let mut tts = TokenStream::new();
+    let mut len = 0usize;
     while !input.is_empty() && !input.peek(Token![;]) {
+        if len == max {
+            return Err(input.error(format!("exceeded the maximum of {max} tokens")))
+        }
         let tt = input.parse::<TokenTree>().unwrap();
         tts.extend(std::iter::once(tt));
+        len += 1;
     }
-    tts
+    Ok(tts)
 }
@@ -123,7 +123,7 @@ impl Parse for VariableDefinition {
             attributes: input.parse()?,
             name: input.parse()?,
             initializer: if input.peek(Token![=]) {
-                Some((input.parse()?, tts_until_semi(input)))
+                Some((input.parse()?, tts_until_semi(input)?))
             } else {
                 None
             },
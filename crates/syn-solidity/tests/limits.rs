@@ -0,0 +1,63 @@
+use syn_solidity::{File, ParseLimits, Type};
+
+fn nested_tuple(depth: usize) -> String {
+    format!("{}uint256{}", "(".repeat(depth), ",)".repeat(depth))
+}
+
+#[test]
+fn default_depth() {
+    let max = ParseLimits::DEFAULT.max_depth;
+    syn::parse_str::<Type>(&nested_tuple(max)).unwrap();
+    syn::parse_str::<Type>(&format!("uint256{}", "[]".repeat(max))).unwrap();
+
+    let e = syn::parse_str::<Type>(&nested_tuple(max + 1)).unwrap_err();
+    assert_eq!(e.to_string(), "exceeded the maximum nesting depth of 128");
+    let e = syn::parse_str::<Type>(&format!("uint256{}", "[]".repeat(max + 1))).unwrap_err();
+    assert_eq!(e.to_string(), "exceeded the maximum nesting depth of 128");
+}
+
+#[test]
+fn pathological_inputs() {
+    // rejected instead of recursing as deep as the input
+    syn::parse_str::<Type>(&nested_tuple(1_000)).unwrap_err();
+    syn::parse_str::<Type>(&format!("uint256{}", "[]".repeat(100_000))).unwrap_err();
+
+    let mapping = format!(
+        "{}uint256{}",
+        "mapping(uint256 => ".repeat(1_000),
+        ")".repeat(1_000)
+    );
+    syn::parse_str::<Type>(&mapping).unwrap_err();
+
+    let function = format!("{}uint256{}", "function(".repeat(1_000), ")".repeat(1_000));
+    syn::parse_str::<Type>(&function).unwrap_err();
+
+    let initializer = format!("uint256 constant X = 1{};", " + 1".repeat(100_000));
+    let e = syn::parse_str::<File>(&initializer).unwrap_err();
+    assert_eq!(e.to_string(), "exceeded the maximum of 65536 tokens");
+}
+
+#[test]
+fn custom_limits() {
+    let limits = ParseLimits {
+        max_depth: 3,
+        max_tokens: 4,
+    };
+    limits.with(|| {
+        assert_eq!(ParseLimits::current(), limits);
+        syn::parse_str::<Type>("(uint256[],)").unwrap();
+        syn::parse_str::<Type>("(uint256[][],)").unwrap();
+        syn::parse_str::<Type>("(uint256[][][],)").unwrap_err();
+        syn::parse_str::<Type>("mapping(uint256 => (uint256,))").unwrap();
+        syn::parse_str::<Type>("mapping(uint256 => ((uint256,),))").unwrap();
+        syn::parse_str::<Type>("mapping(uint256 => (((uint256,),),))").unwrap_err();
+
+        syn::parse_str::<File>("uint256 constant X = 1 << 2;").unwrap();
+        syn::parse_str::<File>("uint256 constant X = 1 << 2 | 1;").unwrap_err();
+        syn::parse_str::<File>("pragma solidity ^0.8;").unwrap();
+    });
+    assert_eq!(ParseLimits::current(), ParseLimits::DEFAULT);
+
+    // the depth is reset after errors
+    syn::parse_str::<Type>(&nested_tuple(ParseLimits::DEFAULT.max_depth)).unwrap();
+}
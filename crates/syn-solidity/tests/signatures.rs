@@ -0,0 +1,197 @@
+use syn_solidity::{File, Item, ItemContract, ItemError, ItemEvent, ItemFunction};
+
+fn file(s: &str) -> File {
+    syn::parse_str(s).unwrap()
+}
+
+fn contract<'a>(file: &'a File, name: &str) -> &'a ItemContract {
+    file.items
+        .iter()
+        .find_map(|item| match item {
+            Item::Contract(c) if c.name == name => Some(c),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn functions(items: &[Item]) -> impl Iterator<Item = &ItemFunction> {
+    items.iter().filter_map(|item| match item {
+        Item::Function(f) => Some(f),
+        _ => None,
+    })
+}
+
+fn events(items: &[Item]) -> impl Iterator<Item = &ItemEvent> {
+    items.iter().filter_map(|item| match item {
+        Item::Event(e) => Some(e),
+        _ => None,
+    })
+}
+
+fn errors(items: &[Item]) -> impl Iterator<Item = &ItemError> {
+    items.iter().filter_map(|item| match item {
+        Item::Error(e) => Some(e),
+        _ => None,
+    })
+}
+
+#[test]
+fn erc20() {
+    let file = file(
+        r#"
+        interface IERC20 {
+            event Transfer(address indexed from, address indexed to, uint value);
+            error Error(string);
+            function transfer(address to, uint amount) external returns (bool);
+            function balanceOf(address) external view returns (uint256);
+        }
+        "#,
+    );
+    let scope = &file.items;
+    let body = &contract(&file, "IERC20").body;
+
+    let sigs: Vec<_> = functions(body)
+        .map(|f| (f.signature(scope).unwrap(), f.selector(scope).unwrap()))
+        .collect();
+    assert_eq!(
+        sigs,
+        [
+            ("transfer(address,uint256)".into(), [0xa9, 0x05, 0x9c, 0xbb]),
+            ("balanceOf(address)".into(), [0x70, 0xa0, 0x82, 0x31]),
+        ]
+    );
+
+    let event = events(body).next().unwrap();
+    assert_eq!(
+        event.signature(scope).unwrap(),
+        "Transfer(address,address,uint256)"
+    );
+    assert_eq!(
+        event.selector(scope).unwrap(),
+        [
+            0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37,
+            0x8d, 0xaa, 0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d,
+            0xf5, 0x23, 0xb3, 0xef
+        ]
+    );
+
+    let error = errors(body).next().unwrap();
+    assert_eq!(error.signature(scope).unwrap(), "Error(string)");
+    assert_eq!(error.selector(scope).unwrap(), [0x08, 0xc3, 0x79, 0xa0]);
+}
+
+#[test]
+fn custom_types() {
+    let file = file(
+        r#"
+        type Price is uint128;
+        struct Inner { int a; bytes32[2] b; Market.Kind kind; }
+
+        contract Market {
+            enum Kind { Buy, Sell }
+            struct Order { address maker; Inner[] inners; Price amount; Market market; }
+
+            function fill(Order[3] calldata orders, Market.Order memory order, function() external f) external;
+            event Filled(Order indexed order, uint8[][] data);
+            error Invalid(Inner inner, Price price);
+        }
+        "#,
+    );
+    let scope = &file.items;
+    let body = &contract(&file, "Market").body;
+
+    let order = "(address,(int256,bytes32[2],uint8)[],uint128,address)";
+    let function = functions(body).next().unwrap();
+    assert_eq!(
+        function.signature(scope).unwrap(),
+        format!("fill({order}[3],{order},function)")
+    );
+    let event = events(body).next().unwrap();
+    assert_eq!(
+        event.signature(scope).unwrap(),
+        format!("Filled({order},uint8[][])")
+    );
+    let error = errors(body).next().unwrap();
+    assert_eq!(
+        error.signature(scope).unwrap(),
+        "Invalid((int256,bytes32[2],uint8),uint128)"
+    );
+
+    // types are only resolved in the given scope
+    let e = error.signature(body).unwrap_err();
+    assert_eq!(e.to_string(), "unresolved type");
+}
+
+#[test]
+fn shadowing() {
+    let file = file(
+        r#"
+        struct S { uint a; }
+        struct Outer { S s; }
+
+        contract A {
+            struct S { address a; }
+            function f(S s) external;
+        }
+
+        contract B {
+            struct S { bool a; }
+            struct T { S[] s; Outer o; }
+            function f(S s, T t, A.S a) external;
+        }
+
+        contract C {
+            function f(S s) external;
+        }
+
+        contract D {
+            struct S { uint8 a; }
+            enum S { X }
+            function f(S s) external;
+        }
+        "#,
+    );
+    let scope = &file.items;
+    let signature = |name| {
+        let f = functions(&contract(&file, name).body).next().unwrap();
+        f.signature(scope).unwrap()
+    };
+    assert_eq!(signature("A"), "f((address))");
+    assert_eq!(signature("B"), "f((bool),((bool)[],((uint256))),(address))");
+    assert_eq!(signature("C"), "f((uint256))");
+
+    let f = functions(&contract(&file, "D").body).next().unwrap();
+    let e = f.signature(scope).unwrap_err();
+    assert_eq!(e.to_string(), "ambiguous type");
+}
+
+#[test]
+fn invalid() {
+    let file = file(
+        r#"
+        struct A { B b; }
+        struct B { A[] a; }
+
+        contract C {
+            constructor(uint a) {}
+            function f(A a) external;
+            function g(Missing m) external;
+        }
+        "#,
+    );
+    let scope = &file.items;
+    let mut functions = functions(&contract(&file, "C").body);
+
+    let constructor = functions.next().unwrap();
+    let e = constructor.signature(scope).unwrap_err();
+    assert_eq!(e.to_string(), "only named functions have a signature");
+
+    let e = functions.next().unwrap().signature(scope).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "failed to resolve a recursive type definition"
+    );
+
+    let e = functions.next().unwrap().selector(scope).unwrap_err();
+    assert_eq!(e.to_string(), "unresolved type");
+}
@@ -0,0 +1,94 @@
+//! Development tasks for the Alloy workspace.
+//!
+//! ```sh
+//! cargo xtask expand [FILE]...
+//! ```
+//!
+//! `expand` prints the expansion of `sol!` inputs. Each file is expanded like
+//! the input of `sol!`, see the `alloy-sol-macro` golden tests for the
+//! supported file types. With no files, the input is read from stdin. The
+//! output is formatted with `rustfmt` if it is available. Pass
+//! `--features arbitrary` to include the `arbitrary` implementations.
+
+#![deny(unused_must_use, rust_2018_idioms)]
+// these modules are those of `alloy-sol-macro`, which uses more of them
+#![allow(dead_code)]
+
+extern crate syn_solidity as ast;
+
+#[path = "../../crates/sol-macro/src/attr.rs"]
+mod attr;
+#[path = "../../crates/sol-macro/src/expand/mod.rs"]
+mod expand;
+#[path = "../../crates/sol-macro/src/file.rs"]
+mod file;
+#[path = "../../crates/sol-macro/src/input.rs"]
+mod input;
+#[cfg(feature = "json")]
+#[path = "../../crates/sol-macro/src/json.rs"]
+mod json;
+#[path = "../../crates/sol-macro/src/utils.rs"]
+mod utils;
+
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "usage: cargo xtask expand [FILE]...";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args_os().skip(1);
+    match args.next().as_ref().and_then(|arg| arg.to_str()) {
+        Some("expand") => expand_files(args.map(PathBuf::from).collect()),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn expand_files(paths: Vec<PathBuf>) -> ExitCode {
+    let inputs = if paths.is_empty() {
+        let mut src = String::new();
+        if let Err(e) = io::stdin().read_to_string(&mut src) {
+            eprintln!("error: failed to read stdin: {e}");
+            return ExitCode::FAILURE
+        }
+        vec![(PathBuf::from("<stdin>"), Ok(src))]
+    } else {
+        paths
+            .into_iter()
+            .map(|path| {
+                let src = std::fs::read_to_string(&path);
+                (path, src)
+            })
+            .collect()
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    for (path, src) in inputs {
+        match src
+            .map_err(|e| e.to_string())
+            .and_then(|src| expand(&path, &src))
+        {
+            Ok(expanded) => print!("{expanded}"),
+            Err(e) => {
+                eprintln!("error: {}: {e}", path.display());
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+fn expand(path: &Path, src: &str) -> Result<String, String> {
+    let tokens = file::expand(path, src).map_err(|e| e.to_string())?;
+    let src = tokens.to_string();
+    Ok(file::rustfmt(&src).unwrap_or(src + "\n"))
+}